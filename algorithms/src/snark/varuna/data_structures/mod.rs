@@ -28,6 +28,10 @@ pub use circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// The Varuna proving report.
+pub(super) mod proving_report;
+pub use proving_report::*;
+
 /// A test circuit.
 #[cfg(any(test, feature = "test"))]
 pub(super) mod test_circuit;
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// A report on the resources used to create a Varuna proof.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvingReport {
+    /// The number of circuit instances in the batch.
+    pub num_instances: usize,
+    /// The total number of constraints, across all instances.
+    pub num_constraints: usize,
+    /// The total number of variables, across all instances.
+    pub num_variables: usize,
+    /// The time spent synthesizing the circuits into the prover state.
+    pub synthesis_time: Duration,
+    /// The time spent computing the prover round polynomials, which is dominated by FFTs.
+    pub fft_time: Duration,
+    /// The time spent on the MSMs committing to the round polynomials.
+    pub commitment_time: Duration,
+    /// The time spent evaluating the linear combinations, and on the MSMs opening them.
+    pub opening_time: Duration,
+    /// The total time spent creating the proof.
+    pub total_time: Duration,
+    /// The peak resident memory of the process in bytes, if it is available on this platform.
+    /// Note that this is a high-water mark for the whole process, and not only for this proof.
+    pub peak_memory: Option<u64>,
+}
+
+impl ProvingReport {
+    /// Runs the given operation, adding the time it takes to the given phase if a report is requested.
+    pub(crate) fn measure<T>(phase: Option<&mut Duration>, operation: impl FnOnce() -> T) -> T {
+        match phase {
+            Some(phase) => {
+                let timer = Instant::now();
+                let output = operation();
+                *phase += timer.elapsed();
+                output
+            }
+            None => operation(),
+        }
+    }
+
+    /// Returns the peak resident memory of the process in bytes, if it is available on this platform.
+    pub(crate) fn peak_memory() -> Option<u64> {
+        #[cfg(target_os = "linux")]
+        {
+            // The high-water mark of the resident set size is reported in kilobytes, e.g. `VmHWM:   1024 kB`.
+            let status = std::fs::read_to_string("/proc/self/status").ok()?;
+            let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+            let kilobytes =
+                line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim().parse::<u64>().ok()?;
+            Some(kilobytes.saturating_mul(1024))
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+}
@@ -33,6 +33,7 @@ use crate::{
         CircuitProvingKey,
         CircuitVerifyingKey,
         Proof,
+        ProvingReport,
         SNARKMode,
         UniversalSRS,
     },
@@ -192,153 +193,37 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         }
         end_timer!(sponge_time);
     }
-}
-
-impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: AlgebraicSponge<E::Fq, 2>,
-    SM: SNARKMode,
-{
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, SM>;
-    type ScalarField = E::Fr;
-    type UniversalProver = UniversalProver<E>;
-    type UniversalSRS = UniversalSRS<E>;
-    type UniversalVerifier = UniversalVerifier<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E>;
-
-    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS> {
-        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
-        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    /// Generates the circuit proving and verifying keys.
-    /// This is a deterministic algorithm that anyone can rerun.
-    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
-        universal_srs: &Self::UniversalSRS,
-        circuit: &C,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
-        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
-        ensure!(circuit_keys.len() == 1);
-        Ok(circuit_keys.pop().unwrap())
-    }
-
-    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
-    fn prove_vk(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        let circuit_id = std::iter::once(&verifying_key.id);
-        let circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id);
 
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (label, &c) in circuit_poly_info.keys().zip(linear_combination_challenges) {
-            lc.add(c, label.clone());
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
-
-        let empty_randomness = vec![Randomness::<E>::empty(); 12];
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
+    /// Returns a proof for the given batch, along with a report on the resources used to create it.
+    pub fn prove_batch_with_report<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<(Proof<E>, ProvingReport)> {
+        let timer = std::time::Instant::now();
+        let mut report = ProvingReport::default();
+        let proof = Self::prove_batch_internal(
             universal_prover,
-            &committer_key,
-            &[lc],
-            proving_key.circuit.interpolate_matrix_evals()?,
-            &empty_randomness,
-            &query_set,
-            &mut sponge,
+            fs_parameters,
+            keys_to_constraints,
+            zk_rng,
+            Some(&mut report),
         )?;
-
-        Ok(Self::Certificate::new(certificate))
+        report.total_time = timer.elapsed();
+        Ok((proof, report))
     }
 
-    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
-    /// Verify that the verifying key's circuit_info is correct
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool> {
-        // Ensure the VerifyingKey encodes the expected circuit.
-        let circuit_id = &verifying_key.id;
-        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
-        if state.index_info != verifying_key.circuit_info {
-            bail!(SNARKError::CircuitNotFound);
-        }
-        if state.id != *circuit_id {
-            bail!(SNARKError::CircuitNotFound);
-        }
-
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let (lc, evaluation) =
-            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
-
-        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(lc.terms.keys())
-            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
-            .collect_vec();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
-    }
-
-    /// This is the main entrypoint for creating proofs.
+    /// Returns a proof for the given batch, recording the resources used in the given report if it is requested.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs
-    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
+    fn prove_batch_internal<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof> {
+        mut report: Option<&mut ProvingReport>,
+    ) -> Result<Proof<E>> {
         let prover_time = start_timer!(|| "Varuna::Prover");
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
@@ -348,7 +233,9 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let prover_state = ProvingReport::measure(report.as_mut().map(|r| &mut r.synthesis_time), || {
+            AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)
+        })?;
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -371,6 +258,16 @@ where
             total_instances = total_instances.saturating_add(batch_size);
 
             circuit_ids.push(circuit_id);
+
+            // Record the size of the batch.
+            if let Some(report) = report.as_mut() {
+                let circuit_info = &pk.circuit_verifying_key.circuit_info;
+                report.num_instances = report.num_instances.saturating_add(batch_size);
+                report.num_constraints =
+                    report.num_constraints.saturating_add(circuit_info.num_constraints.saturating_mul(batch_size));
+                report.num_variables =
+                    report.num_variables.saturating_add(circuit_info.num_variables.saturating_mul(batch_size));
+            }
         }
         ensure!(prover_state.total_instances == total_instances);
 
@@ -384,17 +281,21 @@ where
         // --------------------------------------------------------------------
         // First round
 
-        let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, zk_rng)?;
+        let prover_state = ProvingReport::measure(report.as_mut().map(|r| &mut r.fft_time), || {
+            AHPForR1CS::<_, SM>::prover_first_round(prover_state, zk_rng)
+        })?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = prover_state.first_round_oracles.as_ref().unwrap();
-            SonicKZG10::<E, FS>::commit(
-                universal_prover,
-                &committer_key,
-                first_round_oracles.iter().map(Into::into),
-                SM::ZK.then_some(zk_rng),
-            )?
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.commitment_time), || {
+                SonicKZG10::<E, FS>::commit(
+                    universal_prover,
+                    &committer_key,
+                    first_round_oracles.iter().map(Into::into),
+                    SM::ZK.then_some(zk_rng),
+                )
+            })?
         };
         end_timer!(first_round_comm_time);
 
@@ -413,16 +314,20 @@ where
         // --------------------------------------------------------------------
         // Second round
 
-        let (second_oracles, prover_state) =
-            AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)?;
+        let (second_oracles, prover_state) = ProvingReport::measure(report.as_mut().map(|r| &mut r.fft_time), || {
+            AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)
+        })?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            second_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
+        let (second_commitments, second_commitment_randomnesses) =
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.commitment_time), || {
+                SonicKZG10::<E, FS>::commit(
+                    universal_prover,
+                    &committer_key,
+                    second_oracles.iter().map(Into::into),
+                    SM::ZK.then_some(zk_rng),
+                )
+            })?;
         end_timer!(second_round_comm_time);
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
@@ -434,20 +339,26 @@ where
         // --------------------------------------------------------------------
         // Third round
 
-        let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
-            &verifier_first_message,
-            &verifier_second_msg,
-            prover_state,
-            zk_rng,
-        )?;
+        let (prover_third_message, third_oracles, prover_state) =
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.fft_time), || {
+                AHPForR1CS::<_, SM>::prover_third_round(
+                    &verifier_first_message,
+                    &verifier_second_msg,
+                    prover_state,
+                    zk_rng,
+                )
+            })?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            third_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
+        let (third_commitments, third_commitment_randomnesses) =
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.commitment_time), || {
+                SonicKZG10::<E, FS>::commit(
+                    universal_prover,
+                    &committer_key,
+                    third_oracles.iter().map(Into::into),
+                    SM::ZK.then_some(zk_rng),
+                )
+            })?;
         end_timer!(third_round_comm_time);
 
         Self::absorb_labeled_with_sums(
@@ -464,15 +375,25 @@ where
         // Fourth round
 
         let (prover_fourth_message, fourth_oracles, mut prover_state) =
-            AHPForR1CS::<_, SM>::prover_fourth_round(&verifier_second_msg, &verifier_third_msg, prover_state, zk_rng)?;
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.fft_time), || {
+                AHPForR1CS::<_, SM>::prover_fourth_round(
+                    &verifier_second_msg,
+                    &verifier_third_msg,
+                    prover_state,
+                    zk_rng,
+                )
+            })?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            fourth_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
+        let (fourth_commitments, fourth_commitment_randomnesses) =
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.commitment_time), || {
+                SonicKZG10::<E, FS>::commit(
+                    universal_prover,
+                    &committer_key,
+                    fourth_oracles.iter().map(Into::into),
+                    SM::ZK.then_some(zk_rng),
+                )
+            })?;
         end_timer!(fourth_round_comm_time);
 
         Self::absorb_labeled_with_sums(&fourth_commitments, &prover_fourth_message.sums, &mut sponge);
@@ -490,15 +411,20 @@ where
 
         // --------------------------------------------------------------------
         // Fifth round
-        let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
+        let fifth_oracles = ProvingReport::measure(report.as_mut().map(|r| &mut r.fft_time), || {
+            AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)
+        })?;
 
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
-        let (fifth_commitments, fifth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            fifth_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
+        let (fifth_commitments, fifth_commitment_randomnesses) =
+            ProvingReport::measure(report.as_mut().map(|r| &mut r.commitment_time), || {
+                SonicKZG10::<E, FS>::commit(
+                    universal_prover,
+                    &committer_key,
+                    fifth_oracles.iter().map(Into::into),
+                    SM::ZK.then_some(zk_rng),
+                )
+            })?;
         end_timer!(fifth_round_comm_time);
 
         Self::absorb_labeled(&fifth_commitments, &mut sponge);
@@ -580,29 +506,34 @@ where
         )?;
 
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
-        let mut evaluations = std::collections::BTreeMap::new();
-        for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
-                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
-                evaluations.insert(label, evaluation);
+        let evaluations = ProvingReport::measure(report.as_mut().map(|r| &mut r.opening_time), || {
+            let mut evaluations = std::collections::BTreeMap::new();
+            for (label, (_, point)) in query_set.to_set() {
+                if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                    let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                    let evaluation = polynomials.get_lc_eval(lc, point)?;
+                    evaluations.insert(label, evaluation);
+                }
             }
-        }
+            Ok::<_, anyhow::Error>(evaluations)
+        })?;
 
         let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes.clone());
         end_timer!(eval_time);
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            universal_prover,
-            &committer_key,
-            lc_s.values(),
-            polynomials,
-            &commitment_randomnesses,
-            &query_set.to_set(),
-            &mut sponge,
-        )?;
+        let pc_proof = ProvingReport::measure(report.as_mut().map(|r| &mut r.opening_time), || {
+            SonicKZG10::<E, FS>::open_combinations(
+                universal_prover,
+                &committer_key,
+                lc_s.values(),
+                polynomials,
+                &commitment_randomnesses,
+                &query_set.to_set(),
+                &mut sponge,
+            )
+        })?;
 
         let proof = Proof::<E>::new(
             batch_sizes,
@@ -615,9 +546,163 @@ where
         proof.check_batch_sizes()?;
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
 
+        // Record the peak memory.
+        if let Some(report) = report {
+            report.peak_memory = ProvingReport::peak_memory();
+        }
+
         end_timer!(prover_time);
         Ok(proof)
     }
+}
+
+impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, SM>;
+    type ScalarField = E::Fr;
+    type UniversalProver = UniversalProver<E>;
+    type UniversalSRS = UniversalSRS<E>;
+    type UniversalVerifier = UniversalVerifier<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E>;
+
+    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS> {
+        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
+        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &Self::UniversalSRS,
+        circuit: &C,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
+        ensure!(circuit_keys.len() == 1);
+        Ok(circuit_keys.pop().unwrap())
+    }
+
+    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
+    fn prove_vk(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        let circuit_id = std::iter::once(&verifying_key.id);
+        let circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (label, &c) in circuit_poly_info.keys().zip(linear_combination_challenges) {
+            lc.add(c, label.clone());
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
+
+        let empty_randomness = vec![Randomness::<E>::empty(); 12];
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            universal_prover,
+            &committer_key,
+            &[lc],
+            proving_key.circuit.interpolate_matrix_evals()?,
+            &empty_randomness,
+            &query_set,
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
+    /// Verify that the verifying key's circuit_info is correct
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool> {
+        // Ensure the VerifyingKey encodes the expected circuit.
+        let circuit_id = &verifying_key.id;
+        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
+        if state.index_info != verifying_key.circuit_info {
+            bail!(SNARKError::CircuitNotFound);
+        }
+        if state.id != *circuit_id {
+            bail!(SNARKError::CircuitNotFound);
+        }
+
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let (lc, evaluation) =
+            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
+
+        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(lc.terms.keys())
+            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
+            .collect_vec();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    /// This is the main entrypoint for creating proofs.
+    /// You can find a specification of the prover algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs
+    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof> {
+        Self::prove_batch_internal(universal_prover, fs_parameters, keys_to_constraints, zk_rng, None)
+    }
 
     /// This is the main entrypoint for verifying proofs.
     /// You can find a specification of the verifier algorithm in:
@@ -16,13 +16,14 @@ mod equal;
 mod helpers;
 mod ternary;
 mod verify;
+mod vrf;
 
 #[cfg(test)]
 use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
 
 use crate::ComputeKey;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar};
 
 #[derive(Clone)]
 pub struct Signature<A: Aleo> {
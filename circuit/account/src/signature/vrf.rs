@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Signature<A> {
+    /// Returns `true` if the signature is a valid VRF proof of `gamma` for the given `address` and `input`.
+    pub fn verify_vrf(&self, gamma: &Group<A>, address: &Address<A>, input: &[Field<A>]) -> Boolean<A> {
+        // Retrieve pk_sig.
+        let pk_sig = self.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = self.compute_key.pr_sig();
+
+        // Compute `h` := HashToGroup(vrf_domain, address, input).
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(A::vrf_domain());
+        preimage.push(address.to_field());
+        preimage.extend_from_slice(input);
+        let h = A::hash_to_group_psd8(&preimage);
+
+        // Compute `g_nonce` := (response * G) + (challenge * pk_sig).
+        let g_nonce = A::g_scalar_multiply(&self.response) + (pk_sig * &self.challenge);
+        // Compute `h_nonce` := (response * h) + (challenge * gamma).
+        let h_nonce = (&h * &self.response) + (gamma * &self.challenge);
+
+        // Construct the hash input as (vrf_domain, nonce * G, nonce * h, h, gamma, pk_sig, pr_sig, address).
+        let mut preimage = Vec::with_capacity(8);
+        preimage.push(A::vrf_domain());
+        preimage.extend([&g_nonce, &h_nonce, &h, gamma, pk_sig, pr_sig].map(|point| point.to_x_coordinate()));
+        preimage.push(address.to_field());
+
+        // Compute the candidate verifier challenge.
+        let candidate_challenge = A::hash_to_scalar_psd8(&preimage);
+        // Compute the candidate address.
+        let candidate_address = self.compute_key.to_address();
+
+        // Return `true` if the challenge and address is valid.
+        self.challenge.is_equal(&candidate_challenge) & address.is_equal(&candidate_address)
+    }
+
+    /// Returns the VRF output for the given VRF point `gamma`, as `Hash(vrf_domain, gamma)`.
+    pub fn vrf_output(gamma: &Group<A>) -> Field<A> {
+        A::hash_psd2(&[A::vrf_domain(), gamma.to_x_coordinate()])
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_verify_vrf(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (private_key, _compute_key, _view_key, address) = generate_account()?;
+
+            // Generate a VRF proof.
+            let input = [Field::new(mode, Uniform::rand(rng)), Field::new(mode, Uniform::rand(rng))];
+            let (output, gamma, proof) = console::Signature::prove_vrf(&private_key, &input.eject_value(), rng)?;
+
+            // Initialize the proof, gamma, and address.
+            let proof = Signature::<Circuit>::new(mode, proof);
+            let gamma = Group::new(mode, gamma);
+            let address = Address::new(mode, address);
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = proof.verify_vrf(&gamma, &address, &input);
+                assert!(candidate.eject_value());
+                assert_eq!(output, Signature::vrf_output(&gamma).eject_value());
+                assert!(Circuit::is_satisfied());
+
+                // Ensure the proof is rejected for a different input.
+                let candidate = proof.verify_vrf(&gamma, &address, &input[..1]);
+                assert!(!candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_vrf_constant() -> Result<()> {
+        check_verify_vrf(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_vrf_public() -> Result<()> {
+        check_verify_vrf(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_vrf_private() -> Result<()> {
+        check_verify_vrf(Mode::Private)
+    }
+}
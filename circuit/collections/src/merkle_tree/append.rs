@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Appends the given leaf to an append-only Merkle tree, where the Merkle path is for the next empty leaf.
+    /// Returns `true` if the leaf index of the Merkle path is the given number of leaves in the old tree,
+    /// and the Merkle path is valid for the empty leaf under the given old root,
+    /// along with the new root of the Merkle tree with the leaf inserted.
+    pub fn verify_append<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        old_root: &PH::Hash,
+        num_leaves: &U64<E>,
+        leaf: &LH::Leaf,
+    ) -> (Boolean<E>, PH::Hash) {
+        // Ensure the leaf index is within the tree depth.
+        if (*self.leaf_index.eject_value() as u128) >= (1u128 << DEPTH) {
+            E::halt("Found an out of bounds Merkle leaf index")
+        }
+        // Ensure the path length matches the expected depth.
+        else if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+
+        // Initialize a tracker for the old hash, starting from the empty hash.
+        let mut old_hash = path_hasher.hash_empty();
+        // Initialize a tracker for the new hash, by computing the leaf hash to start.
+        let mut new_hash = leaf_hasher.hash_leaf(leaf);
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = self.leaf_index.to_bits_le().into_iter().take(DEPTH as usize).map(|b| !b);
+
+        // Check levels between leaf level and root, for both the old and new leaf.
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+            // Update the old hash for the next level.
+            let left = Field::ternary(&indicator, &old_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &old_hash);
+            old_hash = path_hasher.hash_children(&left, &right);
+
+            // Update the new hash for the next level.
+            let left = Field::ternary(&indicator, &new_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &new_hash);
+            new_hash = path_hasher.hash_children(&left, &right);
+        }
+
+        // Ensure the leaf is appended at the next position, as the old root alone does not bind the leaf index
+        // (any empty leaf under the old root would otherwise be accepted).
+        let is_next_leaf = self.leaf_index.is_equal(num_leaves);
+        // Ensure the old hash matches the given old root, and return the new root.
+        (is_next_leaf & old_root.is_equal(&old_hash), new_hash)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+
+    macro_rules! check_verify_append {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $num_leaves:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            let mut rng = TestRng::default();
+
+            // Initialize the Merkle frontier.
+            let mut frontier =
+                console::merkle_tree::MerkleFrontier::<_, _, _, $depth>::new(&native_leaf_hasher, &native_path_hasher)?;
+
+            for _ in 0..$num_leaves {
+                // Sample the leaf.
+                let merkle_leaf = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+                // Compute the Merkle path of the next empty leaf, and append the leaf.
+                let merkle_path = frontier.next_path()?;
+                let old_root = *frontier.root();
+                let old_num_leaves = frontier.number_of_leaves();
+                frontier.append(&merkle_leaf)?;
+
+                // Initialize the Merkle path.
+                let path = MerklePath::<Circuit, $depth>::new(Mode::$mode, merkle_path.clone());
+                assert_eq!(merkle_path, path.eject_value());
+                // Initialize the old Merkle root.
+                let root = Field::new(Mode::$mode, old_root);
+                // Initialize the old number of leaves.
+                let num_leaves = U64::new(Mode::$mode, console::U64::new(old_num_leaves));
+                // Initialize the Merkle leaf.
+                let leaf: Vec<_> = Inject::new(Mode::$mode, merkle_leaf.clone());
+
+                Circuit::scope(format!("Append {}", Mode::$mode), || {
+                    let (is_valid, new_root) =
+                        path.verify_append(&circuit_leaf_hasher, &circuit_path_hasher, &root, &num_leaves, &leaf);
+                    assert!(is_valid.eject_value());
+                    assert_eq!(*frontier.root(), new_root.eject_value());
+                    assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+                });
+                Circuit::reset();
+
+                // Initialize an incorrect old Merkle root.
+                let incorrect_root = root.clone() + Field::one();
+
+                Circuit::scope(format!("Append (Incorrect Root) {}", Mode::$mode), || {
+                    let (is_valid, new_root) = path.verify_append(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &incorrect_root,
+                        &num_leaves,
+                        &leaf,
+                    );
+                    assert!(!is_valid.eject_value());
+                    assert_eq!(*frontier.root(), new_root.eject_value());
+                    assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+                });
+                Circuit::reset();
+
+                // Initialize an incorrect old number of leaves.
+                let incorrect_num_leaves = U64::new(Mode::$mode, console::U64::new(old_num_leaves + 1));
+
+                Circuit::scope(format!("Append (Incorrect Number of Leaves) {}", Mode::$mode), || {
+                    let (is_valid, _) = path.verify_append(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &root,
+                        &incorrect_num_leaves,
+                        &leaf,
+                    );
+                    assert!(!is_valid.eject_value());
+                    assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+                });
+                Circuit::reset();
+            }
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_verify_append_poseidon2_constant() -> Result<()> {
+        check_verify_append!(Poseidon4, Poseidon2, Constant, 8, 10, (19, 0, 0, 0))
+    }
+
+    #[test]
+    fn test_verify_append_poseidon2_public() -> Result<()> {
+        check_verify_append!(Poseidon4, Poseidon2, Public, 8, 10, (18, 0, 9377, 9377))
+    }
+
+    #[test]
+    fn test_verify_append_poseidon2_private() -> Result<()> {
+        check_verify_append!(Poseidon4, Poseidon2, Private, 8, 10, (18, 0, 9377, 9377))
+    }
+}
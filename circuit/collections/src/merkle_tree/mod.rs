@@ -13,7 +13,9 @@
 // limitations under the License.
 
 mod helpers;
-use helpers::{LeafHash, PathHash};
+pub use helpers::{LeafHash, PathHash};
+
+mod append;
 
 mod verify;
 
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+pub struct SparseMerklePath<E: Environment, const DEPTH: u8> {
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Inject for SparseMerklePath<E, DEPTH> {
+    type Primitive = console::sparse_merkle_tree::SparseMerklePath<E::Network, DEPTH>;
+
+    /// Initializes a sparse Merkle path from the given mode and native sparse Merkle path.
+    fn new(mode: Mode, merkle_path: Self::Primitive) -> Self {
+        // Initialize the Merkle path siblings.
+        let siblings: Vec<_> = merkle_path.siblings().iter().map(|node| Field::new(mode, *node)).collect();
+        // Ensure the Merkle path is the correct depth.
+        match siblings.len() == DEPTH as usize {
+            // Return the Merkle path.
+            true => Self { siblings },
+            false => E::halt("Merkle path is not the correct depth"),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Eject for SparseMerklePath<E, DEPTH> {
+    type Primitive = console::sparse_merkle_tree::SparseMerklePath<E::Network, DEPTH>;
+
+    /// Ejects the mode of the sparse Merkle path.
+    fn eject_mode(&self) -> Mode {
+        self.siblings.eject_mode()
+    }
+
+    /// Ejects the sparse Merkle path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::try_from(self.siblings.eject_value()) {
+            Ok(merkle_path) => merkle_path,
+            Err(error) => E::halt(format!("Failed to eject the sparse Merkle path: {error}")),
+        }
+    }
+}
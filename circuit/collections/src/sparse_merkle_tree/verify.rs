@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns `true` if the Merkle path proves the given key-value pair is in the tree with the given root.
+    pub fn verify_membership<
+        LH: LeafHash<E, Hash = PH::Hash, Leaf = Vec<Field<E>>>,
+        PH: PathHash<E, Hash = Field<E>>,
+    >(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        value: &Field<E>,
+    ) -> Boolean<E> {
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash_leaf(&vec![key.clone(), value.clone()]);
+        self.verify(path_hasher, root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the Merkle path proves the given key is **not** in the tree with the given root.
+    pub fn verify_non_membership<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+    ) -> Boolean<E> {
+        self.verify(path_hasher, root, key, path_hasher.hash_empty())
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root, key, and leaf hash.
+    fn verify<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        leaf_hash: Field<E>,
+    ) -> Boolean<E> {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash;
+
+        // Compute the ordering of the current hash and sibling hash on each level, from the bits of the key.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = key.to_bits_le().into_iter().map(|b| !b);
+
+        // Check levels between leaf level and root.
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level.
+            current_hash = path_hasher.hash_children(&left, &right);
+        }
+
+        // Ensure the final hash matches the given root.
+        root.is_equal(&current_hash)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+
+    macro_rules! check_verify {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            let mut rng = TestRng::default();
+
+            // Initialize the sparse Merkle tree.
+            let mut merkle_tree = console::sparse_merkle_tree::SparseMerkleTree::<_, _, _, $depth>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+            )?;
+            let entries =
+                (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect::<Vec<_>>();
+            merkle_tree.update_many(&entries.iter().map(|(key, value)| (*key, Some(*value))).collect::<Vec<_>>())?;
+
+            // Initialize the Merkle root.
+            let root = Field::new(Mode::$mode, *merkle_tree.root());
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = root.clone() + Field::one();
+
+            for (merkle_key, merkle_value) in &entries {
+                // Compute the Merkle path.
+                let merkle_path = merkle_tree.prove(merkle_key)?;
+
+                // Initialize the Merkle path.
+                let path = SparseMerklePath::<Circuit, $depth>::new(Mode::$mode, merkle_path.clone());
+                assert_eq!(merkle_path, path.eject_value());
+                // Initialize the key-value pair.
+                let key = Field::new(Mode::$mode, *merkle_key);
+                let value = Field::new(Mode::$mode, *merkle_value);
+
+                Circuit::scope(format!("Verify Membership {}", Mode::$mode), || {
+                    let candidate =
+                        path.verify_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key, &value);
+                    assert!(candidate.eject_value());
+                    assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+                });
+                Circuit::reset();
+
+                // Reinitialize the key, as the bits of the key are cached.
+                let key = Field::new(Mode::$mode, *merkle_key);
+
+                Circuit::scope(format!("Verify Membership (Incorrect Root) {}", Mode::$mode), || {
+                    let candidate = path.verify_membership(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &incorrect_root,
+                        &key,
+                        &value,
+                    );
+                    assert!(!candidate.eject_value());
+                    assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+                });
+                Circuit::reset();
+
+                Circuit::scope(format!("Verify Non-Membership (Present Key) {}", Mode::$mode), || {
+                    let candidate = path.verify_non_membership(&circuit_path_hasher, &root, &key);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+
+            for _ in 0..ITERATIONS {
+                // Sample an absent key, and compute its Merkle path.
+                let merkle_key = Uniform::rand(&mut rng);
+                let merkle_path = merkle_tree.prove(&merkle_key)?;
+
+                // Initialize the Merkle path.
+                let path = SparseMerklePath::<Circuit, $depth>::new(Mode::$mode, merkle_path);
+                // Initialize the key-value pair.
+                let key = Field::new(Mode::$mode, merkle_key);
+                let value = Field::new(Mode::$mode, Uniform::rand(&mut rng));
+
+                Circuit::scope(format!("Verify Non-Membership {}", Mode::$mode), || {
+                    let candidate = path.verify_non_membership(&circuit_path_hasher, &root, &key);
+                    assert!(candidate.eject_value());
+                });
+                Circuit::reset();
+
+                Circuit::scope(format!("Verify Non-Membership (Incorrect Root) {}", Mode::$mode), || {
+                    let candidate = path.verify_non_membership(&circuit_path_hasher, &incorrect_root, &key);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+
+                Circuit::scope(format!("Verify Membership (Absent Key) {}", Mode::$mode), || {
+                    let candidate =
+                        path.verify_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key, &value);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_verify_poseidon2_constant() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Constant, 253, (508, 0, 0, 0))
+    }
+
+    #[test]
+    fn test_verify_poseidon2_public() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Public, 253, (254, 0, 137973, 137975))
+    }
+
+    #[test]
+    fn test_verify_poseidon2_private() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Private, 253, (254, 0, 137973, 137975))
+    }
+}
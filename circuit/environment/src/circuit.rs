@@ -191,6 +191,12 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the index and a copy of the first constraint in the entire circuit that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<(usize, Constraint<Self::BaseField>)> {
+        CIRCUIT
+            .with(|circuit| circuit.borrow().first_unsatisfied().map(|(index, constraint)| (index, constraint.clone())))
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| circuit.borrow().num_constants())
@@ -374,4 +380,32 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_first_unsatisfied_constraint() {
+        let _candidate = create_example_circuit::<Circuit>();
+        assert!(Circuit::first_unsatisfied_constraint().is_none());
+        let num_constraints = Circuit::num_constraints();
+
+        // Enforce an unsatisfied constraint `(2 * 3) == 7`.
+        let [a, b, c] =
+            [2u8, 3, 7].map(|value| Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u8(value)));
+        Circuit::scope("test_first_unsatisfied_constraint", || {
+            Circuit::enforce(|| (&a, &b, &c));
+        });
+        assert!(!Circuit::is_satisfied());
+
+        // Ensure the unsatisfied constraint is reported, with its scope and assignments.
+        let (index, constraint) = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(num_constraints as usize, index);
+        assert!(constraint.scope().contains("test_first_unsatisfied_constraint"));
+        let assignments = constraint.to_assignments();
+        assert_eq!(3, assignments.len());
+        assert!(assignments.iter().all(|variable| variable.is_private()));
+        assert_eq!(
+            [a, b, c].map(|field| *field.eject_value()).to_vec(),
+            assignments.iter().map(|variable| variable.value()).collect::<Vec<_>>()
+        );
+        Circuit::reset();
+    }
 }
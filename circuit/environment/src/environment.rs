@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{witness_mode, Assignment, Constraint, Inject, LinearCombination, Mode, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -109,6 +109,10 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the index and a copy of the first constraint in the environment that is not satisfied, if one exists.
+    /// The constraint reports the scope it was enforced in, and the assignments of its variables.
+    fn first_unsatisfied_constraint() -> Option<(usize, Constraint<Self::BaseField>)>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
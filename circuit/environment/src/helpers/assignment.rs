@@ -42,7 +42,7 @@ impl<F: PrimeField> From<&crate::Variable<F>> for AssignmentVariable<F> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AssignmentLC<F: PrimeField> {
     constant: F,
     terms: Vec<(AssignmentVariable<F>, F)>,
@@ -61,6 +61,11 @@ impl<F: PrimeField> From<&crate::LinearCombination<F>> for AssignmentLC<F> {
 }
 
 impl<F: PrimeField> AssignmentLC<F> {
+    /// Initializes a new linear combination from its constant term and its terms.
+    pub const fn new(constant: F, terms: Vec<(AssignmentVariable<F>, F)>) -> Self {
+        Self { constant, terms }
+    }
+
     /// Returns the constant term of the linear combination.
     pub const fn constant(&self) -> F {
         self.constant
@@ -83,7 +88,7 @@ impl<F: PrimeField> AssignmentLC<F> {
 
 /// A struct that contains public variable assignments, private variable assignments,
 /// and constraint assignments.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Assignment<F: PrimeField> {
     public: Arc<[(Index, F)]>,
     private: Arc<[(Index, F)]>,
@@ -109,6 +114,15 @@ impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
 }
 
 impl<F: PrimeField> Assignment<F> {
+    /// Initializes a new assignment from its public variables, private variables, and constraints.
+    pub const fn new(
+        public: Arc<[(Index, F)]>,
+        private: Arc<[(Index, F)]>,
+        constraints: Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)]>,
+    ) -> Self {
+        Self { public, private, constraints }
+    }
+
     /// Returns the public inputs of the assignment.
     pub const fn public_inputs(&self) -> &Arc<[(Index, F)]> {
         &self.public
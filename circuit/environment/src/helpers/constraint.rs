@@ -32,24 +32,43 @@ impl<F: PrimeField> Constraint<F> {
 
     /// Returns `true` if the constraint is satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
-        let (scope, a, b, c) = (&self.0, &self.1, &self.2, &self.3);
-        let a = a.value();
-        let b = b.value();
-        let c = c.value();
-
-        match a * b == c {
+        match self.evaluate() {
             true => true,
             false => {
+                let (scope, a, b, c) = (&self.0, self.1.value(), self.2.value(), self.3.value());
                 eprintln!("Failed constraint at {scope}:\n\t({a} * {b}) != {c}");
                 false
             }
         }
     }
 
+    /// Returns `true` if `(a * b) == c` holds for the assigned values, without logging.
+    pub(crate) fn evaluate(&self) -> bool {
+        self.1.value() * self.2.value() == self.3.value()
+    }
+
+    /// Returns the scope in which the constraint was enforced.
+    pub fn scope(&self) -> &Scope {
+        &self.0
+    }
+
     /// Returns a reference to the terms `(a, b, c)`.
     pub fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
         (&self.1, &self.2, &self.3)
     }
+
+    /// Returns the public and private variables in the constraint, with their assigned values, in order of index.
+    pub fn to_assignments(&self) -> Vec<Variable<F>> {
+        let (a, b, c) = (&self.1, &self.2, &self.3);
+        let mut variables = [a, b, c]
+            .into_iter()
+            .flat_map(|lc| lc.to_terms().iter().map(|(variable, _)| variable.clone()))
+            .filter(|variable| !variable.is_constant())
+            .collect::<Vec<_>>();
+        variables.sort();
+        variables.dedup();
+        variables
+    }
 }
 
 impl<F: PrimeField> Display for Constraint<F> {
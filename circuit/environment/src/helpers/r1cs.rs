@@ -129,6 +129,15 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.is_satisfied_in_scope()
     }
 
+    /// Returns the index and the first constraint that is not satisfied, if one exists.
+    pub fn first_unsatisfied(&self) -> Option<(usize, &Constraint<F>)> {
+        self.constraints
+            .iter()
+            .enumerate()
+            .find(|(_, constraint)| !constraint.evaluate())
+            .map(|(index, constraint)| (index, constraint.as_ref()))
+    }
+
     /// Returns the current scope.
     pub(crate) fn scope(&self) -> Scope {
         self.counter.scope()
@@ -39,7 +39,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Circuit, Constraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -60,6 +60,8 @@ thread_local! {
     static GRAPH_KEY_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::graph_key_domain());
     /// The serial number domain as a constant field element.
     static SERIAL_NUMBER_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::serial_number_domain());
+    /// The VRF domain as a constant field element.
+    static VRF_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::vrf_domain());
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     static BHP_256: BHP256<AleoV0> = BHP256::<AleoV0>::constant(console::BHP_256.clone());
@@ -116,6 +118,11 @@ impl Aleo for AleoV0 {
         SERIAL_NUMBER_DOMAIN.with(|domain| domain.clone())
     }
 
+    /// Returns the VRF domain as a constant field element.
+    fn vrf_domain() -> Field<Self> {
+        VRF_DOMAIN.with(|domain| domain.clone())
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     #[inline]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
@@ -411,6 +418,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the index and a copy of the first constraint in the entire circuit that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<(usize, Constraint<Self::BaseField>)> {
+        E::first_unsatisfied_constraint()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
@@ -22,6 +22,7 @@ mod size_in_bytes;
 mod to_bits;
 mod to_fields;
 mod verify;
+mod vrf;
 
 #[cfg(feature = "private_key")]
 mod sign;
@@ -33,7 +34,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Boolean, Field, Scalar};
+use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
@@ -14,7 +14,12 @@
 
 use super::*;
 
-static SIGNATURE_PREFIX: &str = "sign";
+const SIGNATURE_PREFIX: &str = "sign";
+
+impl<N: Network> Bech32m for Signature<N> {
+    /// The signature string prefix.
+    const PREFIX: &'static str = SIGNATURE_PREFIX;
+}
 
 impl<N: Network> Parser for Signature<N> {
     /// Parses a string into an signature.
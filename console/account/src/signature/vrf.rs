@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns the VRF output, the VRF point `gamma`, and the proof `(challenge, response, compute_key)`
+    /// for the given input and RNG, where:
+    ///     h := HashToGroup(vrf_domain, address, input)
+    ///     gamma := private_key.sk_sig() * h
+    ///     challenge := HashToScalar(vrf_domain, nonce * G, nonce * h, h, gamma, pk_sig, pr_sig, address)
+    ///     response := nonce - challenge * private_key.sk_sig()
+    ///     output := Hash(vrf_domain, gamma)
+    ///
+    /// The output is unique for a given address and input, while the proof depends on the RNG.
+    #[cfg(feature = "private_key")]
+    pub fn prove_vrf<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        input: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<(Field<N>, Group<N>, Self)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if input.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot prove the VRF: the input exceeds maximum allowed size")
+        }
+
+        // Derive the compute key from the private key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Compute `h` as `HashToGroup(vrf_domain, address, input)`.
+        let h = Self::vrf_base(&address, input)?;
+        // Compute `gamma` as `sk_sig * h`.
+        let gamma = h * private_key.sk_sig();
+
+        // Sample a random nonce from the scalar field.
+        let nonce = Scalar::rand(rng);
+        // Compute `g_r` as `nonce * G`.
+        let g_r = N::g_scalar_multiply(&nonce);
+        // Compute `h_r` as `nonce * h`.
+        let h_r = h * nonce;
+
+        // Compute the verifier challenge.
+        let challenge = Self::vrf_challenge(g_r, h_r, h, gamma, pk_sig, pr_sig, address)?;
+        // Compute the prover response.
+        let response = nonce - (challenge * private_key.sk_sig());
+
+        // Compute the VRF output.
+        let output = Self::vrf_output(&gamma)?;
+
+        // Output the VRF output, gamma, and proof.
+        Ok((output, gamma, Self { challenge, response, compute_key }))
+    }
+
+    /// Verifies (challenge == challenge') && (address == address') where:
+    ///     h := HashToGroup(vrf_domain, address, input)
+    ///     challenge' := HashToScalar(vrf_domain, G^response pk_sig^challenge, h^response gamma^challenge, h, gamma,
+    ///                                pk_sig, pr_sig, address)
+    pub fn verify_vrf(&self, gamma: &Group<N>, address: &Address<N>, input: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if input.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the VRF: the input exceeds maximum allowed size");
+            return false;
+        }
+
+        // Retrieve pk_sig.
+        let pk_sig = self.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = self.compute_key.pr_sig();
+
+        // Compute `h`, and return `false` if this operation fails.
+        let h = match Self::vrf_base(address, input) {
+            Ok(h) => h,
+            Err(_) => return false,
+        };
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig).
+        let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
+        // Compute `h_r` := (response * h) + (challenge * gamma).
+        let h_r = (h * self.response) + (*gamma * self.challenge);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        let candidate_challenge = match Self::vrf_challenge(g_r, h_r, h, *gamma, pk_sig, pr_sig, *address) {
+            // Output the computed candidate challenge.
+            Ok(candidate_challenge) => candidate_challenge,
+            // Return `false` if the challenge errored.
+            Err(_) => return false,
+        };
+
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(self.compute_key) {
+            // Output the computed candidate address.
+            Ok(candidate_address) => candidate_address,
+            // Return `false` if the address errored.
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate challenge and address are correct.
+        self.challenge == candidate_challenge && *address == candidate_address
+    }
+
+    /// Returns the VRF output for the given VRF point `gamma`, as `Hash(vrf_domain, gamma)`.
+    pub fn vrf_output(gamma: &Group<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[N::vrf_domain(), gamma.to_x_coordinate()])
+    }
+
+    /// Returns the VRF base `h` for the given address and input, as `HashToGroup(vrf_domain, address, input)`.
+    fn vrf_base(address: &Address<N>, input: &[Field<N>]) -> Result<Group<N>> {
+        // Construct the hash input as (vrf_domain, address, input).
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(N::vrf_domain());
+        preimage.push(address.to_x_coordinate());
+        preimage.extend_from_slice(input);
+        // Hash to derive the VRF base.
+        N::hash_to_group_psd8(&preimage)
+    }
+
+    /// Returns the VRF challenge for the given points.
+    fn vrf_challenge(
+        g_r: Group<N>,
+        h_r: Group<N>,
+        h: Group<N>,
+        gamma: Group<N>,
+        pk_sig: Group<N>,
+        pr_sig: Group<N>,
+        address: Address<N>,
+    ) -> Result<Scalar<N>> {
+        // Construct the hash input as (vrf_domain, nonce * G, nonce * h, h, gamma, pk_sig, pr_sig, address).
+        let mut preimage = Vec::with_capacity(8);
+        preimage.push(N::vrf_domain());
+        preimage.extend([g_r, h_r, h, gamma, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        // Hash to derive the verifier challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_prove_and_verify_vrf() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the proof is valid for the input.
+            let input: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let (output, gamma, proof) = Signature::prove_vrf(&private_key, &input, rng)?;
+            assert!(proof.verify_vrf(&gamma, &address, &input));
+            assert_eq!(output, Signature::<CurrentNetwork>::vrf_output(&gamma)?);
+
+            // Check that the output is unique, even though the proof is not.
+            let (candidate_output, candidate_gamma, candidate_proof) = Signature::prove_vrf(&private_key, &input, rng)?;
+            assert_eq!(output, candidate_output);
+            assert_eq!(gamma, candidate_gamma);
+            assert_ne!(proof, candidate_proof);
+
+            // Check that the proof is invalid for an incorrect input.
+            let failure_input: Vec<_> = (0..=i).map(|_| Uniform::rand(rng)).collect();
+            assert!(!proof.verify_vrf(&gamma, &address, &failure_input));
+
+            // Check that the proof is invalid for an incorrect gamma.
+            assert!(!proof.verify_vrf(&Uniform::rand(rng), &address, &input));
+
+            // Check that the proof is invalid for an incorrect address.
+            let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!proof.verify_vrf(&gamma, &failure_address, &input));
+
+            // Check that an ordinary signature does not verify as a VRF proof.
+            let signature = Signature::sign(&private_key, &input, rng)?;
+            assert!(!signature.verify_vrf(&gamma, &address, &input));
+        }
+        Ok(())
+    }
+}
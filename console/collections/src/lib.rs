@@ -22,3 +22,4 @@ pub use snarkvm_console_types::prelude::*;
 
 pub mod kary_merkle_tree;
 pub mod merkle_tree;
+pub mod sparse_merkle_tree;
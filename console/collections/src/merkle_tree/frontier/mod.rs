@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An append-only Merkle tree of depth `DEPTH`, which only stores the frontier of the tree.
+///
+/// Unlike `MerkleTree`, the empty subtrees are hashed level by level from the empty hash,
+/// so the shape of the tree does not depend on the number of leaves. This allows a circuit
+/// to append a leaf given only the old root and the Merkle path of the next empty leaf.
+#[derive(Clone)]
+pub struct MerkleFrontier<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+{
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the Merkle tree.
+    root: PH::Hash,
+    /// The roots of the empty subtrees, from the leaf level to the level below the root.
+    empty_hashes: Vec<Field<E>>,
+    /// The most recent left child on each level, from the leaf level to the level below the root.
+    frontier: Vec<Field<E>>,
+    /// The number of hashed leaves in the tree.
+    number_of_leaves: u64,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleFrontier<E, LH, PH, DEPTH>
+{
+    /// Initializes a new empty Merkle frontier.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the roots of the empty subtrees on each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize);
+        let mut empty_hash = path_hasher.hash_empty()?;
+        for _ in 0..DEPTH {
+            empty_hashes.push(empty_hash);
+            empty_hash = path_hasher.hash_children(&empty_hash, &empty_hash)?;
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hash,
+            frontier: empty_hashes.clone(),
+            empty_hashes,
+            number_of_leaves: 0,
+        })
+    }
+
+    /// Returns the Merkle path of the next empty leaf.
+    /// This path is the witness for appending the next leaf in a circuit.
+    pub fn next_path(&self) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the Merkle tree is not full.
+        ensure!((self.number_of_leaves as u128) < (1u128 << DEPTH), "The Merkle tree is full");
+
+        // On each level, the sibling is the frontier node if the path goes right, and the empty subtree otherwise.
+        let siblings = (0..DEPTH as usize)
+            .map(|i| match (self.number_of_leaves >> i) & 1 == 0 {
+                true => self.empty_hashes[i],
+                false => self.frontier[i],
+            })
+            .collect();
+
+        MerklePath::try_from((U64::new(self.number_of_leaves), siblings))
+    }
+
+    /// Appends the given leaf to the Merkle tree.
+    pub fn append(&mut self, leaf: &LH::Leaf) -> Result<()> {
+        // Ensure the Merkle tree is not full.
+        ensure!((self.number_of_leaves as u128) < (1u128 << DEPTH), "The Merkle tree is full");
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let mut current_hash = self.leaf_hasher.hash_leaf(leaf)?;
+
+        // Compute the hashes on each level, storing the new left children in the frontier.
+        for i in 0..DEPTH as usize {
+            current_hash = match (self.number_of_leaves >> i) & 1 == 0 {
+                true => {
+                    self.frontier[i] = current_hash;
+                    self.path_hasher.hash_children(&current_hash, &self.empty_hashes[i])?
+                }
+                false => self.path_hasher.hash_children(&self.frontier[i], &current_hash)?,
+            };
+        }
+
+        // Update the root and the number of leaves.
+        self.root = current_hash;
+        self.number_of_leaves += 1;
+        Ok(())
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Runs the following test:
+    /// 1. Append each leaf to the Merkle frontier.
+    /// 2. Check that the path of the next empty leaf proves the appended leaf under the new root.
+    fn check_merkle_frontier<
+        E: Environment,
+        LH: LeafHash<Hash = PH::Hash>,
+        PH: PathHash<Hash = Field<E>>,
+        const DEPTH: u8,
+    >(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+    ) -> Result<()> {
+        let mut frontier = MerkleFrontier::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            // Retrieve the path of the next empty leaf.
+            let path = frontier.next_path()?;
+            assert_eq!(*path.leaf_index(), i as u64);
+            // Append the leaf.
+            let old_root = *frontier.root();
+            frontier.append(leaf)?;
+            assert_eq!(frontier.number_of_leaves(), i as u64 + 1);
+            // Ensure the path proves the leaf under the new root, but not the old root.
+            assert!(frontier.verify(&path, frontier.root(), leaf));
+            assert!(!frontier.verify(&path, &old_root, leaf));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merkle_frontier_bhp() -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let mut rng = TestRng::default();
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in [0, 1, 2, 3, 4, 5, 8, 9] {
+            let leaves =
+                (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+            check_merkle_frontier::<CurrentEnvironment, LH, PH, 4>(&leaf_hasher, &path_hasher, &leaves)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_merkle_frontier_poseidon() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let mut rng = TestRng::default();
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in [0, 1, 2, 3, 4, 5, 8, 9, 16] {
+            let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+            check_merkle_frontier::<CurrentEnvironment, LH, PH, 4>(&leaf_hasher, &path_hasher, &leaves)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_merkle_frontier_matches_full_tree() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let mut rng = TestRng::default();
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // A full tree has no padding, so its root matches the full `MerkleTree` root.
+        let leaves = (0..8).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        let mut frontier = MerkleFrontier::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher)?;
+        leaves.iter().try_for_each(|leaf| frontier.append(leaf))?;
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        assert_eq!(frontier.root(), merkle_tree.root());
+
+        // Ensure the full tree rejects further leaves.
+        assert!(frontier.next_path().is_err());
+        assert!(frontier.append(&leaves[0]).is_err());
+        Ok(())
+    }
+}
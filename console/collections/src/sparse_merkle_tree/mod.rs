@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod path;
+pub use path::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use aleo_std::prelude::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A sparse Merkle tree of depth `DEPTH`, which maps field keys to field values.
+///
+/// Each key is stored at the leaf given by all bits of the key, so `DEPTH` must equal the bit width of a field
+/// element, and distinct keys never share a leaf. Each leaf hash is the hash of `[key, value]`.
+/// Empty leaves take the empty hash, which allows proving that a key is **not** in the tree.
+#[derive(Clone)]
+pub struct SparseMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash, Leaf = Vec<Field<E>>>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the Merkle tree.
+    root: PH::Hash,
+    /// The roots of the empty subtrees, from the leaf level to the root level.
+    empty_hashes: Vec<Field<E>>,
+    /// The key-value pairs in the tree, indexed by their leaf position.
+    leaves: BTreeMap<Vec<bool>, (Field<E>, Field<E>)>,
+    /// The non-empty nodes of the tree, indexed by their level and position.
+    nodes: BTreeMap<(u8, Vec<bool>), Field<E>>,
+}
+
+impl<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash, Leaf = Vec<Field<E>>>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Initializes a new empty sparse Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is valid.
+        check_depth::<E, DEPTH>()?;
+
+        // Compute the roots of the empty subtrees on each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for i in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[i], &empty_hashes[i])?);
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[DEPTH as usize],
+            empty_hashes,
+            leaves: Default::default(),
+            nodes: Default::default(),
+        })
+    }
+
+    /// Inserts the given key-value pair, overwriting the value if the key already exists.
+    pub fn insert(&mut self, key: Field<E>, value: Field<E>) -> Result<()> {
+        self.update_many(&[(key, Some(value))])
+    }
+
+    /// Removes the given key from the tree.
+    pub fn remove(&mut self, key: &Field<E>) -> Result<()> {
+        self.update_many(&[(*key, None)])
+    }
+
+    /// Applies the given updates in order, where `Some(value)` inserts and `None` removes a key.
+    /// The nodes on the paths of all updated leaves are recomputed once, from the leaf level to the root.
+    pub fn update_many(&mut self, updates: &[(Field<E>, Option<Field<E>>)]) -> Result<()> {
+        let timer = timer!("SparseMerkleTree::update_many");
+
+        // Apply the updates to a copy of the updated leaves, to ensure the tree is unchanged if an update is invalid.
+        let mut updated_leaves = BTreeMap::new();
+        for (key, value) in updates {
+            let position = position::<E, DEPTH>(key);
+            match value {
+                Some(value) => updated_leaves.insert(position, Some((*key, *value))),
+                None => {
+                    // Ensure the key exists, accounting for the preceding updates.
+                    let exists = match updated_leaves.get(&position) {
+                        Some(leaf) => leaf.is_some(),
+                        None => self.leaves.contains_key(&position),
+                    };
+                    ensure!(exists, "The key '{key}' does not exist in the sparse Merkle tree");
+                    updated_leaves.insert(position, None)
+                }
+            };
+        }
+
+        // Update the leaves and their hashes.
+        for (position, leaf) in &updated_leaves {
+            let leaf_hash = match leaf {
+                Some((key, value)) => {
+                    self.leaves.insert(position.clone(), (*key, *value));
+                    self.leaf_hasher.hash_leaf(&vec![*key, *value])?
+                }
+                None => {
+                    self.leaves.remove(position);
+                    self.empty_hashes[0]
+                }
+            };
+            self.set_node(0, position.clone(), leaf_hash);
+        }
+        lap!(timer, "Hashed {} leaves", updated_leaves.len());
+
+        // Recompute the parents of the updated nodes on each level.
+        let mut updated_nodes = updated_leaves.into_keys().collect::<BTreeSet<_>>();
+        for level in 0..DEPTH {
+            updated_nodes = updated_nodes.into_iter().map(|position| position[1..].to_vec()).collect();
+            for parent in &updated_nodes {
+                // Retrieve the children of the parent.
+                let left = self.node(level, [&[false], parent.as_slice()].concat());
+                let right = self.node(level, [&[true], parent.as_slice()].concat());
+                // Compute and store the parent hash.
+                let hash = self.path_hasher.hash_children(&left, &right)?;
+                self.set_node(level + 1, parent.clone(), hash);
+            }
+        }
+        lap!(timer, "Hashed {} levels", DEPTH);
+
+        // Update the root.
+        self.root = self.node(DEPTH, vec![]);
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Returns the Merkle path for the given key.
+    /// If the key is in the tree, the path proves membership, otherwise it proves non-membership.
+    pub fn prove(&self, key: &Field<E>) -> Result<SparseMerklePath<E, DEPTH>> {
+        let position = position::<E, DEPTH>(key);
+
+        // Collect the sibling of the node on each level, from the leaf level to the root.
+        let siblings = (0..DEPTH)
+            .map(|level| {
+                let mut sibling = position[level as usize..].to_vec();
+                sibling[0] = !sibling[0];
+                self.node(level, sibling)
+            })
+            .collect::<Vec<_>>();
+
+        SparseMerklePath::try_from(siblings)
+    }
+
+    /// Returns the value for the given key, if it exists.
+    pub fn get(&self, key: &Field<E>) -> Option<&Field<E>> {
+        self.leaves.get(&position::<E, DEPTH>(key)).map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the given key is in the tree.
+    pub fn contains_key(&self, key: &Field<E>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the number of keys in the tree.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the node at the given level and position.
+    fn node(&self, level: u8, position: Vec<bool>) -> Field<E> {
+        self.nodes.get(&(level, position)).copied().unwrap_or(self.empty_hashes[level as usize])
+    }
+
+    /// Stores the node at the given level and position, only keeping non-empty nodes.
+    fn set_node(&mut self, level: u8, position: Vec<bool>, hash: Field<E>) {
+        match hash == self.empty_hashes[level as usize] {
+            true => self.nodes.remove(&(level, position)),
+            false => self.nodes.insert((level, position), hash),
+        };
+    }
+}
+
+/// Ensures the given sparse Merkle tree depth is valid.
+fn check_depth<E: Environment, const DEPTH: u8>() -> Result<()> {
+    // Ensure the Merkle tree depth covers every bit of a key, as a shorter depth would map distinct keys to one leaf.
+    ensure!(
+        DEPTH as usize == Field::<E>::size_in_bits(),
+        "Sparse Merkle tree depth must be equal to {}",
+        Field::<E>::size_in_bits()
+    );
+    Ok(())
+}
+
+/// Returns the leaf position of the given key, as the little-endian bits of the key.
+fn position<E: Environment, const DEPTH: u8>(key: &Field<E>) -> Vec<bool> {
+    key.to_bits_le()
+}
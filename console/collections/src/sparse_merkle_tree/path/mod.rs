@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseMerklePath<E: Environment, const DEPTH: u8> {
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<Vec<Field<E>>> for SparseMerklePath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from(siblings: Vec<Field<E>>) -> Result<Self> {
+        // Ensure the Merkle tree depth is valid.
+        check_depth::<E, DEPTH>()?;
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        // Return the Merkle path.
+        Ok(Self { siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle path proves the given key-value pair is in the tree with the given root.
+    pub fn verify_membership<LH: LeafHash<Hash = PH::Hash, Leaf = Vec<Field<E>>>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        value: &Field<E>,
+    ) -> bool {
+        // Compute the leaf hash.
+        let leaf_hash = match leaf_hasher.hash_leaf(&vec![*key, *value]) {
+            Ok(leaf_hash) => leaf_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+        self.verify(path_hasher, root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the Merkle path proves the given key is **not** in the tree with the given root.
+    pub fn verify_non_membership<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+    ) -> bool {
+        // Compute the empty hash.
+        let empty_hash = match path_hasher.hash_empty() {
+            Ok(empty_hash) => empty_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the empty Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+        self.verify(path_hasher, root, key, empty_hash)
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root, key, and leaf hash.
+    fn verify<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        leaf_hash: Field<E>,
+    ) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash;
+
+        // Check levels between leaf level and root.
+        // If the key bit is `false`, then the ordering is (current_hash, sibling_hash).
+        // If the key bit is `true`, then the ordering is (sibling_hash, current_hash).
+        for (bit, sibling_hash) in position::<E, DEPTH>(key).into_iter().zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let (left, right) = match bit {
+                false => (current_hash, *sibling_hash),
+                true => (*sibling_hash, current_hash),
+            };
+            // Update the current hash for the next level.
+            match path_hasher.hash_children(&left, &right) {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash the Merkle path during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hash == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for SparseMerklePath<E, DEPTH> {
+    /// Reads in a sparse Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the Merkle path siblings.
+        let siblings =
+            (0..DEPTH).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle path.
+        Self::try_from(siblings).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for SparseMerklePath<E, DEPTH> {
+    /// Writes the sparse Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the Merkle path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
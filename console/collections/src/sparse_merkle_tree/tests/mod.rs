@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = Poseidon<CurrentEnvironment, 4>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+const ITERATIONS: usize = 10;
+const DEPTH: u8 = 253;
+
+fn setup() -> Result<(LH, PH)> {
+    Ok((LH::setup("AleoMerkleTreeTest0")?, PH::setup("AleoMerkleTreeTest1")?))
+}
+
+/// Runs the following test:
+/// 1. Insert the key-value pairs into the sparse Merkle tree.
+/// 2. Check that the Merkle proof for every key proves membership, and not non-membership.
+/// 3. Check that the Merkle proof for an absent key proves non-membership, and not membership.
+fn check_sparse_merkle_tree<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+    let (leaf_hasher, path_hasher) = setup()?;
+    let mut tree = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+
+    // Insert the key-value pairs.
+    let entries = (0..ITERATIONS).map(|_| (Uniform::rand(rng), Uniform::rand(rng))).collect::<Vec<_>>();
+    for (key, value) in &entries {
+        tree.insert(*key, *value)?;
+    }
+    assert_eq!(tree.len(), ITERATIONS);
+
+    // Check each key in the tree.
+    for (key, value) in &entries {
+        assert_eq!(tree.get(key), Some(value));
+        let path = tree.prove(key)?;
+        assert!(path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, value));
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, &Uniform::rand(rng)));
+        assert!(!path.verify_non_membership(&path_hasher, tree.root(), key));
+        // Check the path serialization.
+        assert_eq!(path, SparseMerklePath::read_le(&path.to_bytes_le()?[..])?);
+    }
+
+    // Check an absent key.
+    let key = Uniform::rand(rng);
+    assert!(!tree.contains_key(&key));
+    let path = tree.prove(&key)?;
+    assert!(path.verify_non_membership(&path_hasher, tree.root(), &key));
+    assert!(!path.verify_non_membership(&path_hasher, &Field::zero(), &key));
+    assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), &key, &Uniform::rand(rng)));
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree() -> Result<()> {
+    let mut rng = TestRng::default();
+    check_sparse_merkle_tree::<DEPTH>(&mut rng)
+}
+
+#[test]
+fn test_sparse_merkle_tree_root() -> Result<()> {
+    let (leaf_hasher, path_hasher) = setup()?;
+    let mut tree = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+
+    // Compute the roots of the empty subtrees on each level.
+    let mut empty_hashes = vec![path_hasher.hash_empty()?];
+    for i in 0..DEPTH as usize {
+        empty_hashes.push(path_hasher.hash_children(&empty_hashes[i], &empty_hashes[i])?);
+    }
+    assert_eq!(tree.empty_hash(), &empty_hashes[0]);
+    assert_eq!(tree.root(), &empty_hashes[DEPTH as usize]);
+
+    // Insert a key at position 5 (0b101).
+    let (key, value) = (Field::from_u8(5), Field::from_u8(10));
+    tree.insert(key, value)?;
+
+    // Check the root, where a set bit places the current node on the right.
+    let mut expected = leaf_hasher.hash_leaf(&vec![key, value])?;
+    for (bit, empty_hash) in key.to_bits_le().into_iter().zip(&empty_hashes) {
+        expected = match bit {
+            true => path_hasher.hash_children(empty_hash, &expected)?,
+            false => path_hasher.hash_children(&expected, empty_hash)?,
+        };
+    }
+    assert_eq!(tree.root(), &expected);
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_update_many() -> Result<()> {
+    let mut rng = TestRng::default();
+    let (leaf_hasher, path_hasher) = setup()?;
+
+    let entries = (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect::<Vec<_>>();
+
+    // Insert the key-value pairs one at a time.
+    let mut expected = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+    entries.iter().try_for_each(|(key, value)| expected.insert(*key, *value))?;
+
+    // Insert the key-value pairs in one batch.
+    let mut candidate = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+    candidate.update_many(&entries.iter().map(|(key, value)| (*key, Some(*value))).collect::<Vec<_>>())?;
+    assert_eq!(expected.root(), candidate.root());
+
+    // Update and remove key-value pairs in one batch.
+    let (key_0, key_1) = (entries[0].0, entries[1].0);
+    let new_value = Uniform::rand(&mut rng);
+    expected.insert(key_0, new_value)?;
+    expected.remove(&key_1)?;
+    candidate.update_many(&[(key_0, Some(Uniform::rand(&mut rng))), (key_1, None), (key_0, Some(new_value))])?;
+    assert_eq!(expected.root(), candidate.root());
+    assert_eq!(candidate.get(&key_0), Some(&new_value));
+    assert!(!candidate.contains_key(&key_1));
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_remove() -> Result<()> {
+    let mut rng = TestRng::default();
+    let (leaf_hasher, path_hasher) = setup()?;
+    let mut tree = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+    let empty_root = *tree.root();
+
+    let entries = (0..ITERATIONS).map(|_| (Uniform::rand(&mut rng), Uniform::rand(&mut rng))).collect::<Vec<_>>();
+    entries.iter().try_for_each(|(key, value)| tree.insert(*key, *value))?;
+
+    // Remove each key, and check the key is proven absent.
+    for (key, _) in &entries {
+        tree.remove(key)?;
+        assert!(tree.prove(key)?.verify_non_membership(&path_hasher, tree.root(), key));
+        // Ensure removing an absent key fails.
+        assert!(tree.remove(key).is_err());
+    }
+
+    // Ensure the tree returns to the empty root, and no nodes are left behind.
+    assert!(tree.is_empty());
+    assert_eq!(tree.root(), &empty_root);
+    assert!(tree.nodes.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_no_collision() -> Result<()> {
+    let (leaf_hasher, path_hasher) = setup()?;
+    let mut tree = SparseMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher)?;
+
+    // Keys 3 (0b00011) and 19 (0b10011) share their lower 4 bits, and must not share a leaf.
+    let entries = [(Field::from_u8(3), Field::from_u8(1)), (Field::from_u8(19), Field::from_u8(2))];
+    entries.iter().try_for_each(|(key, value)| tree.insert(*key, *value))?;
+    assert_eq!(tree.len(), 2);
+
+    // Ensure both keys are proven present.
+    for (key, value) in &entries {
+        assert_eq!(tree.get(key), Some(value));
+        assert!(tree.prove(key)?.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, value));
+    }
+
+    // Ensure removing one key leaves the other in place.
+    tree.remove(&entries[1].0)?;
+    assert_eq!(tree.get(&entries[0].0), Some(&entries[0].1));
+    assert!(!tree.contains_key(&entries[1].0));
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_invalid_depth() -> Result<()> {
+    let (leaf_hasher, path_hasher) = setup()?;
+    assert!(SparseMerkleTree::<CurrentEnvironment, LH, PH, 0>::new(&leaf_hasher, &path_hasher).is_err());
+    // Ensure a depth shorter than the key width is rejected, as it would map distinct keys to one leaf.
+    assert!(SparseMerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerkleTree::<CurrentEnvironment, LH, PH, 252>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerklePath::<CurrentEnvironment, 4>::try_from(vec![Field::zero(); 4]).is_err());
+    assert!(SparseMerkleTree::<CurrentEnvironment, LH, PH, 254>::new(&leaf_hasher, &path_hasher).is_err());
+    Ok(())
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{bail, Result};
+use bech32::{FromBase32, ToBase32};
+use serde::{de, Deserialize, Deserializer, Serializer};
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+/// The canonical string encoding of an on-wire object, as the bech32m encoding of its bytes
+/// under a human-readable prefix that names the object type (i.e. `record1...`, `proof1...`).
+pub trait Bech32m: ToBytes + FromBytes + Sized {
+    /// The human-readable prefix of the encoding.
+    const PREFIX: &'static str;
+
+    /// Returns the bech32m string encoding of the object.
+    fn to_bech32m(&self) -> Result<String> {
+        // Encode the bytes into bech32m.
+        Ok(bech32::encode(Self::PREFIX, self.to_bytes_le()?.to_base32(), bech32::Variant::Bech32m)?)
+    }
+
+    /// Returns the object from its bech32m string encoding.
+    fn from_bech32m(string: &str) -> Result<Self> {
+        // Decode the string from bech32m.
+        let (hrp, data, variant) = bech32::decode(string)?;
+        if hrp != Self::PREFIX {
+            bail!("Failed to decode '{}': '{hrp}' is an invalid prefix", Self::PREFIX)
+        } else if data.is_empty() {
+            bail!("Failed to decode '{}': data field is empty", Self::PREFIX)
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found a '{}' that is not bech32m encoded: {string}", Self::PREFIX);
+        }
+        // Decode the data from u5 to u8, and into the object.
+        Self::from_bytes_le(&Vec::from_base32(&data)?)
+    }
+}
+
+/// Serializes an object as its bech32m string in human-readable formats, and as bytes otherwise.
+///
+/// This is intended for `#[serde(with = "bech32m_serde")]` on fields whose type has a structured default encoding,
+/// such as transactions and transitions, so services can exchange them in the canonical string form.
+pub mod bech32m_serde {
+    use super::*;
+
+    /// Serializes the object into a bech32m string or bytes.
+    pub fn serialize<T: Bech32m, S: Serializer>(object: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.serialize_str(&object.to_bech32m().map_err(serde::ser::Error::custom)?),
+            false => ToBytesSerializer::serialize_with_size_encoding(object, serializer),
+        }
+    }
+
+    /// Deserializes the object from a bech32m string or bytes.
+    pub fn deserialize<'de, T: Bech32m, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        match deserializer.is_human_readable() {
+            true => T::from_bech32m(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<T>::deserialize_with_size_encoding(deserializer, T::PREFIX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+    #[derive(Debug, PartialEq)]
+    struct Sample(u64);
+
+    impl FromBytes for Sample {
+        fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+            Ok(Self(u64::read_le(reader)?))
+        }
+    }
+
+    impl ToBytes for Sample {
+        fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+            self.0.write_le(writer)
+        }
+    }
+
+    impl Bech32m for Sample {
+        const PREFIX: &'static str = "sample";
+    }
+
+    #[test]
+    fn test_bech32m() -> Result<()> {
+        for value in [0, 1, 12345, u64::MAX] {
+            let expected = Sample(value);
+            let string = expected.to_bech32m()?;
+            assert_eq!("sample", string.split('1').next().unwrap());
+            assert_eq!(expected, Sample::from_bech32m(&string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bech32m_rejects_invalid_strings() -> Result<()> {
+        let string = Sample(7).to_bech32m()?;
+        // Ensure an incorrect prefix fails.
+        let other = bech32::encode("other", 7u64.to_bytes_le()?.to_base32(), bech32::Variant::Bech32m)?;
+        assert!(Sample::from_bech32m(&other).is_err());
+        // Ensure a bech32 (not bech32m) encoding fails.
+        let legacy = bech32::encode(Sample::PREFIX, 7u64.to_bytes_le()?.to_base32(), bech32::Variant::Bech32)?;
+        assert!(Sample::from_bech32m(&legacy).is_err());
+        // Ensure an empty data field fails.
+        assert!(Sample::from_bech32m("sample1").is_err());
+        // Ensure a corrupted checksum fails.
+        let mut corrupted = string.clone();
+        let last = if corrupted.pop() == Some('q') { 'p' } else { 'q' };
+        corrupted.push(last);
+        assert!(Sample::from_bech32m(&corrupted).is_err());
+        Ok(())
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bech32m;
+pub use bech32m::{bech32m_serde, Bech32m};
+
 mod or_halt;
 pub use or_halt::OrHalt;
 
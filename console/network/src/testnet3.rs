@@ -45,6 +45,8 @@ lazy_static! {
     pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
+    /// The VRF domain as a constant field element.
+    pub static ref VRF_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoVRF0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
@@ -242,6 +244,11 @@ impl Network for Testnet3 {
         *SERIAL_NUMBER_DOMAIN
     }
 
+    /// Returns the VRF domain as a constant field element.
+    fn vrf_domain() -> Field<Self> {
+        *VRF_DOMAIN
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
         BHP_256.commit(input, randomizer)
@@ -14,7 +14,12 @@
 
 use super::*;
 
-static CIPHERTEXT_PREFIX: &str = "ciphertext";
+const CIPHERTEXT_PREFIX: &str = "ciphertext";
+
+impl<N: Network> Bech32m for Ciphertext<N> {
+    /// The ciphertext string prefix.
+    const PREFIX: &'static str = CIPHERTEXT_PREFIX;
+}
 
 impl<N: Network> Parser for Ciphertext<N> {
     /// Parses a string into an ciphertext.
@@ -135,4 +140,20 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bech32m() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new ciphertext.
+            let expected = Ciphertext::<CurrentNetwork>((0..100).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>());
+
+            // Ensure the canonical encoding matches the string representation.
+            let candidate = expected.to_bech32m()?;
+            assert_eq!(expected.to_string(), candidate);
+            assert_eq!(expected, Ciphertext::<CurrentNetwork>::from_bech32m(&candidate)?);
+        }
+        Ok(())
+    }
 }
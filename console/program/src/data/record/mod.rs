@@ -27,6 +27,7 @@ mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod scan;
 mod serial_number;
 mod serialize;
 mod tag;
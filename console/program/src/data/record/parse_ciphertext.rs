@@ -14,7 +14,12 @@
 
 use super::*;
 
-static RECORD_CIPHERTEXT_PREFIX: &str = "record";
+const RECORD_CIPHERTEXT_PREFIX: &str = "record";
+
+impl<N: Network> Bech32m for Record<N, Ciphertext<N>> {
+    /// The record ciphertext string prefix.
+    const PREFIX: &'static str = RECORD_CIPHERTEXT_PREFIX;
+}
 
 impl<N: Network> Parser for Record<N, Ciphertext<N>> {
     /// Parses a string into an ciphertext.
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns the records owned by the given view key, decrypted into plaintext,
+    /// along with the index of each record in the given list of records.
+    /// The records are checked and decrypted in parallel, and records that fail to decrypt are skipped.
+    pub fn scan(view_key: &ViewKey<N>, records: &[Self]) -> Vec<(usize, Record<N, Plaintext<N>>)> {
+        // Compute the address once, for all of the records.
+        let address = view_key.to_address();
+        let address_x_coordinate = address.to_x_coordinate();
+
+        cfg_iter!(records)
+            .enumerate()
+            .filter_map(|(index, record)| {
+                // Skip the record if it is not owned by the view key.
+                if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                    return None;
+                }
+                // Compute the record view key.
+                let record_view_key = (record.nonce * **view_key).to_x_coordinate();
+                // Decrypt the record, and ensure the record owner matches the address.
+                match record.decrypt_symmetric_unchecked(&record_view_key) {
+                    Ok(record) if **record.owner() == address => Some((index, record)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns a new encrypted record for the given owner.
+    fn sample_record<N: Network>(owner: Owner<N, Plaintext<N>>, rng: &mut TestRng) -> Result<Record<N, Ciphertext<N>>> {
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner,
+            data: IndexMap::from_iter(vec![(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            nonce: N::g_scalar_multiply(&randomizer),
+        };
+        record.encrypt(randomizer)
+    }
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the view key and address of the scanner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        // Sample the address of another account.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Sample records owned by both accounts, with public and private owners.
+        let mut records = Vec::with_capacity(ITERATIONS);
+        let mut expected = Vec::new();
+        for index in 0..ITERATIONS {
+            let owner = match index % 4 {
+                0 => Owner::Public(address),
+                1 => Owner::Private(Plaintext::from(Literal::Address(address))),
+                2 => Owner::Public(other_address),
+                _ => Owner::Private(Plaintext::from(Literal::Address(other_address))),
+            };
+            let record = sample_record(owner, &mut rng)?;
+            if index % 4 < 2 {
+                expected.push((index, record.decrypt(&view_key)?));
+            }
+            records.push(record);
+        }
+
+        // Ensure the scan returns exactly the owned records, in order.
+        assert_eq!(expected, Record::scan(&view_key, &records));
+        // Ensure the scan of no records returns no records.
+        assert!(Record::scan(&view_key, &[]).is_empty());
+        Ok(())
+    }
+}
@@ -19,7 +19,13 @@ impl<E: Environment> Parser for StringType<E> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the starting and ending quote '"' keyword from the string.
-        let (string, value) = string_parser::parse_string(string)?;
+        let (string, value) = map_res(string_parser::parse_string, |value: String| {
+            // Ensure the string is within the allowed capacity, instead of halting in `StringType::new`.
+            match value.len() <= E::MAX_STRING_BYTES as usize {
+                true => Ok(value),
+                false => Err(error(format!("String literal exceeds maximum length of {} bytes.", E::MAX_STRING_BYTES))),
+            }
+        })(string)?;
 
         Ok((string, StringType::new(&value)))
     }
@@ -103,4 +109,14 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_too_long() {
+        // Ensure a string at the maximum length succeeds.
+        let string = "a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize);
+        assert!(StringType::<CurrentEnvironment>::parse(&format!("\"{string}\"")).is_ok());
+        // Ensure a string beyond the maximum length fails, without halting.
+        let string = "a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize + 1);
+        assert!(StringType::<CurrentEnvironment>::parse(&format!("\"{string}\"")).is_err());
+    }
 }
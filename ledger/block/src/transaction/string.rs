@@ -14,6 +14,11 @@
 
 use super::*;
 
+impl<N: Network> Bech32m for Transaction<N> {
+    /// The transaction string prefix.
+    const PREFIX: &'static str = "tx";
+}
+
 impl<N: Network> FromStr for Transaction<N> {
     type Err = Error;
 
@@ -36,3 +41,31 @@ impl<N: Network> Display for Transaction<N> {
         write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32m() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ]
+        .into_iter()
+        {
+            // Check the canonical string encoding.
+            let candidate = expected.to_bech32m()?;
+            assert_eq!("tx", candidate.split('1').next().unwrap());
+            assert_eq!(expected, Transaction::from_bech32m(&candidate)?);
+
+            // Check the serde integration.
+            let value = bech32m_serde::serialize(&expected, serde_json::value::Serializer)?;
+            assert_eq!(value.as_str().unwrap(), candidate);
+            assert_eq!(expected, bech32m_serde::deserialize::<Transaction<_>, _>(value)?);
+        }
+        Ok(())
+    }
+}
@@ -14,6 +14,11 @@
 
 use super::*;
 
+impl<N: Network> Bech32m for Transition<N> {
+    /// The transition string prefix.
+    const PREFIX: &'static str = "transition";
+}
+
 impl<N: Network> FromStr for Transition<N> {
     type Err = Error;
 
@@ -36,3 +41,28 @@ impl<N: Network> Display for Transition<N> {
         write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32m() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition.
+        let expected = crate::transition::test_helpers::sample_transition(rng);
+
+        // Check the canonical string encoding.
+        let candidate = expected.to_bech32m()?;
+        assert_eq!("transition", candidate.split('1').next().unwrap());
+        assert_eq!(expected, Transition::from_bech32m(&candidate)?);
+
+        // Check the serde integration.
+        let value = bech32m_serde::serialize(&expected, serde_json::value::Serializer)?;
+        assert_eq!(value.as_str().unwrap(), candidate);
+        assert_eq!(expected, bech32m_serde::deserialize::<Transition<_>, _>(value)?);
+
+        Ok(())
+    }
+}
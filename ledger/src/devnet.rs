@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+};
+use ledger_committee::MIN_VALIDATOR_STAKE;
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use synthesizer::vm::{GenesisBuilder, VM};
+
+use aleo_std::StorageMode;
+use anyhow::Result;
+
+/// A local development network, with an in-memory ledger and a genesis committee of sampled validators.
+pub struct Devnet<N: Network> {
+    /// The private keys of the validators, where the first validator is the genesis account.
+    private_keys: Vec<PrivateKey<N>>,
+    /// The in-memory ledger.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+}
+
+impl<N: Network> Devnet<N> {
+    /// The number of validators in the devnet.
+    pub const NUM_VALIDATORS: usize = 4;
+
+    /// Initializes a new devnet, with freshly sampled validators.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        // Sample the validators.
+        let private_keys = (0..Self::NUM_VALIDATORS).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        // Construct the genesis builder, with the first validator as the genesis account.
+        let mut builder = GenesisBuilder::new(private_keys[0]);
+        for private_key in &private_keys {
+            builder = builder.add_validator(Address::try_from(private_key)?, MIN_VALIDATOR_STAKE)?;
+        }
+        Self::from_builder(private_keys, &builder, rng)
+    }
+
+    /// Initializes a new devnet from the given genesis builder, with the given private keys.
+    /// The initial programs are deployed in one block each, signed by the first validator,
+    /// and the initial mapping entries are written once the programs are deployed.
+    pub fn from_builder<R: Rng + CryptoRng>(
+        private_keys: Vec<PrivateKey<N>>,
+        builder: &GenesisBuilder<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Retrieve the first validator, which signs the blocks after genesis.
+        let signer = *private_keys.first().ok_or_else(|| anyhow!("The devnet requires at least one validator"))?;
+
+        // Initialize the VM.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        // Construct the genesis block.
+        let genesis_block = builder.build(&vm, rng)?;
+        // Load the ledger.
+        let ledger = Ledger::load(genesis_block, StorageMode::Production)?;
+
+        // Deploy the initial programs in order, so that each program may import the ones before it.
+        for program in builder.programs() {
+            let transaction = ledger.vm().deploy(builder.private_key(), program, None, 0, None, rng)?;
+            let block = ledger.prepare_advance_to_next_beacon_block(&signer, vec![], vec![], vec![transaction], rng)?;
+            ledger.check_next_block(&block, rng)?;
+            ledger.advance_to_next_block(&block)?;
+        }
+        // Write the initial mapping entries.
+        builder.insert_mapping_entries(ledger.vm())?;
+
+        Ok(Self { private_keys, ledger })
+    }
+
+    /// Returns the private keys of the validators.
+    pub fn private_keys(&self) -> &[PrivateKey<N>] {
+        &self.private_keys
+    }
+
+    /// Returns the in-memory ledger.
+    pub const fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+}
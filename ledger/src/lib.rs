@@ -34,6 +34,9 @@ pub use ledger_test_helpers;
 mod helpers;
 pub use helpers::*;
 
+mod devnet;
+pub use devnet::*;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
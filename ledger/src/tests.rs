@@ -1254,3 +1254,64 @@ fn test_split_candidate_solutions() {
             split_candidate_solutions(candidate_solutions, max_solutions, |candidate| candidate % 2 == 0);
     }
 }
+
+#[test]
+fn test_devnet() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the devnet.
+    let devnet = crate::Devnet::<CurrentNetwork>::new(rng).unwrap();
+    assert_eq!(devnet.private_keys().len(), crate::Devnet::<CurrentNetwork>::NUM_VALIDATORS);
+
+    // Ensure the genesis committee consists of the devnet validators.
+    let committee = devnet.ledger().latest_committee().unwrap();
+    for private_key in devnet.private_keys() {
+        assert!(committee.is_committee_member(Address::try_from(private_key).unwrap()));
+    }
+    assert_eq!(devnet.ledger().latest_height(), 0);
+}
+
+#[test]
+fn test_devnet_with_programs() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a mapping.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program registry.aleo;
+mapping entries:
+    key as u8.public;
+    value as u64.public;
+function twice:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+    let mapping_name = Identifier::from_str("entries").unwrap();
+    let (key, value) = (Plaintext::from_str("1u8").unwrap(), Value::from_str("100u64").unwrap());
+
+    // Construct the genesis builder, with the program and a mapping entry.
+    let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+    let mut builder = synthesizer::vm::GenesisBuilder::new(private_keys[0]);
+    for private_key in &private_keys {
+        builder = builder
+            .add_validator(Address::try_from(private_key).unwrap(), ledger_committee::MIN_VALIDATOR_STAKE)
+            .unwrap();
+    }
+    let builder = builder
+        .add_program(program.clone())
+        .unwrap()
+        .add_mapping_entry(*program.id(), mapping_name, key.clone(), value.clone())
+        .unwrap();
+
+    // Initialize the devnet.
+    let devnet = crate::Devnet::from_builder(private_keys, &builder, rng).unwrap();
+    let ledger = devnet.ledger();
+
+    // Ensure the program is deployed, and the mapping entry is readable.
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.get_program(*program.id()).unwrap(), program);
+    let candidate = ledger.vm().finalize_store().get_value_confirmed(*program.id(), mapping_name, &key).unwrap();
+    assert_eq!(candidate, Some(value));
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use console::{network::Testnet3, prelude::*};
+use synthesizer_program::Instruction;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Read the instruction, which must never panic.
+    let Ok(instruction) = Instruction::<Testnet3>::read_le(data) else { return };
+    // Ensure the instruction round-trips through its byte and string representations.
+    let bytes = instruction.to_bytes_le().expect("Failed to write a decoded instruction");
+    assert_eq!(instruction, Instruction::<Testnet3>::read_le(&bytes[..]).expect("Failed to read an encoded instruction"));
+    assert_eq!(instruction, Instruction::<Testnet3>::from_str(&instruction.to_string()).expect("Failed to reparse"));
+});
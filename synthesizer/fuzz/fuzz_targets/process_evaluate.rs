@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use circuit::AleoV0;
+use console::{account::PrivateKey, network::Testnet3, prelude::*, program::Value};
+use synthesizer_process::Process;
+use synthesizer_program::Program;
+
+use libfuzzer_sys::fuzz_target;
+use rand::{rngs::StdRng, SeedableRng};
+use std::sync::OnceLock;
+
+type CurrentNetwork = Testnet3;
+type CurrentAleo = AleoV0;
+
+/// The separator between the program and its inputs in a fuzz input.
+const SEPARATOR: &str = "\n---\n";
+
+/// Returns the process with the standard programs loaded, which is shared across runs.
+fn process() -> &'static Process<CurrentNetwork> {
+    static PROCESS: OnceLock<Process<CurrentNetwork>> = OnceLock::new();
+    PROCESS.get_or_init(|| Process::load().expect("Failed to load the process"))
+}
+
+// The fuzz input is a program, followed by the separator, followed by one input value per line.
+// The first function of the program is evaluated on the inputs.
+fuzz_target!(|data: &[u8]| {
+    let Ok(string) = std::str::from_utf8(data) else { return };
+    let Some((program, inputs)) = string.split_once(SEPARATOR) else { return };
+
+    // Parse the program and its inputs.
+    let Ok(program) = Program::<CurrentNetwork>::from_str(program) else { return };
+    let Ok(inputs) = inputs.lines().map(Value::<CurrentNetwork>::from_str).collect::<Result<Vec<_>>>() else { return };
+    let Some(function_name) = program.functions().keys().next().copied() else { return };
+
+    // Add the program to a fresh copy of the process.
+    let mut process = process().clone();
+    if process.add_program(&program).is_err() {
+        return;
+    }
+
+    // Authorize the call with a deterministic private key.
+    let rng = &mut StdRng::seed_from_u64(data.len() as u64);
+    let Ok(private_key) = PrivateKey::<CurrentNetwork>::new(rng) else { return };
+    let Ok(authorization) =
+        process.authorize::<CurrentAleo, _>(&private_key, *program.id(), function_name, inputs.iter(), rng)
+    else {
+        return;
+    };
+
+    // Evaluate the function, which reports halts as errors and must never panic.
+    let _ = process.evaluate::<CurrentAleo>(authorization);
+});
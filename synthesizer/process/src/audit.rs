@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ValueType;
+use synthesizer_program::{CallOperator, InstructionTrait, Opcode, Operand};
+
+use indexmap::IndexSet;
+
+/// An instruction flagged by the timing audit, as it operates on secret data with a variable-time primitive.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TimingFinding<N: Network> {
+    /// The name of the function or closure containing the instruction.
+    resource: Identifier<N>,
+    /// The index of the instruction in its function or closure.
+    index: usize,
+    /// The instruction.
+    instruction: Instruction<N>,
+    /// The reason the instruction is variable-time.
+    reason: &'static str,
+}
+
+impl<N: Network> TimingFinding<N> {
+    /// Returns the name of the function or closure containing the instruction.
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the index of the instruction in its function or closure.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the instruction.
+    pub const fn instruction(&self) -> &Instruction<N> {
+        &self.instruction
+    }
+
+    /// Returns the reason the instruction is variable-time.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl<N: Network> Display for TimingFinding<N> {
+    /// Prints the finding as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "'{}' instruction {} ({}): {}", self.resource, self.index, self.instruction, self.reason)
+    }
+}
+
+/// The report of a timing audit over a function, as produced by `Process::audit_timing`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TimingAudit<N: Network> {
+    /// The instructions that use a variable-time primitive on secret data.
+    findings: Vec<TimingFinding<N>>,
+    /// A description of how evaluation diverged between the two inputs, if it did.
+    divergence: Option<String>,
+}
+
+impl<N: Network> TimingAudit<N> {
+    /// Returns the instructions that use a variable-time primitive on secret data.
+    pub fn findings(&self) -> &[TimingFinding<N>] {
+        &self.findings
+    }
+
+    /// Returns a description of how evaluation diverged between the two inputs, if it did.
+    pub fn divergence(&self) -> Option<&str> {
+        self.divergence.as_deref()
+    }
+
+    /// Returns `true` if the audit found no variable-time behavior.
+    pub fn is_constant_time(&self) -> bool {
+        self.findings.is_empty() && self.divergence.is_none()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Audits the evaluation of a function for timing side-channels, using two authorizations
+    /// of the same function that differ only in their secret inputs.
+    ///
+    /// The audit reports every instruction that applies a variable-time primitive (such as division,
+    /// exponentiation, inversion, branching selection, or scalar multiplication) to a register derived
+    /// from a private or record input, including inside called closures. It then evaluates both
+    /// authorizations, and reports a divergence if evaluation halts on one input but not the other.
+    ///
+    /// Note: This audit covers the native `evaluate` path used by wallets, not the circuit.
+    pub fn audit_timing<A: circuit::Aleo<Network = N>>(
+        &self,
+        first: Authorization<N>,
+        second: Authorization<N>,
+    ) -> Result<TimingAudit<N>> {
+        let timer = timer!("Process::audit_timing");
+
+        // Retrieve the top-level requests.
+        let (program_id, function_name) = {
+            let (first, second) = (first.peek_next()?, second.peek_next()?);
+            ensure!(
+                first.program_id() == second.program_id() && first.function_name() == second.function_name(),
+                "Cannot audit the timing of two different functions"
+            );
+            (*first.program_id(), *first.function_name())
+        };
+
+        // Retrieve the stack and function.
+        let stack = self.get_stack(program_id)?;
+        let function = stack.get_function_ref(&function_name)?;
+
+        // Initialize the secret registers from the private and record inputs.
+        let mut secrets = function
+            .inputs()
+            .iter()
+            .filter(|input| {
+                matches!(
+                    input.value_type(),
+                    ValueType::Private(..) | ValueType::Record(..) | ValueType::ExternalRecord(..)
+                )
+            })
+            .map(|input| input.register().locator())
+            .collect::<IndexSet<_>>();
+        // Audit the instructions of the function.
+        let mut findings = Vec::new();
+        audit_instructions(stack, function.name(), function.instructions(), &mut secrets, &mut findings)?;
+        lap!(timer, "Audit the instructions");
+
+        // Evaluate the function on both inputs, and ensure they either both succeed or both halt.
+        let divergence = match (self.evaluate::<A>(first), self.evaluate::<A>(second)) {
+            (Ok(_), Ok(_)) | (Err(_), Err(_)) => None,
+            (Err(error), Ok(_)) => Some(format!("Evaluation halted on the first input only - {error}")),
+            (Ok(_), Err(error)) => Some(format!("Evaluation halted on the second input only - {error}")),
+        };
+        lap!(timer, "Evaluate the inputs");
+
+        finish!(timer);
+
+        Ok(TimingAudit { findings, divergence })
+    }
+}
+
+/// Returns the reason the given opcode is variable-time in native evaluation, if it is.
+fn variable_time_reason(opcode: Opcode) -> Option<&'static str> {
+    match opcode {
+        Opcode::Assert(..) | Opcode::Is(..) => Some("comparison may exit early"),
+        Opcode::Cast(..) => Some("group and field recovery is variable-time"),
+        Opcode::Commit(..) | Opcode::Sign | Opcode::VRF => Some("scalar multiplication is variable-time"),
+        Opcode::Literal(opcode) => match opcode {
+            "div" | "div.wrapped" | "rem" | "rem.wrapped" | "mod" | "fixed64.div" => Some("division is variable-time"),
+            "pow" | "pow.wrapped" => Some("exponentiation depends on the exponent bits"),
+            "inv" | "sqrt" => Some("field inversion and square root are variable-time"),
+            "ternary" | "min" | "max" | "clamp" => Some("selection branches on the operands"),
+            "fixed64.exp" | "fixed64.ln" | "fixed64.sin" => Some("range reduction depends on the operand"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Audits the given instructions, recording a finding for each variable-time instruction on a secret register.
+/// The destinations of any instruction with a secret operand are marked as secret.
+fn audit_instructions<N: Network>(
+    stack: &Stack<N>,
+    resource: &Identifier<N>,
+    instructions: &[Instruction<N>],
+    secrets: &mut IndexSet<u64>,
+    findings: &mut Vec<TimingFinding<N>>,
+) -> Result<()> {
+    // Returns `true` if the given operand is derived from a secret register.
+    let is_secret = |secrets: &IndexSet<u64>, operand: &Operand<N>| match operand {
+        Operand::Register(register) => secrets.contains(&register.locator()),
+        _ => false,
+    };
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        // Skip the instruction if none of its operands are secret.
+        if !instruction.operands().iter().any(|operand| is_secret(secrets, operand)) {
+            continue;
+        }
+        // Record the instruction, if it is variable-time.
+        if let Some(reason) = variable_time_reason(instruction.opcode()) {
+            findings.push(TimingFinding { resource: *resource, index, instruction: instruction.clone(), reason });
+        }
+        // If the instruction calls a local closure, audit the closure on its secret inputs.
+        if let Instruction::Call(call) = instruction {
+            if let CallOperator::Resource(name) = call.operator() {
+                if stack.program().contains_closure(name) {
+                    let closure = stack.program().get_closure(name)?;
+                    let mut closure_secrets = closure
+                        .inputs()
+                        .iter()
+                        .zip_eq(call.operands())
+                        .filter(|(_, operand)| is_secret(secrets, operand))
+                        .map(|(input, _)| input.register().locator())
+                        .collect();
+                    audit_instructions(stack, name, closure.instructions(), &mut closure_secrets, findings)?;
+                }
+            }
+        }
+        // Mark the destinations as secret.
+        secrets.extend(instruction.destinations().iter().map(|register| register.locator()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a sample program, with a function that mixes public and private inputs.
+    fn sample_program() -> Program<CurrentNetwork> {
+        Program::from_str(
+            r"
+program timing.aleo;
+
+closure scale:
+    input r0 as u32;
+    input r1 as u32;
+    mul r0 r1 into r2;
+    div r2 3u32 into r3;
+    output r3 as u32;
+
+function mixed:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    div r1 2u32 into r2;
+    add r0 1u32 into r3;
+    div 100u32 r3 into r4;
+    call scale r1 r1 into r5;
+    call scale r0 r1 into r6;
+    output r4 as u32.private;
+    output r5 as u32.private;
+    output r6 as u32.private;
+
+function uniform:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    xor r2 r0 into r3;
+    output r3 as u32.private;",
+        )
+        .unwrap()
+    }
+
+    /// Returns the timing audit of the given function on the two given private inputs.
+    fn audit(function_name: &str, first: &str, second: &str) -> TimingAudit<CurrentNetwork> {
+        let rng = &mut TestRng::default();
+
+        let program = sample_program();
+        let process = crate::test_helpers::sample_process(&program);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function on each private input, with the same public input.
+        let mut authorize = |secret: &str| {
+            let inputs = [Value::from_str(secret).unwrap(), Value::from_str("7u32").unwrap()];
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs.iter(), rng).unwrap()
+        };
+        let (first, second) = (authorize(first), authorize(second));
+
+        process.audit_timing::<CurrentAleo>(first, second).unwrap()
+    }
+
+    #[test]
+    fn test_audit_timing_constant() {
+        let audit = audit("uniform", "1u32", "2u32");
+        assert!(audit.is_constant_time());
+    }
+
+    #[test]
+    fn test_audit_timing_findings() {
+        let audit = audit("mixed", "1u32", "2u32");
+        assert!(audit.divergence().is_none());
+
+        // Ensure only the divisions on secret data are reported, including the one inside the closure.
+        let findings = audit.findings();
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].resource().to_string(), "mixed");
+        assert_eq!(findings[0].index(), 2);
+        assert_eq!(findings[0].instruction().to_string(), "div 100u32 r3 into r4;");
+        assert_eq!(findings[1].resource().to_string(), "scale");
+        assert_eq!(findings[1].index(), 1);
+    }
+
+    #[test]
+    fn test_audit_timing_divergence() {
+        // Ensure an overflow on only one of the inputs is reported as a divergence.
+        let audit = audit("mixed", "1u32", "4294967295u32");
+        assert!(!audit.is_constant_time());
+        assert!(audit.divergence().unwrap().contains("second input only"));
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for CompiledProgram<N> {
+    /// Reads the compiled program from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid compiled program version"));
+        }
+
+        // Read the program.
+        let program = Program::read_le(&mut reader)?;
+
+        // Read the number of register types.
+        let num_register_types = u16::read_le(&mut reader)?;
+        // Read the register types.
+        let mut register_types = IndexMap::new();
+        for _ in 0..num_register_types {
+            // Read the closure or function name.
+            let name = Identifier::<N>::read_le(&mut reader)?;
+            // Read the register types.
+            let types = RegisterTypes::<N>::read_le(&mut reader)?;
+            // Ensure the name is not a duplicate.
+            if register_types.insert(name, types).is_some() {
+                return Err(error(format!("Duplicate register types for '{name}' in the compiled program")));
+            }
+        }
+
+        // Read the number of finalize types.
+        let num_finalize_types = u16::read_le(&mut reader)?;
+        // Read the finalize types.
+        let mut finalize_types = IndexMap::new();
+        for _ in 0..num_finalize_types {
+            // Read the finalize name.
+            let name = Identifier::<N>::read_le(&mut reader)?;
+            // Read the finalize types.
+            let types = FinalizeTypes::<N>::read_le(&mut reader)?;
+            // Ensure the name is not a duplicate.
+            if finalize_types.insert(name, types).is_some() {
+                return Err(error(format!("Duplicate finalize types for '{name}' in the compiled program")));
+            }
+        }
+
+        // Read the number of verifying keys.
+        let num_verifying_keys = u16::read_le(&mut reader)?;
+        // Read the verifying keys and certificates.
+        let mut verifying_keys = IndexMap::new();
+        let mut certificates = IndexMap::new();
+        for _ in 0..num_verifying_keys {
+            // Read the function name.
+            let function_name = Identifier::<N>::read_le(&mut reader)?;
+            // Read the verifying key.
+            let verifying_key = VerifyingKey::<N>::read_le(&mut reader)?;
+            // Read the certificate.
+            let certificate = Certificate::<N>::read_le(&mut reader)?;
+            // Ensure the function name is not a duplicate.
+            if verifying_keys.insert(function_name, verifying_key).is_some() {
+                return Err(error(format!("Duplicate verifying key for '{function_name}' in the compiled program")));
+            }
+            certificates.insert(function_name, certificate);
+        }
+
+        // Read the number of proving keys.
+        let num_proving_keys = u16::read_le(&mut reader)?;
+        // Read the proving keys.
+        let mut proving_keys = IndexMap::new();
+        for _ in 0..num_proving_keys {
+            // Read the function name.
+            let function_name = Identifier::<N>::read_le(&mut reader)?;
+            // Read the proving key.
+            let proving_key = ProvingKey::<N>::read_le(&mut reader)?;
+            // Ensure the function name is not a duplicate.
+            if proving_keys.insert(function_name, proving_key).is_some() {
+                return Err(error(format!("Duplicate proving key for '{function_name}' in the compiled program")));
+            }
+        }
+
+        // Return the compiled program.
+        Self::new(program, register_types, finalize_types, verifying_keys, certificates, proving_keys)
+            .map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for CompiledProgram<N> {
+    /// Writes the compiled program to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program.
+        self.program.write_le(&mut writer)?;
+        // Write the number of register types.
+        u16::try_from(self.register_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the register types.
+        for (name, register_types) in &self.register_types {
+            name.write_le(&mut writer)?;
+            register_types.write_le(&mut writer)?;
+        }
+        // Write the number of finalize types.
+        u16::try_from(self.finalize_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the finalize types.
+        for (name, finalize_types) in &self.finalize_types {
+            name.write_le(&mut writer)?;
+            finalize_types.write_le(&mut writer)?;
+        }
+        // Write the number of verifying keys.
+        u16::try_from(self.verifying_keys.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the verifying keys and certificates.
+        let certificates = self.certificates.values();
+        for ((function_name, verifying_key), certificate) in self.verifying_keys.iter().zip(certificates) {
+            function_name.write_le(&mut writer)?;
+            verifying_key.write_le(&mut writer)?;
+            certificate.write_le(&mut writer)?;
+        }
+        // Write the number of proving keys.
+        u16::try_from(self.proving_keys.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the proving keys.
+        for (function_name, proving_key) in &self.proving_keys {
+            function_name.write_le(&mut writer)?;
+            proving_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the compiled program.
+        let expected = test_helpers::sample_compiled_program();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = CompiledProgram::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected.program(), candidate.program());
+        assert!(expected.register_types() == candidate.register_types());
+        assert_eq!(expected.verifying_keys(), candidate.verifying_keys());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        assert!(CompiledProgram::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,478 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+use synthesizer_snark::Certificate;
+
+/// A program together with its analysis and the circuit keys of its functions, as produced by `Process::compile`.
+///
+/// Loading a compiled program with `Process::load_compiled` restores the register types and finalize types
+/// of the program instead of type-checking it again, and inserts the circuit keys once each verifying key
+/// is checked against its certificate, so a long-running service can restore a program without
+/// analyzing it or synthesizing its circuit keys again.
+#[derive(Clone)]
+pub struct CompiledProgram<N: Network> {
+    /// The program.
+    program: Program<N>,
+    /// The mapping of closure and function names to their register types.
+    register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
+    /// The mapping of finalize names to their finalize types.
+    finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+    /// The mapping of function name to verifying key.
+    verifying_keys: IndexMap<Identifier<N>, VerifyingKey<N>>,
+    /// The mapping of function name to the certificate of its verifying key.
+    certificates: IndexMap<Identifier<N>, Certificate<N>>,
+    /// The mapping of function name to proving key, for the functions that were compiled with one.
+    proving_keys: IndexMap<Identifier<N>, ProvingKey<N>>,
+}
+
+impl<N: Network> CompiledProgram<N> {
+    /// Initializes a new compiled program, ensuring the analysis and circuit keys match the program.
+    pub fn new(
+        program: Program<N>,
+        register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
+        finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+        verifying_keys: IndexMap<Identifier<N>, VerifyingKey<N>>,
+        certificates: IndexMap<Identifier<N>, Certificate<N>>,
+        proving_keys: IndexMap<Identifier<N>, ProvingKey<N>>,
+    ) -> Result<Self> {
+        // Ensure the register types match the closures and functions, in order.
+        ensure!(
+            program.closures().keys().chain(program.functions().keys()).eq(register_types.keys()),
+            "The register types do not match the closures and functions in program '{}'",
+            program.id()
+        );
+        // Ensure the finalize types match the functions with a finalize, in order.
+        let finalize_names =
+            program.functions().values().filter_map(|function| function.finalize_logic().map(|_| function.name()));
+        ensure!(
+            finalize_names.eq(finalize_types.keys()),
+            "The finalize types do not match the finalize blocks in program '{}'",
+            program.id()
+        );
+        // Ensure the verifying keys match the functions, in order.
+        ensure!(
+            program.functions().keys().eq(verifying_keys.keys()),
+            "The verifying keys do not match the functions in program '{}'",
+            program.id()
+        );
+        // Ensure the certificates match the functions, in order.
+        ensure!(
+            program.functions().keys().eq(certificates.keys()),
+            "The certificates do not match the functions in program '{}'",
+            program.id()
+        );
+        // Ensure each proving key belongs to a function.
+        for function_name in proving_keys.keys() {
+            ensure!(
+                program.contains_function(function_name),
+                "Function '{function_name}' does not exist in program '{}'",
+                program.id()
+            );
+        }
+        Ok(Self { program, register_types, finalize_types, verifying_keys, certificates, proving_keys })
+    }
+
+    /// Returns the program.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the program ID.
+    pub fn program_id(&self) -> &ProgramID<N> {
+        self.program.id()
+    }
+
+    /// Returns the register types.
+    pub const fn register_types(&self) -> &IndexMap<Identifier<N>, RegisterTypes<N>> {
+        &self.register_types
+    }
+
+    /// Returns the finalize types.
+    pub const fn finalize_types(&self) -> &IndexMap<Identifier<N>, FinalizeTypes<N>> {
+        &self.finalize_types
+    }
+
+    /// Returns the verifying keys.
+    pub const fn verifying_keys(&self) -> &IndexMap<Identifier<N>, VerifyingKey<N>> {
+        &self.verifying_keys
+    }
+
+    /// Returns the certificates.
+    pub const fn certificates(&self) -> &IndexMap<Identifier<N>, Certificate<N>> {
+        &self.certificates
+    }
+
+    /// Returns the proving keys.
+    pub const fn proving_keys(&self) -> &IndexMap<Identifier<N>, ProvingKey<N>> {
+        &self.proving_keys
+    }
+
+    /// Returns the register types for the given closure or function name.
+    pub fn get_register_types(&self, name: &Identifier<N>) -> Result<&RegisterTypes<N>> {
+        self.register_types.get(name).ok_or_else(|| anyhow!("Register types for '{name}' do not exist"))
+    }
+
+    /// Returns the finalize types for the given finalize name.
+    pub fn get_finalize_types(&self, name: &Identifier<N>) -> Result<&FinalizeTypes<N>> {
+        self.finalize_types.get(name).ok_or_else(|| anyhow!("Finalize types for '{name}' do not exist"))
+    }
+
+    /// Returns the number of constraints in the circuit of the given function.
+    pub fn num_constraints(&self, function_name: &Identifier<N>) -> Result<usize> {
+        match self.verifying_keys.get(function_name) {
+            Some(verifying_key) => Ok(verifying_key.circuit_info.num_constraints),
+            None => bail!("Function '{function_name}' does not exist in program '{}'", self.program.id()),
+        }
+    }
+
+    /// Returns the number of variables in the circuit of the given function.
+    pub fn num_variables(&self, function_name: &Identifier<N>) -> Result<usize> {
+        match self.verifying_keys.get(function_name) {
+            Some(verifying_key) => Ok(verifying_key.circuit_info.num_variables),
+            None => bail!("Function '{function_name}' does not exist in program '{}'", self.program.id()),
+        }
+    }
+
+    /// Returns the verifying keys and certificates, in the form of a deployment of the program.
+    fn to_deployment(&self) -> Result<Deployment<N>> {
+        // Pair each verifying key with its certificate.
+        let verifying_keys = self
+            .verifying_keys
+            .iter()
+            .zip(self.certificates.values())
+            .map(|((function_name, verifying_key), certificate)| {
+                (*function_name, (verifying_key.clone(), certificate.clone()))
+            })
+            .collect();
+        // Construct the deployment.
+        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Compiles the given program ID, synthesizing the circuit keys of any function that does not have them yet.
+    /// If `include_proving_keys` is `true`, the proving keys are included, so the program can be executed once loaded.
+    #[inline]
+    pub fn compile<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        include_proving_keys: bool,
+        rng: &mut R,
+    ) -> Result<CompiledProgram<N>> {
+        let timer = timer!("Process::compile");
+
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Synthesize the circuit keys.
+        stack.synthesize_keys::<A, R>(rng)?;
+        lap!(timer, "Synthesize the circuit keys");
+
+        // Retrieve the analysis of the program.
+        let program = stack.program();
+        let mut register_types = IndexMap::with_capacity(program.closures().len() + program.functions().len());
+        let mut finalize_types = IndexMap::new();
+        for name in program.closures().keys().chain(program.functions().keys()) {
+            register_types.insert(*name, stack.get_register_types(name)?.clone());
+        }
+        for function in program.functions().values().filter(|function| function.finalize_logic().is_some()) {
+            finalize_types.insert(*function.name(), stack.get_finalize_types(function.name())?.clone());
+        }
+        lap!(timer, "Retrieve the register types");
+
+        // Retrieve the circuit keys, and certify each verifying key.
+        let mut verifying_keys = IndexMap::with_capacity(program.functions().len());
+        let mut certificates = IndexMap::with_capacity(program.functions().len());
+        let mut proving_keys = IndexMap::new();
+        for function_name in program.functions().keys() {
+            let proving_key = stack.get_proving_key(function_name)?;
+            let verifying_key = stack.get_verifying_key(function_name)?;
+            let certificate = Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)?;
+            certificates.insert(*function_name, certificate);
+            verifying_keys.insert(*function_name, verifying_key);
+            if include_proving_keys {
+                proving_keys.insert(*function_name, proving_key);
+            }
+        }
+        lap!(timer, "Retrieve and certify the circuit keys");
+
+        finish!(timer);
+
+        CompiledProgram::new(
+            program.clone(),
+            register_types,
+            finalize_types,
+            verifying_keys,
+            certificates,
+            proving_keys,
+        )
+    }
+
+    /// Adds the given compiled program, along with its circuit keys.
+    ///
+    /// The register types and finalize types of the program are restored from the compiled program,
+    /// after checking them against the layout of the program, instead of type-checking the program again.
+    /// Each verifying key is checked against its certificate by synthesizing the function, which also
+    /// checks the restored types of every register the function assigns.
+    #[inline]
+    pub fn load_compiled<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        compiled: &CompiledProgram<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Process::load_compiled");
+
+        // Retrieve the program ID.
+        let program_id = compiled.program_id();
+        // Ensure the program does not already exist in the process.
+        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program satisfies the program policy.
+        self.policy().check(compiled.program())?;
+
+        // Restore the program stack from the compiled program.
+        let stack = Stack::initialize_compiled(self, compiled)?;
+        lap!(timer, "Restore the stack");
+
+        // Ensure the verifying keys are well-formed and the certificates are valid.
+        stack.verify_deployment::<A, R>(&compiled.to_deployment()?, rng)?;
+        lap!(timer, "Verify the certificates");
+
+        // Ensure each proving key corresponds to the verifying key of its function.
+        for (function_name, proving_key) in compiled.proving_keys() {
+            ensure!(
+                proving_key.circuit_verifying_key == *compiled.verifying_keys()[function_name],
+                "The proving key for '{function_name}' does not match its verifying key in '{program_id}'"
+            );
+        }
+
+        // Insert the circuit keys.
+        for (function_name, verifying_key) in compiled.verifying_keys() {
+            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+        }
+        for (function_name, proving_key) in compiled.proving_keys() {
+            stack.insert_proving_key(function_name, proving_key.clone())?;
+        }
+        lap!(timer, "Insert the circuit keys");
+
+        // Add the stack to the process.
+        self.add_stack(stack);
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    use once_cell::sync::OnceCell;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a sample compiled program, with its proving keys.
+    pub(crate) fn sample_compiled_program() -> CompiledProgram<CurrentNetwork> {
+        static INSTANCE: OnceCell<CompiledProgram<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new program.
+                let program = Program::<CurrentNetwork>::from_str(
+                    r"
+program compiled.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.public;
+
+function times_two:
+    input r0 as u32.public;
+    mul r0 2u32 into r1;
+    output r1 as u32.private;",
+                )
+                .unwrap();
+
+                // Construct the process.
+                let process = crate::test_helpers::sample_process(&program);
+                // Compile the program.
+                process.compile::<CurrentAleo, _>(program.id(), true, &mut TestRng::default()).unwrap()
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::RegisterType};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_load_compiled() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let compiled = test_helpers::sample_compiled_program();
+        let program_id = *compiled.program_id();
+        assert_eq!(compiled.register_types().len(), 2);
+        assert_eq!(compiled.verifying_keys().len(), 2);
+        assert_eq!(compiled.certificates().len(), 2);
+        assert_eq!(compiled.proving_keys().len(), 2);
+
+        // Load the compiled program into a new process.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        process.load_compiled::<CurrentAleo, _>(&compiled, rng)?;
+
+        // Ensure the program, register types, and circuit keys are restored.
+        let stack = process.get_stack(program_id)?;
+        assert_eq!(process.get_program(program_id)?, compiled.program());
+        for (function_name, verifying_key) in compiled.verifying_keys() {
+            assert!(stack.get_register_types(function_name)? == compiled.get_register_types(function_name)?);
+            assert_eq!(&process.get_verifying_key(program_id, function_name)?, verifying_key);
+            let proving_key = process.get_proving_key(program_id, function_name)?;
+            assert_eq!(proving_key.to_bytes_le()?, compiled.proving_keys()[function_name].to_bytes_le()?);
+            assert!(compiled.num_constraints(function_name)? > 0);
+            assert!(compiled.num_variables(function_name)? > 0);
+        }
+
+        // Ensure the compiled program cannot be loaded twice.
+        assert!(process.load_compiled::<CurrentAleo, _>(&compiled, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_compiled_rejects_invalid_analysis() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let compiled = test_helpers::sample_compiled_program();
+        let function_name = Identifier::from_str("add_one")?;
+
+        // Initializes a compiled program, with the given register types for 'add_one'.
+        let sample = |function_types| {
+            let mut register_types = compiled.register_types().clone();
+            register_types.insert(function_name, function_types);
+            CompiledProgram::new(
+                compiled.program().clone(),
+                register_types,
+                compiled.finalize_types().clone(),
+                compiled.verifying_keys().clone(),
+                compiled.certificates().clone(),
+                compiled.proving_keys().clone(),
+            )
+        };
+
+        // Ensure register types that do not match the layout of 'add_one' are rejected.
+        let mut bytes = vec![];
+        0u32.write_le(&mut bytes)?;
+        0u32.write_le(&mut bytes)?;
+        let candidate = sample(RegisterTypes::read_le(&bytes[..])?)?;
+        let mut process = Process::<CurrentNetwork>::load()?;
+        assert!(process.load_compiled::<CurrentAleo, _>(&candidate, rng).is_err());
+
+        // Ensure register types that assign the wrong type to 'r1' of 'add_one' are rejected.
+        let mut bytes = vec![];
+        1u32.write_le(&mut bytes)?;
+        RegisterType::<CurrentNetwork>::from_str("u32")?.write_le(&mut bytes)?;
+        1u32.write_le(&mut bytes)?;
+        RegisterType::<CurrentNetwork>::from_str("field")?.write_le(&mut bytes)?;
+        let candidate = sample(RegisterTypes::read_le(&bytes[..])?)?;
+        let mut process = Process::<CurrentNetwork>::load()?;
+        assert!(process.load_compiled::<CurrentAleo, _>(&candidate, rng).is_err());
+        assert!(!process.contains_program(compiled.program_id()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_compiled_rejects_invalid_keys() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let compiled = test_helpers::sample_compiled_program();
+        let add_one = Identifier::from_str("add_one")?;
+        let times_two = Identifier::from_str("times_two")?;
+
+        // Swap the verifying keys and certificates of the two functions.
+        let mut verifying_keys = compiled.verifying_keys().clone();
+        let mut certificates = compiled.certificates().clone();
+        verifying_keys.insert(add_one, compiled.verifying_keys()[&times_two].clone());
+        verifying_keys.insert(times_two, compiled.verifying_keys()[&add_one].clone());
+        certificates.insert(add_one, compiled.certificates()[&times_two].clone());
+        certificates.insert(times_two, compiled.certificates()[&add_one].clone());
+        let candidate = CompiledProgram::new(
+            compiled.program().clone(),
+            compiled.register_types().clone(),
+            compiled.finalize_types().clone(),
+            verifying_keys,
+            certificates,
+            IndexMap::new(),
+        )?;
+        // Ensure the verifying keys are rejected, as they do not belong to the circuits of the functions.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        assert!(process.load_compiled::<CurrentAleo, _>(&candidate, rng).is_err());
+
+        // Swap the proving keys of the two functions.
+        let mut proving_keys = compiled.proving_keys().clone();
+        proving_keys.insert(add_one, compiled.proving_keys()[&times_two].clone());
+        proving_keys.insert(times_two, compiled.proving_keys()[&add_one].clone());
+        let candidate = CompiledProgram::new(
+            compiled.program().clone(),
+            compiled.register_types().clone(),
+            compiled.finalize_types().clone(),
+            compiled.verifying_keys().clone(),
+            compiled.certificates().clone(),
+            proving_keys,
+        )?;
+        // Ensure the proving keys are rejected, as they do not match the verifying keys.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        assert!(process.load_compiled::<CurrentAleo, _>(&candidate, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_rejects_mismatched_keys() -> Result<()> {
+        let compiled = test_helpers::sample_compiled_program();
+        let new = |register_types, verifying_keys, proving_keys| {
+            CompiledProgram::new(
+                compiled.program().clone(),
+                register_types,
+                compiled.finalize_types().clone(),
+                verifying_keys,
+                compiled.certificates().clone(),
+                proving_keys,
+            )
+        };
+
+        // Ensure missing register types are rejected.
+        let mut register_types = compiled.register_types().clone();
+        register_types.pop();
+        assert!(new(register_types, compiled.verifying_keys().clone(), IndexMap::new()).is_err());
+
+        // Ensure a missing verifying key is rejected.
+        let mut verifying_keys = compiled.verifying_keys().clone();
+        verifying_keys.pop();
+        assert!(new(compiled.register_types().clone(), verifying_keys, IndexMap::new()).is_err());
+
+        // Ensure a proving key for an unknown function is rejected.
+        let mut proving_keys = compiled.proving_keys().clone();
+        let (_, proving_key) = proving_keys.pop().unwrap();
+        proving_keys.insert(Identifier::from_str("unknown")?, proving_key);
+        assert!(new(compiled.register_types().clone(), compiled.verifying_keys().clone(), proving_keys).is_err());
+        Ok(())
+    }
+}
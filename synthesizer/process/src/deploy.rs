@@ -15,7 +15,7 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program ID, if it does not exist and it satisfies the program policy of the process.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -24,6 +24,9 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
+        // Ensure the program satisfies the program policy.
+        self.policy.check(program)?;
+
         // Compute the stack.
         let stack = Stack::new(self, program)?;
         lap!(timer, "Compute the stack");
@@ -13,9 +13,13 @@
 // limitations under the License.
 
 use super::*;
+use utilities::handle_halting;
 
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
+    ///
+    /// If an instruction halts during evaluation, the halt is returned as an error,
+    /// so that evaluating untrusted programs and inputs never aborts the process.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
         let timer = timer!("Process::evaluate");
@@ -28,12 +32,60 @@ impl<N: Network> Process<N> {
 
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
+        // Prepare the call stack.
+        let call_stack = CallStack::evaluate(authorization)?;
         // Evaluate the function.
-        let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization)?, None);
+        let response = match handle_halting!(panic::AssertUnwindSafe(|| stack.evaluate_function::<A>(call_stack, None))) {
+            Ok(response) => response,
+            // If the evaluation halts, bail and return the error.
+            Err(_) => Err(anyhow!("'{}/{}' halted during evaluation", request.program_id(), request.function_name())),
+        };
         lap!(timer, "Evaluate the function");
 
         finish!(timer);
 
         response
     }
+
+    /// Prepares the given program function for repeated evaluation with `Process::evaluate_prepared`.
+    #[inline]
+    pub fn prepare_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<PreparedFunction<N>> {
+        self.get_stack(program_id)?.prepare_function(function_name)
+    }
+
+    /// Evaluates a prepared program function on the given request.
+    ///
+    /// If an instruction halts during evaluation, the halt is returned as an error,
+    /// so that evaluating untrusted programs and inputs never aborts the process.
+    #[inline]
+    pub fn evaluate_prepared(
+        &self,
+        prepared: &PreparedFunction<N>,
+        authorization: Authorization<N>,
+    ) -> Result<Response<N>> {
+        let timer = timer!("Process::evaluate_prepared");
+
+        // Retrieve the request.
+        let request = authorization.next()?;
+        // Ensure the authorization does not contain further requests.
+        ensure!(authorization.is_empty(), "A prepared function cannot be evaluated with child requests");
+
+        // Retrieve the stack.
+        let stack = self.get_stack(prepared.program_id())?;
+        // Evaluate the function.
+        let response = match handle_halting!(panic::AssertUnwindSafe(|| stack.evaluate_prepared(prepared, &request))) {
+            Ok(response) => response,
+            // If the evaluation halts, bail and return the error.
+            Err(_) => Err(anyhow!("'{}/{}' halted during evaluation", request.program_id(), request.function_name())),
+        };
+        lap!(timer, "Evaluate the prepared function");
+
+        finish!(timer);
+
+        response
+    }
 }
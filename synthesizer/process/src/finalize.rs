@@ -28,6 +28,38 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         deployment: &Deployment<N>,
         fee: &Fee<N>,
+    ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
+        self.finalize_deployment_into(state, store, store, deployment, fee)
+    }
+
+    /// Finalizes the deployment and fee, returning the state diffs alongside the finalize operations.
+    /// The state diffs list each mapping key that was written by the fee, with its value before and after finalize.
+    /// This method assumes the given deployment **is valid**.
+    #[inline]
+    pub fn finalize_deployment_with_state_diffs<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        deployment: &Deployment<N>,
+        fee: &Fee<N>,
+    ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>, Vec<StateDiff<N>>)> {
+        // Initialize a recorder over the store.
+        let recorder = StateDiffRecorder::new(store);
+        // Finalize the deployment through the recorder.
+        let (stack, finalize_operations) = self.finalize_deployment_into(state, store, &recorder, deployment, fee)?;
+        // Return the stack, finalize operations, and state diffs.
+        Ok((stack, finalize_operations, recorder.into_state_diffs()))
+    }
+
+    /// Finalizes the deployment and fee, writing the mapping updates of the fee through the given `target`.
+    /// The atomic batch is scoped over the given `store`, which `target` must write into.
+    fn finalize_deployment_into<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        target: &impl FinalizeStoreTrait<N>,
+        deployment: &Deployment<N>,
+        fee: &Fee<N>,
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
@@ -51,7 +83,7 @@ impl<N: Network> Process<N> {
             // Retrieve the fee stack.
             let fee_stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+            finalize_operations.extend(finalize_fee_transition(state, target, fee_stack, fee)?);
             lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
 
             /* Finalize the deployment. */
@@ -80,6 +112,38 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_execution_into(state, store, store, execution, fee)
+    }
+
+    /// Finalizes the execution and fee, returning the state diffs alongside the finalize operations.
+    /// The state diffs list each mapping key that was written, with its value before and after finalize.
+    /// This method assumes the given execution **is valid**.
+    #[inline]
+    pub fn finalize_execution_with_state_diffs<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<StateDiff<N>>)> {
+        // Initialize a recorder over the store.
+        let recorder = StateDiffRecorder::new(store);
+        // Finalize the execution through the recorder.
+        let finalize_operations = self.finalize_execution_into(state, store, &recorder, execution, fee)?;
+        // Return the finalize operations and the state diffs.
+        Ok((finalize_operations, recorder.into_state_diffs()))
+    }
+
+    /// Finalizes the execution and fee, writing the mapping updates through the given `target`.
+    /// The atomic batch is scoped over the given `store`, which `target` must write into.
+    fn finalize_execution_into<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        target: &impl FinalizeStoreTrait<N>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("Program::finalize_execution");
 
@@ -107,7 +171,7 @@ impl<N: Network> Process<N> {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let mut finalize_operations = finalize_transition(state, target, stack, transition, call_graph)?;
 
             /* Finalize the fee. */
 
@@ -115,7 +179,7 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(finalize_fee_transition(state, target, fee_stack, fee)?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
@@ -134,6 +198,36 @@ impl<N: Network> Process<N> {
         state: FinalizeGlobalState,
         store: &FinalizeStore<N, P>,
         fee: &Fee<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_fee_into(state, store, store, fee)
+    }
+
+    /// Finalizes the fee, returning the state diffs alongside the finalize operations.
+    /// The state diffs list each mapping key that was written, with its value before and after finalize.
+    /// This method assumes the given fee **is valid**.
+    #[inline]
+    pub fn finalize_fee_with_state_diffs<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        fee: &Fee<N>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<StateDiff<N>>)> {
+        // Initialize a recorder over the store.
+        let recorder = StateDiffRecorder::new(store);
+        // Finalize the fee through the recorder.
+        let finalize_operations = self.finalize_fee_into(state, store, &recorder, fee)?;
+        // Return the finalize operations and the state diffs.
+        Ok((finalize_operations, recorder.into_state_diffs()))
+    }
+
+    /// Finalizes the fee, writing the mapping updates through the given `target`.
+    /// The atomic batch is scoped over the given `store`, which `target` must write into.
+    fn finalize_fee_into<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        target: &impl FinalizeStoreTrait<N>,
+        fee: &Fee<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("Program::finalize_fee");
 
@@ -141,7 +235,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = finalize_fee_transition(state, target, stack, fee);
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -150,9 +244,9 @@ impl<N: Network> Process<N> {
 }
 
 /// Finalizes the given fee transition.
-fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
+fn finalize_fee_transition<N: Network>(
     state: FinalizeGlobalState,
-    store: &FinalizeStore<N, P>,
+    store: &impl FinalizeStoreTrait<N>,
     stack: &Stack<N>,
     fee: &Fee<N>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
@@ -171,9 +265,9 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
 }
 
 /// Finalizes the given transition.
-fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
+fn finalize_transition<N: Network>(
     state: FinalizeGlobalState,
-    store: &FinalizeStore<N, P>,
+    store: &impl FinalizeStoreTrait<N>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
@@ -442,7 +536,11 @@ fn branch_to<N: Network, const VARIANT: u8>(
 mod tests {
     use super::*;
     use crate::tests::test_execute::{sample_fee, sample_finalize_state};
-    use console::prelude::TestRng;
+    use console::{
+        account::{Address, PrivateKey},
+        prelude::TestRng,
+        program::{Literal, Plaintext},
+    };
     use ledger_store::{
         helpers::memory::{BlockMemory, FinalizeMemory},
         BlockStore,
@@ -513,4 +611,96 @@ function compute:
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
     }
+
+    #[test]
+    fn test_finalize_execution_with_state_diffs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async deposit r0 r1 into r2;
+    output r2 as testing.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    add r3 r1 into r4;
+    set r4 into account[r0];",
+        )
+        .unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        // Deploy the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+        // Compute the fee.
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        // Finalize the deployment.
+        let (stack, _) =
+            process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+        // Add the stack *manually* to the process.
+        process.add_stack(stack);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Executes `deposit` with the given amount, and finalizes it, returning the state diffs.
+        let mut deposit = |amount: &str| {
+            let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str(amount).unwrap()];
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "deposit", inputs.iter(), rng)
+                .unwrap();
+            let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Construct the execution, without a proof.
+            let execution =
+                Execution::from(trace.transitions().iter().cloned(), block_store.current_state_root(), None).unwrap();
+            process
+                .finalize_execution_with_state_diffs(sample_finalize_state(1), &finalize_store, &execution, None)
+                .unwrap()
+        };
+
+        // Ensure the first deposit records a single insert, merging both writes.
+        let (operations, state_diffs) = deposit("3u64");
+        assert_eq!(operations.len(), 2);
+        assert_eq!(state_diffs.len(), 1);
+        assert_eq!(state_diffs[0].program_id(), program.id());
+        assert_eq!(state_diffs[0].mapping_name(), &mapping_name);
+        assert_eq!(state_diffs[0].key(), &key);
+        assert_eq!(state_diffs[0].old_value(), &None);
+        assert_eq!(state_diffs[0].new_value(), &Some(Value::from_str("6u64").unwrap()));
+        assert!(state_diffs[0].is_insert());
+
+        // Ensure the second deposit records an update from the previous value.
+        let (_, state_diffs) = deposit("1u64");
+        assert_eq!(state_diffs.len(), 1);
+        assert_eq!(state_diffs[0].old_value(), &Some(Value::from_str("6u64").unwrap()));
+        assert_eq!(state_diffs[0].new_value(), &Some(Value::from_str("8u64").unwrap()));
+        assert!(!state_diffs[0].is_insert() && !state_diffs[0].is_remove());
+
+        // Ensure the state diff matches the store.
+        let value = finalize_store.get_value_speculative(*program.id(), mapping_name, &key).unwrap();
+        assert_eq!(&value, state_diffs[0].new_value());
+    }
 }
@@ -18,6 +18,12 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod audit;
+pub use audit::*;
+
+mod compiled_program;
+pub use compiled_program::*;
+
 mod stack;
 pub use stack::*;
 
@@ -54,11 +60,15 @@ use synthesizer_program::{
     Finalize,
     FinalizeGlobalState,
     FinalizeOperation,
+    FinalizeStoreTrait,
     Instruction,
     Program,
+    ProgramPolicy,
     RegistersLoad,
     RegistersStore,
     StackProgram,
+    StateDiff,
+    StateDiffRecorder,
 };
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
@@ -74,6 +84,8 @@ use colored::Colorize;
 pub struct Process<N: Network> {
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
+    /// The policy that programs must satisfy to be added to the process.
+    policy: ProgramPolicy,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
 }
@@ -85,7 +97,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            policy: Default::default(),
+            stacks: IndexMap::new(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -111,7 +127,7 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
-    /// Adds a new program to the process.
+    /// Adds a new program to the process, if it satisfies the program policy of the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_program(&mut self, program: &Program<N>) -> Result<()> {
@@ -119,11 +135,25 @@ impl<N: Network> Process<N> {
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
+            // Ensure the program satisfies the program policy.
+            self.policy.check(program)?;
             self.add_stack(Stack::new(self, program)?);
         }
         Ok(())
     }
 
+    /// Adds the standard library programs to the process, skipping the programs that already exist.
+    /// On a ledger, the standard library programs are imported once they are deployed.
+    #[inline]
+    pub fn add_stdlib(&mut self) -> Result<()> {
+        for program in Program::stdlib()? {
+            if !self.contains_program(program.id()) {
+                self.add_program(&program)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -140,7 +170,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            policy: Default::default(),
+            stacks: IndexMap::new(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +207,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            policy: Default::default(),
+            stacks: IndexMap::new(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -194,6 +232,21 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the program policy.
+    #[inline]
+    pub const fn policy(&self) -> &ProgramPolicy {
+        &self.policy
+    }
+
+    /// Sets the program policy, which applies to the programs that are subsequently added to the process
+    /// with `add_program` or `load_compiled`, and to the deployments created with `deploy`.
+    /// The policy is local to the process, so it does not apply to the verification or finalization of deployments,
+    /// which must agree across the network.
+    #[inline]
+    pub fn set_policy(&mut self, policy: ProgramPolicy) {
+        self.policy = policy;
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -278,6 +331,17 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes the proving and verifying keys for every function in the given program ID, in parallel.
+    #[inline]
+    pub fn synthesize_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Synthesize the proving and verifying keys.
+        self.get_stack(program_id)?.synthesize_keys::<A, R>(rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
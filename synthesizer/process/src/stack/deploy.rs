@@ -25,14 +25,14 @@ impl<N: Network> Stack<N> {
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
 
+        // Synthesize the proving and verifying keys.
+        self.synthesize_keys::<A, R>(rng)?;
+        lap!(timer, "Synthesize the keys");
+
         // Initialize a vector for the verifying keys and certificates.
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
 
         for function_name in self.program.functions().keys() {
-            // Synthesize the proving and verifying key.
-            self.synthesize_key::<A, R>(function_name, rng)?;
-            lap!(timer, "Synthesize key for {function_name}");
-
             // Retrieve the proving key.
             let proving_key = self.get_proving_key(function_name)?;
             // Retrieve the verifying key.
@@ -82,6 +82,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the input digest, throw an error.
+                    Operand::InputDigest => bail!("Cannot retrieve the input digest from a closure scope."),
                 }
             })
             .collect();
@@ -140,6 +142,16 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         };
         let tvk = *request.tvk();
 
+        // Ensure the caller is allowed to call the function, if it is restricted.
+        if let Some(callers) = self.program.get_restriction(function.name()) {
+            ensure!(
+                callers.contains(&caller),
+                "Caller '{caller}' is not allowed to call '{}/{}'",
+                self.program.id(),
+                function.name()
+            );
+        }
+
         // Ensure the number of inputs matches.
         if function.inputs().len() != inputs.len() {
             bail!(
@@ -173,6 +185,14 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // If the function reads the input digest, compute the digest of the inputs.
+        if Self::uses_input_digest(&function) {
+            // Compute the input digest, as the hash of the field elements of the inputs.
+            let preimage = inputs.iter().map(|input| input.to_fields()).collect::<Result<Vec<_>>>()?.concat();
+            // Set the input digest.
+            registers.set_input_digest(N::hash_psd8(&preimage)?);
+        }
+
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
         for instruction in function.instructions() {
@@ -213,6 +233,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the input digest, retrieve the input digest from the registers.
+                    Operand::InputDigest => {
+                        Ok(Value::Plaintext(Plaintext::from(Literal::Field(registers.input_digest()?))))
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
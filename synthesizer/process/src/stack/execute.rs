@@ -115,6 +115,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the input digest, throw an error.
+                    Operand::InputDigest => {
+                        bail!("Illegal operation: cannot retrieve the input digest in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -208,6 +212,25 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Determine the caller.
         let caller = Ternary::ternary(&is_root, request.signer(), &parent);
 
+        // Ensure the caller is allowed to call the function, if it is restricted.
+        if let Some(callers) = self.program.get_restriction(function.name()) {
+            // Note: The requests for synthesis and deployment checks are sampled, so their callers are not checked here.
+            if !matches!(registers.call_stack(), CallStack::Synthesize(..) | CallStack::CheckDeployment(..)) {
+                let console_caller = caller.eject_value();
+                ensure!(
+                    callers.contains(&console_caller),
+                    "Caller '{console_caller}' is not allowed to call '{}/{}'",
+                    self.program.id(),
+                    function.name()
+                );
+            }
+            // Ensure the caller is one of the allowed callers, in the circuit.
+            let is_allowed = callers.iter().fold(circuit::Boolean::constant(false), |is_allowed, allowed_caller| {
+                is_allowed | caller.is_equal(&circuit::Address::constant(*allowed_caller))
+            });
+            A::assert(is_allowed);
+        }
+
         // Ensure the request has a valid signature, inputs, and transition view key.
         A::assert(request.verify(&input_types, &tpk));
         lap!(timer, "Verify the circuit request");
@@ -250,6 +273,24 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // If the function reads the input digest, compute the digest of the inputs.
+        if Self::uses_input_digest(&function) {
+            // Compute the input digest, as the hash of the field elements of the inputs.
+            let preimage = request.inputs().iter().flat_map(circuit::traits::ToFields::to_fields).collect::<Vec<_>>();
+            let input_digest = A::hash_psd8(&preimage);
+            // Set the input digest.
+            registers.set_input_digest(input_digest.eject_value());
+            // Set the input digest, as a circuit.
+            registers.set_input_digest_circuit(input_digest);
+        }
+
+        // If the circuit is in execute mode, then evaluate the independent instructions ahead, in parallel.
+        let witnesses = match registers.call_stack() {
+            CallStack::Execute(..) => self.evaluate_witnesses(&function, &registers)?,
+            _ => None,
+        };
+        lap!(timer, "Evaluate the witnesses");
+
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
@@ -258,9 +299,16 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
-                let result = match instruction {
+                let result = match (instruction, &witnesses) {
                     // If the instruction is a `call` instruction, we need to handle it separately.
-                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    (Instruction::Call(call), _) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction was evaluated ahead, store its destinations.
+                    (_, Some(witnesses)) => instruction.destinations().iter().try_for_each(|destination| {
+                        match witnesses.get(&destination.locator()) {
+                            Some(value) => registers.store(self, destination, value.clone()),
+                            None => bail!("Missing the evaluated value of '{destination}'"),
+                        }
+                    }),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -322,6 +370,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the input digest, retrieve the input digest from the registers.
+                    Operand::InputDigest => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
+                        circuit::Literal::Field(registers.input_digest_circuit()?),
+                    ))),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -37,6 +37,8 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::Signer => bail!("Forbidden operation: Cannot use 'self.signer' in 'finalize'"),
             // If the operand is the caller, throw an error.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the input digest, throw an error.
+            Operand::InputDigest => bail!("Forbidden operation: Cannot use 'input.digest' in 'finalize'"),
             // If the operand is the block height, load the block height.
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
@@ -15,6 +15,7 @@
 use super::*;
 use crate::RegisterTypes;
 use synthesizer_program::{
+    AssertBlock,
     Await,
     Branch,
     CastType,
@@ -184,6 +185,10 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::AssertHeightGe(assert) => self.check_assert_block(stack, assert)?,
+            Command::AssertHeightLe(assert) => self.check_assert_block(stack, assert)?,
+            Command::AssertTimestampGe(assert) => self.check_assert_block(stack, assert)?,
+            Command::AssertTimestampLe(assert) => self.check_assert_block(stack, assert)?,
         }
         Ok(())
     }
@@ -207,6 +212,26 @@ impl<N: Network> FinalizeTypes<N> {
         }
     }
 
+    /// Checks that the given variant of the `assert.height.*` or `assert.timestamp.*` command is well-formed.
+    #[inline]
+    fn check_assert_block<const VARIANT: u8>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        assert: &AssertBlock<N, VARIANT>,
+    ) -> Result<()> {
+        // Retrieve the expected operand type.
+        let expected_type = AssertBlock::<N, VARIANT>::operand_type();
+        // Ensure the operand is of the expected type.
+        match self.get_type_from_operand(stack, assert.operand())? {
+            FinalizeType::Plaintext(PlaintextType::Literal(literal_type)) if literal_type == expected_type => Ok(()),
+            FinalizeType::Plaintext(plaintext_type) => bail!(
+                "Command '{}' expects an operand of type '{expected_type}'. Found operand of type '{plaintext_type}'",
+                AssertBlock::<N, VARIANT>::opcode(),
+            ),
+            FinalizeType::Future(..) => bail!("A future cannot be used in an `assert` command"),
+        }
+    }
+
     /// Checks that the given variant of the `branch` command is well-formed.
     #[inline]
     fn check_branch<const VARIANT: u8>(
@@ -597,6 +622,8 @@ impl<N: Network> FinalizeTypes<N> {
                     instruction.destinations().len() == 1,
                     "Instruction '{instruction}' has multiple destinations."
                 );
+                // Ensure the fractional bits of a `fixed64` instruction are a literal.
+                check_fractional_bits_operand(instruction)?;
             }
             Opcode::Assert(opcode) => match opcode {
                 "assert.eq" => ensure!(
@@ -679,7 +706,18 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Linalg(opcode) => match opcode {
+                "dot" => ensure!(
+                    matches!(instruction, Instruction::Dot(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "matmul" => ensure!(
+                    matches!(instruction, Instruction::MatMul(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Sign | Opcode::VRF => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
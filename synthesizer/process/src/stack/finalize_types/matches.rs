@@ -86,6 +86,10 @@ impl<N: Network> FinalizeTypes<N> {
                 Operand::Caller => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be cast from a caller in a finalize scope."
                 ),
+                // If the operand is an input digest, throw an error.
+                Operand::InputDigest => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be cast from an input digest in a finalize scope."
+                ),
                 // Ensure the block height type (u32) matches the member type.
                 Operand::BlockHeight => {
                     // Retrieve the block height type.
@@ -166,6 +170,8 @@ impl<N: Network> FinalizeTypes<N> {
                 Operand::Signer => bail!("Array element cannot be cast from a signer in a finalize scope."),
                 // If the operand is a caller, throw an error.
                 Operand::Caller => bail!("Array element cannot be cast from a caller in a finalize scope."),
+                // If the operand is an input digest, throw an error.
+                Operand::InputDigest => bail!("Array element cannot be cast from an input digest in a finalize scope."),
                 // Ensure the block height type (u32) matches the member type.
                 Operand::BlockHeight => {
                     // Retrieve the block height type.
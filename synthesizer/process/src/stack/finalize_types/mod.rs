@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
 mod initialize;
 mod matches;
 
@@ -20,7 +21,9 @@ use console::{
     program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType},
 };
 use synthesizer_program::{
+    check_fractional_bits_operand,
     Command,
+    CommandTrait,
     Finalize,
     Instruction,
     InstructionTrait,
@@ -52,6 +55,42 @@ impl<N: Network> FinalizeTypes<N> {
         Self::initialize_finalize_types(stack, finalize)
     }
 
+    /// Checks that the (restored) finalize types match the register layout of the given finalize.
+    /// Note: This does not type-check the commands; their types are checked when the finalize is executed.
+    #[inline]
+    pub fn check_finalize_layout(&self, finalize: &Finalize<N>) -> Result<()> {
+        // Ensure the input registers have their declared types.
+        let inputs = finalize.inputs().iter().map(|input| (input.register().locator(), input.finalize_type().clone()));
+        ensure!(
+            self.inputs.iter().map(|(locator, finalize_type)| (*locator, finalize_type.clone())).eq(inputs),
+            "The input finalize types do not match the inputs of finalize '{}'",
+            finalize.name()
+        );
+        // Ensure the destination registers are the command destinations, in order.
+        let destinations = finalize.commands().iter().flat_map(|command| command.destinations());
+        ensure!(
+            self.destinations.keys().copied().eq(destinations.map(|register| register.locator())),
+            "The destination finalize types do not match the commands of finalize '{}'",
+            finalize.name()
+        );
+        // Ensure the input futures are awaited in the order they are passed in.
+        let input_futures = finalize
+            .inputs()
+            .iter()
+            .filter(|input| matches!(input.finalize_type(), FinalizeType::Future(..)))
+            .map(|input| input.register());
+        let awaited_futures = finalize.commands().iter().filter_map(|command| match command {
+            Command::Await(await_) => Some(await_.register()),
+            _ => None,
+        });
+        ensure!(
+            input_futures.eq(awaited_futures),
+            "Futures in finalize '{}' are not awaited in the order they are passed in.",
+            finalize.name()
+        );
+        Ok(())
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -78,6 +117,7 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::ProgramID(_) => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
+            Operand::InputDigest => bail!("'input.digest' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
         })
     }
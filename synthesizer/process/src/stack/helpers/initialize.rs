@@ -30,16 +30,7 @@ impl<N: Network> Stack<N> {
         };
 
         // Add all of the imports into the stack.
-        for import in program.imports().keys() {
-            // Ensure the program imports all exist in the process already.
-            if !process.contains_program(import) {
-                bail!("Cannot add program '{}' because its import '{import}' must be added first", program.id())
-            }
-            // Retrieve the external stack for the import program ID.
-            let external_stack = process.get_stack(import)?;
-            // Add the external stack to the stack.
-            stack.insert_external_stack(external_stack.clone())?;
-        }
+        stack.insert_imports(process)?;
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
@@ -53,9 +44,79 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Ok(stack)
     }
+
+    /// Initializes a new stack, given the process and compiled program.
+    /// The register types and finalize types are restored from the compiled program,
+    /// instead of type-checking the program again, and are checked against the layout of the program.
+    #[inline]
+    pub(crate) fn initialize_compiled(process: &Process<N>, compiled: &CompiledProgram<N>) -> Result<Self> {
+        // Retrieve the program.
+        let program = compiled.program();
+        // Construct the stack for the program.
+        let mut stack = Self {
+            program: program.clone(),
+            external_stacks: Default::default(),
+            register_types: Default::default(),
+            finalize_types: Default::default(),
+            universal_srs: process.universal_srs().clone(),
+            proving_keys: Default::default(),
+            verifying_keys: Default::default(),
+        };
+
+        // Add all of the imports into the stack.
+        stack.insert_imports(process)?;
+        // Add the program closures to the stack.
+        for (name, closure) in program.closures() {
+            // Retrieve the register types.
+            let register_types = compiled.get_register_types(name)?;
+            // Ensure the register types match the closure.
+            register_types.check_closure_layout(closure)?;
+            // Add the closure name and register types to the stack.
+            stack.register_types.insert(*name, register_types.clone());
+        }
+        // Add the program functions to the stack.
+        for (name, function) in program.functions() {
+            // Retrieve the register types.
+            let register_types = compiled.get_register_types(name)?;
+            // Ensure the register types match the function.
+            register_types.check_function_layout(function)?;
+            // Add the function name and register types to the stack.
+            stack.register_types.insert(*name, register_types.clone());
+
+            // If the function contains a finalize, insert it.
+            if let Some(finalize) = function.finalize_logic() {
+                // Retrieve the finalize types.
+                let finalize_types = compiled.get_finalize_types(name)?;
+                // Ensure the finalize types match the finalize.
+                finalize_types.check_finalize_layout(finalize)?;
+                // Add the finalize name and finalize types to the stack.
+                stack.finalize_types.insert(*name, finalize_types.clone());
+            }
+        }
+        // Return the stack.
+        Ok(stack)
+    }
 }
 
 impl<N: Network> Stack<N> {
+    /// Inserts the external stacks of the program imports to the stack.
+    #[inline]
+    fn insert_imports(&mut self, process: &Process<N>) -> Result<()> {
+        // Collect the import program IDs, as the stack is mutated below.
+        let imports: Vec<_> = self.program.imports().keys().copied().collect();
+        for import in &imports {
+            // Ensure the program imports all exist in the process already.
+            if !process.contains_program(import) {
+                bail!("Cannot add program '{}' because its import '{import}' must be added first", self.program.id())
+            }
+            // Retrieve the external stack for the import program ID.
+            let external_stack = process.get_stack(import)?;
+            // Add the external stack to the stack.
+            self.insert_external_stack(external_stack.clone())?;
+        }
+        Ok(())
+    }
+
     /// Inserts the given external stack to the stack.
     #[inline]
     fn insert_external_stack(&mut self, external_stack: Arc<Stack<N>>) -> Result<()> {
@@ -14,7 +14,30 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 impl<N: Network> Stack<N> {
+    /// Synthesizes the proving key and verifying key for every function in the program, in parallel.
+    ///
+    /// Each function is synthesized in its own circuit environment, with its own RNG derived from the given RNG.
+    /// The universal SRS is shared across the functions, so it is only loaded once.
+    #[inline]
+    pub fn synthesize_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        // Determine the functions that are missing a proving or verifying key.
+        let function_names = self
+            .program
+            .functions()
+            .keys()
+            .filter(|name| !(self.contains_proving_key(name) && self.contains_verifying_key(name)))
+            .collect::<Vec<_>>();
+        // Initialize an RNG for each function.
+        let rngs = (0..function_names.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        // Synthesize the proving and verifying keys.
+        cfg_into_iter!(function_names)
+            .zip_eq(rngs)
+            .try_for_each(|(function_name, mut rng)| self.synthesize_key::<A, _>(function_name, &mut rng))
+    }
+
     /// Synthesizes the proving key and verifying key for the given function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
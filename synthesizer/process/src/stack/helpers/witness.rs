@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashMap;
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the console values of the given function's instructions ahead of synthesis,
+    /// returning the values of every register assigned by the function.
+    ///
+    /// The instructions are grouped into levels of the register dependency graph, and the instructions
+    /// of each level are evaluated in parallel, as they only read registers assigned by earlier levels.
+    /// The outputs of each level are merged in instruction order, so the result is deterministic.
+    /// Only the console values are evaluated ahead; the circuit is still synthesized in instruction order,
+    /// as the circuit environment is thread-local.
+    ///
+    /// The instructions are evaluated on scoped threads, which do not share the circuit of this thread.
+    /// Unlike a thread pool, this thread does not run other (stolen) work while it waits on them,
+    /// so work that synthesizes or resets a circuit cannot run on this thread in the middle of synthesis.
+    ///
+    /// Returns `None` if the function contains a `call`, as calls must be evaluated in order,
+    /// if none of its instructions are independent of each other, or if an instruction fails to evaluate.
+    /// In the last case, the caller evaluates the instructions in order, so the error is the same as
+    /// without evaluating ahead.
+    pub(crate) fn evaluate_witnesses<A: circuit::Aleo<Network = N>>(
+        &self,
+        function: &Function<N>,
+        registers: &Registers<N, A>,
+    ) -> Result<Option<IndexMap<u64, Value<N>>>> {
+        // If the `serial` feature is enabled, do not evaluate ahead.
+        if cfg!(feature = "serial") {
+            return Ok(None);
+        }
+
+        // Retrieve the dependency levels of the instructions.
+        let levels = match dependency_levels(function.instructions()) {
+            Some(levels) if levels.len() < function.instructions().len() => levels,
+            _ => return Ok(None),
+        };
+
+        // Retrieve the transition state, as the registers cannot be shared across threads.
+        let register_types = registers.register_types();
+        let (signer, caller) = (registers.signer().ok(), registers.caller().ok());
+        let (tvk, input_digest) = (registers.tvk().ok(), registers.input_digest().ok());
+
+        // Retrieve the number of available threads.
+        let num_threads = std::thread::available_parallelism().map_or(1, |num_threads| num_threads.get());
+
+        // Initialize the assigned registers with the inputs.
+        let mut assigned = registers.console_registers().clone();
+
+        for level in levels {
+            // Evaluates the given instructions of the level, over the assigned registers.
+            let evaluate = &|indices: &[usize]| {
+                indices
+                    .iter()
+                    .map(|index| {
+                        // Initialize the witness registers over the assigned registers.
+                        let mut witness =
+                            WitnessRegisters::new(register_types, &assigned, signer, caller, tvk, input_digest);
+                        // Evaluate the instruction.
+                        function.instructions()[*index].evaluate(self, &mut witness).map(|_| witness.into_outputs())
+                    })
+                    .collect::<Result<Vec<_>>>()
+            };
+
+            // Split the level into one chunk per thread.
+            let chunk_size = (level.len() + num_threads - 1) / num_threads;
+            // Evaluate the chunks, on scoped threads if there is more than one chunk.
+            let outputs = match level.len() > chunk_size {
+                true => std::thread::scope(|scope| {
+                    let handles =
+                        level.chunks(chunk_size).map(|chunk| scope.spawn(move || evaluate(chunk))).collect::<Vec<_>>();
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().ok().and_then(Result::ok))
+                        .collect::<Option<Vec<_>>>()
+                }),
+                false => evaluate(level.as_slice()).ok().map(|outputs| vec![outputs]),
+            };
+
+            // Merge the outputs in instruction order.
+            match outputs {
+                Some(outputs) => assigned.extend(outputs.into_iter().flatten().flatten()),
+                // If an instruction failed to evaluate, defer to evaluating the instructions in order.
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(assigned))
+    }
+}
+
+/// Returns the indices of the given instructions, grouped into levels such that each instruction
+/// only reads registers assigned by the inputs or by an instruction in an earlier level.
+/// Returns `None` if the instructions contain a `call`.
+fn dependency_levels<N: Network>(instructions: &[Instruction<N>]) -> Option<Vec<Vec<usize>>> {
+    // Initialize a mapping of each assigned register locator to the level of its instruction.
+    let mut register_levels = HashMap::<u64, usize>::new();
+    // Initialize the levels.
+    let mut levels: Vec<Vec<usize>> = Vec::new();
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        // Ensure the instruction is not a call.
+        if let Instruction::Call(..) = instruction {
+            return None;
+        }
+        // Determine the level, as one past the latest level of the registers it reads.
+        let level = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => register_levels.get(&register.locator()).map(|level| level + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        // Record the level of the destinations.
+        for destination in instruction.destinations() {
+            register_levels.insert(destination.locator(), level);
+        }
+        // Add the instruction to its level.
+        if levels.len() <= level {
+            levels.resize_with(level + 1, Vec::new);
+        }
+        levels[level].push(index);
+    }
+    Some(levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_dependency_levels() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program levels.aleo;
+
+closure twice:
+    input r0 as u32;
+    add r0 r0 into r1;
+    output r1 as u32;
+
+function independent:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    mul r0 r0 into r2;
+    mul r1 r1 into r3;
+    add r2 r3 into r4;
+    add r0 r1 into r5;
+    sub r4 r5 into r6;
+    output r6 as u32.private;
+
+function calls:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    call twice r1 into r2;
+    output r2 as u32.private;",
+        )
+        .unwrap();
+
+        // Ensure the independent instructions share a level.
+        let function = program.get_function(&Identifier::from_str("independent").unwrap()).unwrap();
+        assert_eq!(dependency_levels(function.instructions()), Some(vec![vec![0, 1, 3], vec![2], vec![4]]));
+
+        // Ensure a function with a call is not leveled.
+        let function = program.get_function(&Identifier::from_str("calls").unwrap()).unwrap();
+        assert_eq!(dependency_levels(function.instructions()), None);
+    }
+
+    #[test]
+    fn test_evaluate_witnesses_error_order() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program ordered.aleo;
+
+function check:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r1 1u8 into r2;
+    assert.eq r2 r0;
+    assert.eq r0 r1;
+    output r2 as u8.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = crate::test_helpers::sample_process(&program);
+        // Authorize the function call.
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "check", ["5u8", "0u8"].into_iter(), rng)
+            .unwrap();
+
+        // Both assertions fail. The second assertion is evaluated ahead, as it is independent of the first one,
+        // yet the error must be the one from evaluating the instructions in order.
+        let error = process.execute::<CurrentAleo, _>(authorization, rng).err().unwrap();
+        assert!(error.to_string().contains("assert.eq r2 r0"), "{error}");
+    }
+}
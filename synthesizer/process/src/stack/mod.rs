@@ -24,19 +24,28 @@ pub use finalize_registers::*;
 mod finalize_types;
 pub use finalize_types::*;
 
+mod prepared_function;
+pub use prepared_function::*;
+
+mod public_input_layout;
+pub use public_input_layout::*;
+
 mod register_types;
 pub use register_types::*;
 
 mod registers;
 pub use registers::*;
 
+mod witness_registers;
+use witness_registers::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{traits::*, CallMetrics, CompiledProgram, Process, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -442,6 +451,12 @@ impl<N: Network> Stack<N> {
         }
         Ok(())
     }
+
+    /// Returns `true` if the given function reads the input digest, as an instruction operand or an output.
+    fn uses_input_digest(function: &Function<N>) -> bool {
+        function.instructions().iter().any(|instruction| instruction.operands().contains(&Operand::InputDigest))
+            || function.outputs().iter().any(|output| output.operand() == &Operand::InputDigest)
+    }
 }
 
 impl<N: Network> PartialEq for Stack<N> {
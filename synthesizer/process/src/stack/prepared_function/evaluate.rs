@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the prepared function on the given request.
+    ///
+    /// # Errors
+    /// This method will halt if the request is not for the prepared function, or if the request is invalid.
+    #[inline]
+    pub fn evaluate_prepared(&self, prepared: &PreparedFunction<N>, request: &Request<N>) -> Result<Response<N>> {
+        let timer = timer!("Stack::evaluate_prepared");
+
+        // Ensure the request is for the prepared function.
+        ensure!(
+            prepared.program_id() == self.program_id() && request.program_id() == self.program_id(),
+            "Request for '{}' does not match the prepared program '{}'",
+            request.program_id(),
+            prepared.program_id()
+        );
+        ensure!(
+            request.function_name() == prepared.function_name(),
+            "Request for '{}' does not match the prepared function '{}'",
+            request.function_name(),
+            prepared.function_name()
+        );
+
+        // Ensure the network ID matches.
+        ensure!(
+            **request.network_id() == N::ID,
+            "Network ID mismatch. Expected {}, but found {}",
+            N::ID,
+            request.network_id()
+        );
+
+        // Retrieve the function, inputs, and transition view key.
+        let function = &prepared.function;
+        let inputs = request.inputs();
+        let signer = *request.signer();
+        let tvk = *request.tvk();
+
+        // Ensure the signer is allowed to call the function, if it is restricted.
+        // Note: A prepared function is always evaluated as a top-level function, so the caller is the signer.
+        if let Some(callers) = self.program.get_restriction(function.name()) {
+            ensure!(
+                callers.contains(&signer),
+                "Caller '{signer}' is not allowed to call '{}/{}'",
+                self.program.id(),
+                function.name()
+            );
+        }
+
+        // Ensure the number of inputs matches.
+        if prepared.input_types.len() != inputs.len() {
+            bail!(
+                "Function '{}' in the program '{}' expects {} inputs, but {} were provided.",
+                function.name(),
+                self.program.id(),
+                prepared.input_types.len(),
+                inputs.len()
+            )
+        }
+        lap!(timer, "Perform input checks");
+
+        // Ensure the request is well-formed.
+        ensure!(request.verify(&function.input_types()), "Request is invalid");
+        lap!(timer, "Verify the request");
+
+        // Initialize the registers.
+        let mut registers = PreparedRegisters::new(prepared.num_registers);
+        registers.set_signer(signer);
+        registers.set_caller(signer);
+        registers.set_tvk(tvk);
+
+        // Store the inputs.
+        // Note: Unlike the instruction outputs, the inputs are untrusted, so they are checked against their types.
+        for ((input, register_type), value) in function.inputs().iter().zip_eq(&prepared.input_types).zip_eq(inputs) {
+            self.matches_register_type(value, register_type)?;
+            registers.store(self, input.register(), value.clone())?;
+        }
+        lap!(timer, "Store the inputs");
+
+        // If the function reads the input digest, compute the digest of the inputs.
+        if prepared.uses_input_digest {
+            // Compute the input digest, as the hash of the field elements of the inputs.
+            let preimage = inputs.iter().map(|input| input.to_fields()).collect::<Result<Vec<_>>>()?.concat();
+            // Set the input digest.
+            registers.set_input_digest(N::hash_psd8(&preimage)?);
+        }
+
+        // Evaluate the instructions.
+        for instruction in function.instructions() {
+            // If the evaluation fails, bail and return the error.
+            if let Err(error) = instruction.evaluate(self, &mut registers) {
+                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            }
+        }
+        lap!(timer, "Evaluate the instructions");
+
+        // Load the outputs, and map the output operands to registers.
+        let mut outputs = Vec::with_capacity(function.outputs().len());
+        let mut output_registers = Vec::with_capacity(function.outputs().len());
+        for output in function.outputs() {
+            outputs.push(registers.load(self, output.operand())?);
+            output_registers.push(match output.operand() {
+                Operand::Register(register) => Some(register.clone()),
+                _ => None,
+            });
+        }
+        lap!(timer, "Load the outputs");
+
+        // Compute the response.
+        let response = Response::new(
+            request.network_id(),
+            self.program.id(),
+            function.name(),
+            request.inputs().len(),
+            request.tvk(),
+            request.tcm(),
+            outputs,
+            &function.output_types(),
+            &output_registers,
+        );
+        finish!(timer);
+
+        response
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod evaluate;
+mod registers;
+
+use registers::PreparedRegisters;
+
+use super::*;
+
+/// A function that is prepared for repeated evaluation.
+///
+/// A prepared function is derived from a type-checked function, so its registers are resolved once to dense indices,
+/// and the register values are neither hashed on access nor checked against their types on each store.
+/// The inputs of each request are still checked against their types.
+///
+/// The instructions are evaluated as in `Process::evaluate`, where each instruction dispatches on its enum variant,
+/// so a prepared function speeds up register access, and not instruction dispatch.
+/// Functions with a `call` instruction, whether to a closure or to another function, cannot be prepared,
+/// and must be evaluated with `Process::evaluate`.
+#[derive(Clone)]
+pub struct PreparedFunction<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The function.
+    function: Function<N>,
+    /// The register types of the inputs, in order.
+    input_types: Vec<RegisterType<N>>,
+    /// The number of registers, which is one more than the largest register locator.
+    num_registers: usize,
+    /// Whether the function reads the input digest.
+    uses_input_digest: bool,
+}
+
+impl<N: Network> PreparedFunction<N> {
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the function.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        self.function.name()
+    }
+
+    /// Returns the number of registers.
+    pub const fn num_registers(&self) -> usize {
+        self.num_registers
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Prepares the given function for repeated evaluation.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist, or if the function contains a `call` instruction.
+    pub fn prepare_function(&self, function_name: &Identifier<N>) -> Result<PreparedFunction<N>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Ensure the function does not call a closure or another function.
+        ensure!(
+            !function.instructions().iter().any(|instruction| matches!(instruction, Instruction::Call(..))),
+            "Function '{}/{function_name}' contains a call, and cannot be prepared",
+            self.program_id()
+        );
+
+        // Retrieve the register types, which exist once the function is type-checked.
+        let register_types = self.get_register_types(function_name)?;
+        // Retrieve the register types of the inputs.
+        let input_types = function
+            .inputs()
+            .iter()
+            .map(|input| register_types.get_type(self, input.register()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the number of registers, from the input and destination registers.
+        let max_locator = function
+            .inputs()
+            .iter()
+            .map(|input| input.register().locator())
+            .chain(function.instructions().iter().flat_map(|instruction| {
+                instruction.destinations().into_iter().map(|register| register.locator())
+            }))
+            .max();
+        let num_registers = match max_locator {
+            Some(locator) => usize::try_from(locator)?.saturating_add(1),
+            None => 0,
+        };
+
+        Ok(PreparedFunction {
+            program_id: *self.program_id(),
+            uses_input_digest: Self::uses_input_digest(&function),
+            function,
+            input_types,
+            num_registers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_evaluate_prepared() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program prepared.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function scale:
+    input r0 as point.private;
+    input r1 as u64.public;
+    mul r0.x r1 into r2;
+    mul r0.y r1 into r3;
+    cast r2 r3 into r4 as point;
+    add r4.x r4.y into r5;
+    output r4 as point.private;
+    output r5 as u64.public;
+
+function relay:
+    input r0 as u64.private;
+    call twice r0 into r1;
+    output r1 as u64.private;",
+        )?;
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        process.add_program(&program)?;
+        let function_name = Identifier::from_str("scale")?;
+
+        // Prepare the function.
+        let prepared = process.prepare_function(program.id(), &function_name)?;
+        assert_eq!(prepared.function_name(), &function_name);
+        assert_eq!(prepared.num_registers(), 6);
+
+        // Ensure a function that calls a closure cannot be prepared.
+        assert!(process.prepare_function(program.id(), &Identifier::from_str("relay")?).is_err());
+
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let inputs = [Value::from_str("{ x: 2u64, y: 3u64 }")?, Value::from_str("5u64")?];
+
+        // Evaluate the function repeatedly, and ensure the response matches the unprepared evaluation.
+        for _ in 0..2 {
+            let authorization =
+                process.authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, inputs.iter(), rng)?;
+            let expected = process.evaluate::<CurrentAleo>(authorization.replicate())?;
+            let candidate = process.evaluate_prepared(&prepared, authorization)?;
+            assert_eq!(expected.outputs(), candidate.outputs());
+            assert_eq!(candidate.outputs()[1], Value::from_str("25u64")?);
+        }
+
+        // Ensure the prepared function rejects a request for a different function.
+        let inputs = [Value::<CurrentNetwork>::from_str("1u64")?];
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), "relay", inputs.iter(), rng)?;
+        assert!(process.evaluate_prepared(&prepared, authorization).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+
+/// A console-only view of the registers of a prepared function.
+///
+/// The register values are held in a dense vector indexed by locator, and are not checked against their types,
+/// as the instructions of a prepared function are type-checked when the program is added.
+pub(super) struct PreparedRegisters<N: Network> {
+    /// The register values, indexed by locator.
+    values: Vec<Option<Value<N>>>,
+    /// The transition signer.
+    signer: Option<Address<N>>,
+    /// The transition caller.
+    caller: Option<Address<N>>,
+    /// The transition view key.
+    tvk: Option<Field<N>>,
+    /// The digest of the transition inputs.
+    input_digest: Option<Field<N>>,
+}
+
+impl<N: Network> PreparedRegisters<N> {
+    /// Initializes a new set of registers, with the given number of unassigned registers.
+    #[inline]
+    pub(super) fn new(num_registers: usize) -> Self {
+        Self { values: vec![None; num_registers], signer: None, caller: None, tvk: None, input_digest: None }
+    }
+}
+
+impl<N: Network> RegistersSigner<N> for PreparedRegisters<N> {
+    /// Returns the transition signer.
+    #[inline]
+    fn signer(&self) -> Result<Address<N>> {
+        self.signer.ok_or_else(|| anyhow!("Signer address (console) is not set in the registers."))
+    }
+
+    /// Sets the transition signer.
+    #[inline]
+    fn set_signer(&mut self, signer: Address<N>) {
+        self.signer = Some(signer);
+    }
+
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        self.caller.ok_or_else(|| anyhow!("Caller address (console) is not set in the registers."))
+    }
+
+    /// Sets the transition caller.
+    #[inline]
+    fn set_caller(&mut self, caller: Address<N>) {
+        self.caller = Some(caller);
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        self.tvk.ok_or_else(|| anyhow!("Transition view key (console) is not set in the registers."))
+    }
+
+    /// Sets the transition view key.
+    #[inline]
+    fn set_tvk(&mut self, tvk: Field<N>) {
+        self.tvk = Some(tvk);
+    }
+
+    /// Returns the digest of the transition inputs.
+    #[inline]
+    fn input_digest(&self) -> Result<Field<N>> {
+        self.input_digest.ok_or_else(|| anyhow!("Input digest (console) is not set in the registers."))
+    }
+
+    /// Sets the digest of the transition inputs.
+    #[inline]
+    fn set_input_digest(&mut self, input_digest: Field<N>) {
+        self.input_digest = Some(input_digest);
+    }
+}
+
+impl<N: Network> RegistersLoad<N> for PreparedRegisters<N> {
+    /// Loads the value of a given operand from the registers.
+    ///
+    /// # Errors
+    /// This method will halt if the register is not assigned.
+    /// In the case of register accesses, this method will halt if the access is not found.
+    #[inline]
+    fn load(&self, _stack: &(impl StackMatches<N> + StackProgram<N>), operand: &Operand<N>) -> Result<Value<N>> {
+        // Retrieve the register.
+        let register = match operand {
+            // If the operand is a literal, return the literal.
+            Operand::Literal(literal) => return Ok(Value::Plaintext(Plaintext::from(literal))),
+            // If the operand is a register, load the value from the register.
+            Operand::Register(register) => register,
+            // If the operand is the program ID, load the program address.
+            Operand::ProgramID(program_id) => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))));
+            }
+            // If the operand is the signer, load the value of the signer.
+            Operand::Signer => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.signer()?)))),
+            // If the operand is the caller, load the value of the caller.
+            Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the input digest, load the value of the input digest.
+            Operand::InputDigest => return Ok(Value::Plaintext(Plaintext::from(Literal::Field(self.input_digest()?)))),
+        };
+
+        // Retrieve the stack value.
+        let stack_value = self
+            .values
+            .get(usize::try_from(register.locator())?)
+            .and_then(Option::as_ref)
+            .ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+
+        // Return the value for the given register or register access.
+        match register {
+            // If the register is a locator, then return the stack value.
+            Register::Locator(..) => Ok(stack_value.clone()),
+            // If the register is a register access, then load the specific stack value.
+            Register::Access(_, ref path) => match stack_value {
+                // Retrieve the plaintext member from the path.
+                Value::Plaintext(plaintext) => Ok(Value::Plaintext(plaintext.find(path)?)),
+                // Retrieve the record entry from the path.
+                Value::Record(record) => match record.find(path)? {
+                    Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                        Ok(Value::Plaintext(plaintext))
+                    }
+                },
+                // Retrieve the argument from the future.
+                Value::Future(future) => future.find(path),
+            },
+        }
+    }
+}
+
+impl<N: Network> RegistersStore<N> for PreparedRegisters<N> {
+    /// Assigns the given value to the given register, assuming the register is not already assigned.
+    ///
+    /// # Errors
+    /// This method will halt if the given register is a register access.
+    /// This method will halt if the register is already used.
+    #[inline]
+    fn store(
+        &mut self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+        stack_value: Value<N>,
+    ) -> Result<()> {
+        match register {
+            Register::Locator(locator) => {
+                // Retrieve the slot of the register.
+                let slot = self
+                    .values
+                    .get_mut(usize::try_from(*locator)?)
+                    .ok_or_else(|| anyhow!("Register '{register}' is not a member of the function"))?;
+                // Ensure the register does not already exist.
+                ensure!(slot.is_none(), "Cannot write to occupied register '{register}'");
+                // Store the stack value.
+                *slot = Some(stack_value);
+                Ok(())
+            }
+            // Ensure the register is not a register access.
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
+        }
+    }
+}
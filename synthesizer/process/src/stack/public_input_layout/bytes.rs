@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for PublicInput<N> {
+    /// Reads the public input from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the public input.
+        let input = match variant {
+            0 => Self::One,
+            1 => Self::TpkX,
+            2 => Self::TpkY,
+            3 => Self::Tcm,
+            4 => Self::InputId(u16::read_le(&mut reader)?, ValueType::read_le(&mut reader)?),
+            5 => Self::InputTag(u16::read_le(&mut reader)?),
+            6 => Self::IsRoot,
+            7 => Self::ParentX,
+            8 => Self::ParentY,
+            9 => Self::CallTcm(u16::read_le(&mut reader)?, Locator::read_le(&mut reader)?),
+            10 => Self::CallInputId(
+                u16::read_le(&mut reader)?,
+                Locator::read_le(&mut reader)?,
+                u16::read_le(&mut reader)?,
+                ValueType::read_le(&mut reader)?,
+            ),
+            11 => Self::CallInputTag(
+                u16::read_le(&mut reader)?,
+                Locator::read_le(&mut reader)?,
+                u16::read_le(&mut reader)?,
+            ),
+            12 => Self::CallOutputId(
+                u16::read_le(&mut reader)?,
+                Locator::read_le(&mut reader)?,
+                u16::read_le(&mut reader)?,
+                ValueType::read_le(&mut reader)?,
+            ),
+            13 => Self::OutputId(u16::read_le(&mut reader)?, ValueType::read_le(&mut reader)?),
+            14 => Self::OutputChecksum(u16::read_le(&mut reader)?),
+            15.. => return Err(error(format!("Failed to deserialize a public input variant {variant}"))),
+        };
+        Ok(input)
+    }
+}
+
+impl<N: Network> ToBytes for PublicInput<N> {
+    /// Writes the public input to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::One => 0u8.write_le(&mut writer),
+            Self::TpkX => 1u8.write_le(&mut writer),
+            Self::TpkY => 2u8.write_le(&mut writer),
+            Self::Tcm => 3u8.write_le(&mut writer),
+            Self::InputId(index, value_type) => {
+                4u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                value_type.write_le(&mut writer)
+            }
+            Self::InputTag(index) => {
+                5u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::IsRoot => 6u8.write_le(&mut writer),
+            Self::ParentX => 7u8.write_le(&mut writer),
+            Self::ParentY => 8u8.write_le(&mut writer),
+            Self::CallTcm(call, locator) => {
+                9u8.write_le(&mut writer)?;
+                call.write_le(&mut writer)?;
+                locator.write_le(&mut writer)
+            }
+            Self::CallInputId(call, locator, index, value_type) => {
+                10u8.write_le(&mut writer)?;
+                call.write_le(&mut writer)?;
+                locator.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                value_type.write_le(&mut writer)
+            }
+            Self::CallInputTag(call, locator, index) => {
+                11u8.write_le(&mut writer)?;
+                call.write_le(&mut writer)?;
+                locator.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::CallOutputId(call, locator, index, value_type) => {
+                12u8.write_le(&mut writer)?;
+                call.write_le(&mut writer)?;
+                locator.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                value_type.write_le(&mut writer)
+            }
+            Self::OutputId(index, value_type) => {
+                13u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                value_type.write_le(&mut writer)
+            }
+            Self::OutputChecksum(index) => {
+                14u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for PublicInputLayout<N> {
+    /// Reads the public input layout from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the locator.
+        let locator = Locator::read_le(&mut reader)?;
+        // Read the number of public inputs.
+        let num_inputs = u32::read_le(&mut reader)?;
+        // Read the public inputs.
+        let inputs = (0..num_inputs).map(|_| PublicInput::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the public input layout.
+        Ok(Self { locator, inputs })
+    }
+}
+
+impl<N: Network> ToBytes for PublicInputLayout<N> {
+    /// Writes the public input layout to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the locator.
+        self.locator.write_le(&mut writer)?;
+        // Write the number of public inputs.
+        u32::try_from(self.inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the public inputs.
+        self.inputs.iter().try_for_each(|input| input.write_le(&mut writer))
+    }
+}
+
+impl<N: Network> FromBytes for VerifyingKeyWithLayout<N> {
+    /// Reads the verifying key and its public input layout from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid verifying key with layout version"));
+        }
+        // Read the verifying key.
+        let verifying_key = VerifyingKey::read_le(&mut reader)?;
+        // Read the public input layout.
+        let layout = PublicInputLayout::read_le(&mut reader)?;
+        // Return the verifying key with its layout, ensuring the layout matches the verifying key.
+        Self::new(verifying_key, layout).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for VerifyingKeyWithLayout<N> {
+    /// Writes the verifying key and its public input layout to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the verifying key.
+        self.verifying_key.write_le(&mut writer)?;
+        // Write the public input layout.
+        self.layout.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+/// The source of a single public input in the circuit of a function.
+/// Each public input is exactly one field element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicInput<N: Network> {
+    /// The constant `1`, which is the first public input of every circuit.
+    One,
+    /// The x-coordinate of the transition public key.
+    TpkX,
+    /// The y-coordinate of the transition public key.
+    TpkY,
+    /// The transition commitment.
+    Tcm,
+    /// The ID of the input at the given index, with its value type.
+    InputId(u16, ValueType<N>),
+    /// The tag of the record input at the given index.
+    InputTag(u16),
+    /// The field element `1` if the transition is the root transition, and `0` otherwise.
+    IsRoot,
+    /// The x-coordinate of the parent address.
+    ParentX,
+    /// The y-coordinate of the parent address.
+    ParentY,
+    /// The transition commitment of the external call at the given index.
+    CallTcm(u16, Locator<N>),
+    /// The ID of the input at the given index, of the external call at the given index.
+    CallInputId(u16, Locator<N>, u16, ValueType<N>),
+    /// The tag of the record input at the given index, of the external call at the given index.
+    CallInputTag(u16, Locator<N>, u16),
+    /// The ID of the output at the given index, of the external call at the given index.
+    CallOutputId(u16, Locator<N>, u16, ValueType<N>),
+    /// The ID of the output at the given index, with its value type.
+    OutputId(u16, ValueType<N>),
+    /// The checksum of the record output at the given index.
+    OutputChecksum(u16),
+}
+
+/// The ordered layout of the public inputs for the circuit of a function.
+///
+/// The layout matches the order in which the verifier inputs for a transition are constructed,
+/// allowing an external verifier to assemble the public inputs from a transition and its direct external calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicInputLayout<N: Network> {
+    /// The locator of the function.
+    locator: Locator<N>,
+    /// The public inputs, in order.
+    inputs: Vec<PublicInput<N>>,
+}
+
+impl<N: Network> PublicInputLayout<N> {
+    /// Returns the locator of the function.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the public inputs, in order.
+    pub fn inputs(&self) -> &[PublicInput<N>] {
+        &self.inputs
+    }
+
+    /// Returns the number of public inputs.
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Returns `true` if there are no public inputs.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Ensures the layout matches the number of public inputs of the given verifying key.
+    /// Note: The constraint system prepends its own constant `1` to the public inputs of the circuit,
+    /// and pads them with zeros, up to the next power of two.
+    pub fn check_verifying_key(&self, verifying_key: &VerifyingKey<N>) -> Result<()> {
+        let num_public_inputs = verifying_key.circuit_info.num_public_inputs;
+        ensure!(
+            (self.len() + 1).next_power_of_two() == num_public_inputs,
+            "The public input layout of '{}' has {} inputs, but the verifying key has {num_public_inputs} padded public inputs",
+            self.locator,
+            self.len()
+        );
+        Ok(())
+    }
+}
+
+/// The verifying key for the circuit of a function, together with the layout of its public inputs.
+///
+/// The layout is checked against the verifying key on construction and on deserialization,
+/// so an external verifier can trust the layout to assemble the public inputs for the verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKeyWithLayout<N: Network> {
+    /// The verifying key.
+    verifying_key: VerifyingKey<N>,
+    /// The layout of the public inputs.
+    layout: PublicInputLayout<N>,
+}
+
+impl<N: Network> VerifyingKeyWithLayout<N> {
+    /// Initializes a new verifying key with its layout, ensuring the layout matches the verifying key.
+    pub fn new(verifying_key: VerifyingKey<N>, layout: PublicInputLayout<N>) -> Result<Self> {
+        layout.check_verifying_key(&verifying_key)?;
+        Ok(Self { verifying_key, layout })
+    }
+
+    /// Returns the verifying key.
+    pub const fn verifying_key(&self) -> &VerifyingKey<N> {
+        &self.verifying_key
+    }
+
+    /// Returns the layout of the public inputs.
+    pub const fn layout(&self) -> &PublicInputLayout<N> {
+        &self.layout
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the layout of the public inputs for the circuit of the given function.
+    pub fn get_public_input_layout(&self, function_name: &Identifier<N>) -> Result<PublicInputLayout<N>> {
+        // Retrieve the function.
+        let function = self.get_function_ref(function_name)?;
+
+        // [Inputs] Add the transition public key and transition commitment.
+        let mut inputs = vec![PublicInput::One, PublicInput::TpkX, PublicInput::TpkY, PublicInput::Tcm];
+        // [Inputs] Add the input IDs, and the tags of the record inputs.
+        for (index, input_type) in function.input_types().into_iter().enumerate() {
+            let index = u16::try_from(index)?;
+            let is_record = matches!(input_type, ValueType::Record(..));
+            inputs.push(PublicInput::InputId(index, input_type));
+            if is_record {
+                inputs.push(PublicInput::InputTag(index));
+            }
+        }
+        // [Inputs] Add the public inputs for 'self.caller'.
+        inputs.extend([PublicInput::IsRoot, PublicInput::ParentX, PublicInput::ParentY]);
+
+        // [Inputs] Add the inputs and outputs of each external function call, in order of the calls.
+        let mut num_calls = 0u16;
+        for instruction in function.instructions() {
+            // Retrieve the locator of the external call, skipping instructions that are not calls.
+            let locator = match instruction {
+                Instruction::Call(call) => match call.operator() {
+                    CallOperator::Locator(locator) => locator,
+                    // Note: Calls to a resource in this program are closure calls, which do not produce a transition.
+                    CallOperator::Resource(..) => continue,
+                },
+                _ => continue,
+            };
+            // Retrieve the external function, skipping calls to external closures.
+            let external_stack = self.get_external_stack(locator.program_id())?;
+            let Ok(external_function) = external_stack.get_function_ref(locator.resource()) else {
+                continue;
+            };
+            // Add the transition commitment of the external call.
+            inputs.push(PublicInput::CallTcm(num_calls, *locator));
+            // Add the input IDs of the external call, and the tags of the record inputs.
+            for (index, input_type) in external_function.input_types().into_iter().enumerate() {
+                let index = u16::try_from(index)?;
+                let is_record = matches!(input_type, ValueType::Record(..));
+                inputs.push(PublicInput::CallInputId(num_calls, *locator, index, input_type));
+                if is_record {
+                    inputs.push(PublicInput::CallInputTag(num_calls, *locator, index));
+                }
+            }
+            // Add the output IDs of the external call.
+            for (index, output_type) in external_function.output_types().into_iter().enumerate() {
+                inputs.push(PublicInput::CallOutputId(num_calls, *locator, u16::try_from(index)?, output_type));
+            }
+            num_calls = num_calls.checked_add(1).ok_or_else(|| anyhow!("Overflow in the number of calls"))?;
+        }
+
+        // [Inputs] Add the output IDs, and the checksums of the record outputs.
+        for (index, output_type) in function.output_types().into_iter().enumerate() {
+            let index = u16::try_from(index)?;
+            let is_record = matches!(output_type, ValueType::Record(..));
+            inputs.push(PublicInput::OutputId(index, output_type));
+            if is_record {
+                inputs.push(PublicInput::OutputChecksum(index));
+            }
+        }
+
+        Ok(PublicInputLayout { locator: Locator::new(*self.program_id(), *function_name), inputs })
+    }
+
+    /// Returns the verifying key for the given function, together with the layout of its public inputs.
+    pub fn get_verifying_key_with_layout(&self, function_name: &Identifier<N>) -> Result<VerifyingKeyWithLayout<N>> {
+        // Retrieve the verifying key.
+        let verifying_key = self.get_verifying_key(function_name)?;
+        // Construct the public input layout.
+        let layout = self.get_public_input_layout(function_name)?;
+        // Return the verifying key with its layout.
+        VerifyingKeyWithLayout::new(verifying_key, layout)
+    }
+}
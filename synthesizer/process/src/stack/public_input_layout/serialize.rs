@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for PublicInput<N> {
+    /// Serializes the public input into its source, and the index, locator, and type it refers to.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                // Retrieve the source, the optional call index and locator, the optional index, and the optional type.
+                let (source, call, index, value_type) = match self {
+                    Self::One => ("one", None, None, None),
+                    Self::TpkX => ("tpk_x", None, None, None),
+                    Self::TpkY => ("tpk_y", None, None, None),
+                    Self::Tcm => ("tcm", None, None, None),
+                    Self::InputId(index, value_type) => ("input_id", None, Some(index), Some(value_type)),
+                    Self::InputTag(index) => ("input_tag", None, Some(index), None),
+                    Self::IsRoot => ("is_root", None, None, None),
+                    Self::ParentX => ("parent_x", None, None, None),
+                    Self::ParentY => ("parent_y", None, None, None),
+                    Self::CallTcm(call, locator) => ("call_tcm", Some((call, locator)), None, None),
+                    Self::CallInputId(call, locator, index, value_type) => {
+                        ("call_input_id", Some((call, locator)), Some(index), Some(value_type))
+                    }
+                    Self::CallInputTag(call, locator, index) => {
+                        ("call_input_tag", Some((call, locator)), Some(index), None)
+                    }
+                    Self::CallOutputId(call, locator, index, value_type) => {
+                        ("call_output_id", Some((call, locator)), Some(index), Some(value_type))
+                    }
+                    Self::OutputId(index, value_type) => ("output_id", None, Some(index), Some(value_type)),
+                    Self::OutputChecksum(index) => ("output_checksum", None, Some(index), None),
+                };
+
+                // Compute the number of fields.
+                let num_fields =
+                    1 + 2 * call.is_some() as usize + index.is_some() as usize + value_type.is_some() as usize;
+
+                let mut input = serializer.serialize_struct("PublicInput", num_fields)?;
+                input.serialize_field("source", source)?;
+                if let Some((call, locator)) = call {
+                    input.serialize_field("call", call)?;
+                    input.serialize_field("locator", locator)?;
+                }
+                if let Some(index) = index {
+                    input.serialize_field("index", index)?;
+                }
+                if let Some(value_type) = value_type {
+                    input.serialize_field("type", value_type)?;
+                }
+                input.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PublicInput<N> {
+    /// Deserializes the public input from its source, and the index, locator, and type it refers to.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the public input from a string into a value.
+                let mut input = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the source.
+                let source: String = DeserializeExt::take_from_value::<D>(&mut input, "source")?;
+
+                // Retrieves the given field of the public input.
+                fn take<'de, D: Deserializer<'de>, T: DeserializeOwned>(
+                    input: &mut serde_json::Value,
+                    field: &str,
+                ) -> Result<T, D::Error> {
+                    DeserializeExt::take_from_value::<D>(input, field)
+                }
+
+                // Recover the public input.
+                let input = match source.as_str() {
+                    "one" => Self::One,
+                    "tpk_x" => Self::TpkX,
+                    "tpk_y" => Self::TpkY,
+                    "tcm" => Self::Tcm,
+                    "input_id" => Self::InputId(take::<D, _>(&mut input, "index")?, take::<D, _>(&mut input, "type")?),
+                    "input_tag" => Self::InputTag(take::<D, _>(&mut input, "index")?),
+                    "is_root" => Self::IsRoot,
+                    "parent_x" => Self::ParentX,
+                    "parent_y" => Self::ParentY,
+                    "call_tcm" => {
+                        Self::CallTcm(take::<D, _>(&mut input, "call")?, take::<D, _>(&mut input, "locator")?)
+                    }
+                    "call_input_id" => Self::CallInputId(
+                        take::<D, _>(&mut input, "call")?,
+                        take::<D, _>(&mut input, "locator")?,
+                        take::<D, _>(&mut input, "index")?,
+                        take::<D, _>(&mut input, "type")?,
+                    ),
+                    "call_input_tag" => Self::CallInputTag(
+                        take::<D, _>(&mut input, "call")?,
+                        take::<D, _>(&mut input, "locator")?,
+                        take::<D, _>(&mut input, "index")?,
+                    ),
+                    "call_output_id" => Self::CallOutputId(
+                        take::<D, _>(&mut input, "call")?,
+                        take::<D, _>(&mut input, "locator")?,
+                        take::<D, _>(&mut input, "index")?,
+                        take::<D, _>(&mut input, "type")?,
+                    ),
+                    "output_id" => {
+                        Self::OutputId(take::<D, _>(&mut input, "index")?, take::<D, _>(&mut input, "type")?)
+                    }
+                    "output_checksum" => Self::OutputChecksum(take::<D, _>(&mut input, "index")?),
+                    _ => return Err(de::Error::custom(format!("Invalid public input source '{source}'"))),
+                };
+                Ok(input)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "public input"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for PublicInputLayout<N> {
+    /// Serializes the public input layout into the function locator and the ordered public inputs.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut layout = serializer.serialize_struct("PublicInputLayout", 2)?;
+                layout.serialize_field("locator", &self.locator)?;
+                layout.serialize_field("inputs", &self.inputs)?;
+                layout.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PublicInputLayout<N> {
+    /// Deserializes the public input layout from the function locator and the ordered public inputs.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the public input layout from a string into a value.
+                let mut layout = serde_json::Value::deserialize(deserializer)?;
+                // Recover the public input layout.
+                Ok(Self {
+                    // Retrieve the locator.
+                    locator: DeserializeExt::take_from_value::<D>(&mut layout, "locator")?,
+                    // Retrieve the public inputs.
+                    inputs: DeserializeExt::take_from_value::<D>(&mut layout, "inputs")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "public input layout"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for VerifyingKeyWithLayout<N> {
+    /// Serializes the verifying key and its public input layout into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut verifying_key = serializer.serialize_struct("VerifyingKeyWithLayout", 2)?;
+                verifying_key.serialize_field("verifying_key", &self.verifying_key)?;
+                verifying_key.serialize_field("layout", &self.layout)?;
+                verifying_key.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for VerifyingKeyWithLayout<N> {
+    /// Deserializes the verifying key and its public input layout from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the verifying key with its layout from a string into a value.
+                let mut verifying_key = serde_json::Value::deserialize(deserializer)?;
+                // Recover the verifying key with its layout, ensuring the layout matches the verifying key.
+                Self::new(
+                    // Retrieve the verifying key.
+                    DeserializeExt::take_from_value::<D>(&mut verifying_key, "verifying_key")?,
+                    // Retrieve the public input layout.
+                    DeserializeExt::take_from_value::<D>(&mut verifying_key, "layout")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "verifying key with layout")
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RegisterTypes<N> {
+    /// Reads the register types from a buffer.
+    /// Note: The registers are assigned in order, so only their types are stored.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the input register types.
+        let num_inputs = u32::read_le(&mut reader)?;
+        let mut inputs = IndexMap::new();
+        for locator in 0..num_inputs {
+            inputs.insert(u64::from(locator), RegisterType::read_le(&mut reader)?);
+        }
+        // Read the destination register types.
+        let num_destinations = u32::read_le(&mut reader)?;
+        let mut destinations = IndexMap::new();
+        for index in 0..num_destinations {
+            destinations.insert(u64::from(num_inputs) + u64::from(index), RegisterType::read_le(&mut reader)?);
+        }
+        Ok(Self { inputs, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for RegisterTypes<N> {
+    /// Writes the register types to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the input register types.
+        u32::try_from(self.inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for register_type in self.inputs.values() {
+            register_type.write_le(&mut writer)?;
+        }
+        // Write the destination register types.
+        u32::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for register_type in self.destinations.values() {
+            register_type.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
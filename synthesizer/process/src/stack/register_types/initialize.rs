@@ -38,6 +38,11 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the closure does not read the input digest.
+            ensure!(
+                !instruction.operands().contains(&Operand::InputDigest),
+                "The input digest is not allowed in closures"
+            );
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -49,6 +54,8 @@ impl<N: Network> RegisterTypes<N> {
                 !matches!(output.register_type(), RegisterType::Record(..)),
                 "Closure outputs do not support records"
             );
+            // Ensure the closure output is not the input digest.
+            ensure!(output.operand() != &Operand::InputDigest, "The input digest is not allowed in closures");
 
             // Check the output operand type.
             register_types.check_output(stack, output.operand(), output.register_type())?;
@@ -410,6 +417,8 @@ impl<N: Network> RegisterTypes<N> {
                     instruction.destinations().len() == 1,
                     "Instruction '{instruction}' has multiple destinations."
                 );
+                // Ensure the fractional bits of a `fixed64` instruction are a literal.
+                check_fractional_bits_operand(instruction)?;
             }
             Opcode::Assert(opcode) => match opcode {
                 "assert.eq" => ensure!(
@@ -573,7 +582,18 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Linalg(opcode) => match opcode {
+                "dot" => ensure!(
+                    matches!(instruction, Instruction::Dot(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "matmul" => ensure!(
+                    matches!(instruction, Instruction::MatMul(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Sign | Opcode::VRF => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -669,7 +689,8 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
-    /// Ensures the opcode is a valid opcode and corresponds to the `commit` instruction.
+    /// Ensures the opcode is a valid opcode and corresponds to a `commit`, `commit.seal`, `commit.open`,
+    /// or `commit.verify` instruction.
     #[inline]
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
         // Ensure the instruction is the correct one.
@@ -698,6 +719,38 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::CommitPED128(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "commit.open" => ensure!(
+                matches!(instruction, Instruction::CommitOpen(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.seal" => ensure!(
+                matches!(instruction, Instruction::CommitSeal(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp256" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp512" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP512(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp768" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP768(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp1024" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP1024(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.ped64" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyPED64(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.ped128" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyPED128(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
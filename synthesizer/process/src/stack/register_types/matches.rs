@@ -88,6 +88,16 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a block height in a non-finalize scope"
                 ),
+                // Ensure the input digest type (field) matches the member type.
+                Operand::InputDigest => {
+                    // Retrieve the operand type.
+                    let operand_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the operand type matches the member type.
+                    ensure!(
+                        &operand_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{operand_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -162,6 +172,17 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 // If the operand is a block height type, throw an error.
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
+                // Ensure the input digest type (field) matches the element type.
+                Operand::InputDigest => {
+                    // Retrieve the operand type.
+                    let operand_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the operand type matches the element type.
+                    ensure!(
+                        &operand_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{operand_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -224,6 +245,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::BlockHeight => {
                 bail!("Forbidden operation: Cannot cast a block height as a record owner")
             }
+            Operand::InputDigest => {
+                bail!("Forbidden operation: Cannot cast an input digest as a record owner")
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -279,6 +303,16 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block height in the operand '{operand}'."
                             )
                         }
+                        // Ensure the input digest type (field) matches the entry type.
+                        Operand::InputDigest => {
+                            // Retrieve the operand type.
+                            let operand_type = &PlaintextType::Literal(LiteralType::Field);
+                            // Ensure the operand type matches the entry type.
+                            ensure!(
+                                operand_type == plaintext_type,
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{operand_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
 mod initialize;
 mod matches;
 
@@ -32,6 +33,7 @@ use console::{
     },
 };
 use synthesizer_program::{
+    check_fractional_bits_operand,
     CallOperator,
     Closure,
     Function,
@@ -70,6 +72,54 @@ impl<N: Network> RegisterTypes<N> {
         Self::initialize_function_types(stack, function)
     }
 
+    /// Checks that the (restored) register types match the register layout of the given closure.
+    /// Note: This does not type-check the instructions; their types are checked when the closure is executed.
+    #[inline]
+    pub fn check_closure_layout(&self, closure: &Closure<N>) -> Result<()> {
+        // Retrieve the input registers and types.
+        let inputs = closure.inputs().iter().map(|input| (input.register().locator(), input.register_type().clone()));
+        // Check the layout.
+        self.check_layout(closure.name(), inputs, closure.instructions())
+    }
+
+    /// Checks that the (restored) register types match the register layout of the given function.
+    /// Note: This does not type-check the instructions; their types are checked when the function is executed.
+    #[inline]
+    pub fn check_function_layout(&self, function: &Function<N>) -> Result<()> {
+        for input in function.inputs() {
+            ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported");
+            ensure!(!matches!(input.value_type(), ValueType::Future(..)), "Future inputs are not supported");
+        }
+        // Retrieve the input registers and types.
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| (input.register().locator(), RegisterType::from(input.value_type().clone())));
+        // Check the layout.
+        self.check_layout(function.name(), inputs, function.instructions())
+    }
+
+    /// Checks that the inputs have the given types, and that every instruction destination has a type, in order.
+    fn check_layout(
+        &self,
+        name: &Identifier<N>,
+        inputs: impl Iterator<Item = (u64, RegisterType<N>)>,
+        instructions: &[Instruction<N>],
+    ) -> Result<()> {
+        // Ensure the input registers have their declared types.
+        ensure!(
+            self.inputs.iter().map(|(locator, register_type)| (*locator, register_type.clone())).eq(inputs),
+            "The input register types do not match the inputs of '{name}'"
+        );
+        // Ensure the destination registers are the instruction destinations, in order.
+        let destinations = instructions.iter().flat_map(|instruction| instruction.destinations());
+        ensure!(
+            self.destinations.keys().copied().eq(destinations.map(|register| register.locator())),
+            "The destination register types do not match the instructions of '{name}'"
+        );
+        Ok(())
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -97,6 +147,7 @@ impl<N: Network> RegisterTypes<N> {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::InputDigest => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
         })
     }
 
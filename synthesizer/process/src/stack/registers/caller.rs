@@ -50,6 +50,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersSigner<N> for Registers
     fn set_tvk(&mut self, tvk: Field<N>) {
         self.tvk = Some(tvk);
     }
+
+    /// Returns the digest of the transition inputs.
+    #[inline]
+    fn input_digest(&self) -> Result<Field<N>> {
+        self.input_digest.ok_or_else(|| anyhow!("Input digest (console) is not set in the registers."))
+    }
+
+    /// Sets the digest of the transition inputs.
+    #[inline]
+    fn set_input_digest(&mut self, input_digest: Field<N>) {
+        self.input_digest = Some(input_digest);
+    }
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> RegistersSignerCircuit<N, A> for Registers<N, A> {
@@ -88,4 +100,16 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersSignerCircuit<N, A> for
     fn set_tvk_circuit(&mut self, tvk_circuit: circuit::Field<A>) {
         self.tvk_circuit = Some(tvk_circuit);
     }
+
+    /// Returns the digest of the transition inputs, as a circuit.
+    #[inline]
+    fn input_digest_circuit(&self) -> Result<circuit::Field<A>> {
+        self.input_digest_circuit.clone().ok_or_else(|| anyhow!("Input digest (circuit) is not set in the registers."))
+    }
+
+    /// Sets the digest of the transition inputs, as a circuit.
+    #[inline]
+    fn set_input_digest_circuit(&mut self, input_digest_circuit: circuit::Field<A>) {
+        self.input_digest_circuit = Some(input_digest_circuit);
+    }
 }
@@ -38,6 +38,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the input digest, load the value of the input digest.
+            Operand::InputDigest => return Ok(Value::Plaintext(Plaintext::from(Literal::Field(self.input_digest()?)))),
         };
 
         // Retrieve the stack value.
@@ -121,6 +123,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the input digest, load the value of the input digest.
+            Operand::InputDigest => {
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Field(
+                    self.input_digest_circuit()?,
+                ))));
+            }
         };
 
         // Retrieve the circuit value.
@@ -59,6 +59,10 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The digest of the transition inputs.
+    input_digest: Option<Field<N>>,
+    /// The digest of the transition inputs, as a circuit.
+    input_digest_circuit: Option<circuit::Field<A>>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -76,9 +80,23 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            input_digest: None,
+            input_digest_circuit: None,
         }
     }
 
+    /// Returns the mapping of all registers to their defined types.
+    #[inline]
+    pub(crate) const fn register_types(&self) -> &RegisterTypes<N> {
+        &self.register_types
+    }
+
+    /// Returns the mapping of assigned console registers to their values.
+    #[inline]
+    pub(crate) const fn console_registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.console_registers
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
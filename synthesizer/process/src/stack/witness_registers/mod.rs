@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod load;
+mod store;
+
+use crate::RegisterTypes;
+use console::{
+    network::prelude::*,
+    program::{Entry, Literal, Plaintext, Register, Value},
+    types::{Address, Field},
+};
+use synthesizer_program::{Operand, RegistersLoad, RegistersSigner, RegistersStore, StackMatches, StackProgram};
+
+use indexmap::IndexMap;
+
+/// A console-only view of the registers, used to evaluate a single instruction ahead of synthesis.
+///
+/// The registers read from a shared snapshot of the assigned values, and write into their own outputs,
+/// so that independent instructions can be evaluated on separate threads and merged afterwards.
+pub(crate) struct WitnessRegisters<'a, N: Network> {
+    /// The mapping of all registers to their defined types.
+    register_types: &'a RegisterTypes<N>,
+    /// The snapshot of assigned registers to their values.
+    assigned: &'a IndexMap<u64, Value<N>>,
+    /// The mapping of registers assigned by the instruction to their values.
+    outputs: IndexMap<u64, Value<N>>,
+    /// The transition signer.
+    signer: Option<Address<N>>,
+    /// The transition caller.
+    caller: Option<Address<N>>,
+    /// The transition view key.
+    tvk: Option<Field<N>>,
+    /// The digest of the transition inputs.
+    input_digest: Option<Field<N>>,
+}
+
+impl<'a, N: Network> WitnessRegisters<'a, N> {
+    /// Initializes a new set of witness registers over the given snapshot of assigned registers.
+    #[inline]
+    pub(crate) fn new(
+        register_types: &'a RegisterTypes<N>,
+        assigned: &'a IndexMap<u64, Value<N>>,
+        signer: Option<Address<N>>,
+        caller: Option<Address<N>>,
+        tvk: Option<Field<N>>,
+        input_digest: Option<Field<N>>,
+    ) -> Self {
+        Self { register_types, assigned, outputs: IndexMap::new(), signer, caller, tvk, input_digest }
+    }
+
+    /// Returns the registers assigned by the instruction.
+    #[inline]
+    pub(crate) fn into_outputs(self) -> IndexMap<u64, Value<N>> {
+        self.outputs
+    }
+}
+
+impl<'a, N: Network> RegistersSigner<N> for WitnessRegisters<'a, N> {
+    /// Returns the transition signer.
+    #[inline]
+    fn signer(&self) -> Result<Address<N>> {
+        self.signer.ok_or_else(|| anyhow!("Signer address (console) is not set in the registers."))
+    }
+
+    /// Sets the transition signer.
+    #[inline]
+    fn set_signer(&mut self, signer: Address<N>) {
+        self.signer = Some(signer);
+    }
+
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        self.caller.ok_or_else(|| anyhow!("Caller address (console) is not set in the registers."))
+    }
+
+    /// Sets the transition caller.
+    #[inline]
+    fn set_caller(&mut self, caller: Address<N>) {
+        self.caller = Some(caller);
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        self.tvk.ok_or_else(|| anyhow!("Transition view key (console) is not set in the registers."))
+    }
+
+    /// Sets the transition view key.
+    #[inline]
+    fn set_tvk(&mut self, tvk: Field<N>) {
+        self.tvk = Some(tvk);
+    }
+
+    /// Returns the digest of the transition inputs.
+    #[inline]
+    fn input_digest(&self) -> Result<Field<N>> {
+        self.input_digest.ok_or_else(|| anyhow!("Input digest (console) is not set in the registers."))
+    }
+
+    /// Sets the digest of the transition inputs.
+    #[inline]
+    fn set_input_digest(&mut self, input_digest: Field<N>) {
+        self.input_digest = Some(input_digest);
+    }
+}
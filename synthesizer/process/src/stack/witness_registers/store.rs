@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<'a, N: Network> RegistersStore<N> for WitnessRegisters<'a, N> {
+    /// Assigns the given value to the given register, assuming the register is not already assigned.
+    /// The order of the writes is checked when the values are stored into the execution registers.
+    ///
+    /// # Errors
+    /// This method will halt if the given register is a register access.
+    /// This method will halt if the register is already used.
+    #[inline]
+    fn store(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+        stack_value: Value<N>,
+    ) -> Result<()> {
+        match register {
+            Register::Locator(locator) => {
+                // Ensure the register does not already exist.
+                ensure!(
+                    !self.assigned.contains_key(locator) && !self.outputs.contains_key(locator),
+                    "Cannot write to occupied register '{register}'"
+                );
+
+                // Ensure the register type is valid.
+                match self.register_types.get_type(stack, register) {
+                    // Ensure the stack value matches the register type.
+                    Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
+                    // Ensure the register is defined.
+                    Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
+                };
+
+                // Store the stack value.
+                self.outputs.insert(*locator, stack_value);
+                Ok(())
+            }
+            // Ensure the register is not a register access.
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
+        }
+    }
+}
@@ -14,18 +14,21 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Assignments,
     CallStack,
     Process,
+    PublicInput,
     Trace,
+    VerifyingKeyWithLayout,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Value, ValueType},
     types::{Field, U64},
 };
-use ledger_block::Fee;
+use ledger_block::{Fee, Transition};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -34,7 +37,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, ProgramPolicy};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -46,7 +49,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -764,6 +767,130 @@ fn test_process_self_caller() {
     assert_eq!(output, candidate[0]);
 }
 
+#[test]
+fn test_process_restricted_caller() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the allowed caller account.
+    let allowed_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let allowed_caller = Address::try_from(&allowed_private_key).unwrap();
+    // Initialize a caller account that is not allowed.
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"program restricted.aleo;
+
+  function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;
+
+  restrict compute to {allowed_caller};"
+    ))
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the input and output value.
+    let input = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    let output = Value::<CurrentNetwork>::from_str("10u64").unwrap();
+
+    // Authorize the function call from the allowed caller.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&allowed_private_key, program.id(), function_name, [input.clone()].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [output.clone()]);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [output]);
+
+    // Ensure the function call from a caller that is not allowed fails.
+    let result =
+        process.authorize::<CurrentAleo, _>(&other_private_key, program.id(), function_name, [input].iter(), rng);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_process_input_digest() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program digest.aleo;
+
+  struct data:
+    digest as field;
+
+  function compute:
+    input r0 as u64.private;
+    input r1 as field.public;
+    cast input.digest into r2 as data;
+    output r2 as data.private;
+    output input.digest as field.public;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the inputs.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap(), Value::from_str("7field").unwrap()];
+
+    // Compute the expected input digest.
+    let preimage = inputs.iter().map(|input| input.to_fields()).collect::<Result<Vec<_>>>().unwrap().concat();
+    let digest = CurrentNetwork::hash_psd8(&preimage).unwrap();
+    let expected = [Value::from_str(&format!("{{ digest: {digest} }}")).unwrap(), Value::from(Literal::Field(digest))];
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure a closure may not read the input digest.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program digest_closure.aleo;
+
+  closure compute:
+    input r0 as field;
+    add r0 input.digest into r1;
+    output r1 as field;
+
+  function main:
+    input r0 as field.private;
+    output r0 as field.private;",
+    )
+    .unwrap();
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
 #[test]
 fn test_process_program_id() {
     // Initialize a new program.
@@ -1191,6 +1318,175 @@ function transfer:
     // assert_eq!(215810, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_public_input_layout() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function produce_magic_number:
+    add 1234u64 0u64 into r0;
+    output r0 as u64.private;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function transfer:
+    input r0 as token.aleo/token.record;
+    input r1 as address.private;
+    input r2 as u64.public;
+    call token.aleo/produce_magic_number into r3;
+    call token.aleo/transfer r0 r1 r2 into r4 r5;
+    output r3 as u64.public;
+    output r4 as token.aleo/token.record;
+    output r5 as token.aleo/token.record;",
+    )
+    .unwrap();
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("99u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+
+    // Ensure the public input layout of each function matches the number of verifier inputs of its transition.
+    // Note: The transitions are in post-order, so the first transitions are the external calls of the last.
+    let transitions = authorization.transitions().into_values().collect::<Vec<_>>();
+    assert_eq!(transitions.len(), 3);
+    let num_verifier_inputs = |transition: &Transition<CurrentNetwork>, calls: &[Transition<CurrentNetwork>]| {
+        4 + transition.inputs().iter().flat_map(|input| input.verifier_inputs()).count()
+            + 3
+            + calls
+                .iter()
+                .map(|call| {
+                    1 + call.inputs().iter().flat_map(|input| input.verifier_inputs()).count()
+                        + call.output_ids().count()
+                })
+                .sum::<usize>()
+            + transition.outputs().iter().flat_map(|output| output.verifier_inputs()).count()
+    };
+    for (index, transition) in transitions.iter().enumerate() {
+        let stack = process.get_stack(transition.program_id()).unwrap();
+        let layout = stack.get_public_input_layout(transition.function_name()).unwrap();
+        let calls = if index == transitions.len() - 1 { &transitions[..index] } else { &[] };
+        assert_eq!(layout.len(), num_verifier_inputs(transition, calls));
+    }
+
+    // Ensure the layout records the record input tag and the record output checksum.
+    let layout = process.get_stack(program0.id()).unwrap().get_public_input_layout(&function_name).unwrap();
+    assert_eq!(layout.inputs()[5], PublicInput::InputTag(0));
+    assert_eq!(layout.inputs().last(), Some(&PublicInput::OutputChecksum(1)));
+
+    // Ensure the layout records the external calls, and external records do not have a tag or checksum.
+    let layout = process.get_stack(program1.id()).unwrap().get_public_input_layout(&function_name).unwrap();
+    let locator = Locator::from_str("token.aleo/transfer").unwrap();
+    assert_eq!(
+        layout.inputs()[4],
+        PublicInput::InputId(0, ValueType::ExternalRecord(Locator::from_str("token.aleo/token").unwrap()))
+    );
+    assert_eq!(layout.inputs()[5], PublicInput::InputId(1, ValueType::from_str("address.private").unwrap()));
+    assert!(layout.inputs().contains(&PublicInput::CallTcm(1, locator)));
+    assert!(layout.inputs().contains(&PublicInput::CallInputTag(1, locator, 0)));
+    assert!(matches!(layout.inputs().last(), Some(PublicInput::OutputId(2, ValueType::ExternalRecord(..)))));
+    assert!(layout.to_string().contains(r#"{"source":"call_tcm","call":1,"locator":"token.aleo/transfer"}"#));
+    assert!(layout.to_string().contains(r#"{"source":"input_id","index":2,"type":"u64.public"}"#));
+
+    // Ensure the public input layout of each function matches the public inputs of its circuit.
+    let requests = authorization.to_vec_deque();
+    for (index, transition) in transitions.iter().enumerate() {
+        // Synthesize the circuit of the function, with the request of the transition.
+        // Note: The root transition is the last, and the external calls are made by the root program.
+        let request = requests.iter().find(|request| request.tcm() == transition.tcm()).unwrap().clone();
+        let is_root = index == transitions.len() - 1;
+        let console_caller = if is_root { None } else { Some(*program1.id()) };
+        let assignments = Assignments::<CurrentNetwork>::default();
+        let call_stack = CallStack::CheckDeployment(vec![request], caller_private_key, assignments.clone());
+        let stack = process.get_stack(transition.program_id()).unwrap();
+        stack.execute_function::<CurrentAleo, _>(call_stack, console_caller, rng).unwrap();
+
+        // Note: The external calls of a deployment check are not synthesized, so there is one assignment.
+        let assignments = assignments.read();
+        assert_eq!(assignments.len(), 1);
+        let (assignment, _) = &assignments[0];
+        let layout = stack.get_public_input_layout(transition.function_name()).unwrap();
+        assert_eq!(layout.len() as u64, assignment.num_public());
+        // Ensure the constant `1` and the 'is_root' flag are at their positions in the layout.
+        let public_inputs = assignment.public_inputs();
+        let is_root_index = layout.inputs().iter().position(|input| *input == PublicInput::IsRoot).unwrap();
+        assert_eq!(layout.inputs()[0], PublicInput::One);
+        assert_eq!(public_inputs[0].1, *Field::<CurrentNetwork>::one());
+        match is_root {
+            true => assert_eq!(public_inputs[is_root_index].1, *Field::<CurrentNetwork>::one()),
+            false => assert_eq!(public_inputs[is_root_index].1, *Field::<CurrentNetwork>::zero()),
+        }
+    }
+
+    // Synthesize the verifying key of 'produce_magic_number'.
+    let stack = process.get_stack(program0.id()).unwrap();
+    let magic_number = Identifier::from_str("produce_magic_number").unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program0.id(), &magic_number, rng).unwrap();
+
+    // Ensure the verifying key is serialized with its layout.
+    let expected = stack.get_verifying_key_with_layout(&magic_number).unwrap();
+    assert_eq!(expected.layout(), &stack.get_public_input_layout(&magic_number).unwrap());
+    let bytes = expected.to_bytes_le().unwrap();
+    assert_eq!(expected, VerifyingKeyWithLayout::read_le(&bytes[..]).unwrap());
+    assert_eq!(expected, serde_json::from_str(&serde_json::to_string(&expected).unwrap()).unwrap());
+    assert_eq!(expected, bincode::deserialize(&bincode::serialize(&expected).unwrap()).unwrap());
+
+    // Ensure a layout is rejected with the verifying key of a different circuit.
+    let verifying_key = stack.get_verifying_key(&magic_number).unwrap();
+    let layout = process.get_stack(program1.id()).unwrap().get_public_input_layout(&function_name).unwrap();
+    assert!(layout.check_verifying_key(&verifying_key).is_err());
+    assert!(VerifyingKeyWithLayout::new(verifying_key.clone(), layout.clone()).is_err());
+    let mut bytes = vec![1u8];
+    verifying_key.write_le(&mut bytes).unwrap();
+    layout.write_le(&mut bytes).unwrap();
+    assert!(VerifyingKeyWithLayout::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_add_set() {
     // Initialize a new program.
@@ -2362,8 +2658,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        policy: Default::default(),
+        stacks: IndexMap::new(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2404,6 +2703,51 @@ function compute:
     assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
 }
 
+#[test]
+fn test_process_deploy_stdlib_namespace() {
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Fetch the `std_token.aleo` program.
+    let program_id = ProgramID::from_str("std_token.aleo").unwrap();
+    let program = Program::stdlib_program(&program_id).unwrap();
+    // Ensure the deployment of the standard library program is valid.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_ok());
+
+    // Create a modified `std_token.aleo` program.
+    let program = Program::from_str(
+        r"
+program std_token.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+    // Ensure the deployment of the modified program is not valid.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+
+    // Create a program in the namespace of the standard library.
+    let program = Program::from_str(
+        r"
+program std_custom.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+    // Ensure the deployment of the program is not valid.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+}
+
 #[test]
 fn test_process_zero_input_zero_output_executions() {
     // Initialize the RNG.
@@ -2483,3 +2827,90 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_synthesize_keys_in_parallel() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Create a new program with several functions.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.public;
+
+function squared:
+    input r0 as u64.public;
+    mul r0 r0 into r1;
+    output r1 as u64.private;
+
+function hash:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    output r1 as field.public;",
+    )
+    .unwrap();
+
+    // Synthesize the keys sequentially.
+    let mut sequential = Process::load().unwrap();
+    sequential.add_program(&program).unwrap();
+    for function_name in program.functions().keys() {
+        sequential.synthesize_key::<CurrentAleo, _>(program.id(), function_name, rng).unwrap();
+    }
+
+    // Synthesize the keys in parallel.
+    let mut parallel = Process::load().unwrap();
+    parallel.add_program(&program).unwrap();
+    parallel.synthesize_keys::<CurrentAleo, _>(program.id(), rng).unwrap();
+
+    // Ensure the keys match.
+    for function_name in program.functions().keys() {
+        let expected = sequential.get_verifying_key(program.id(), function_name).unwrap();
+        let candidate = parallel.get_verifying_key(program.id(), function_name).unwrap();
+        assert_eq!(expected, candidate);
+        let expected = sequential.get_proving_key(program.id(), function_name).unwrap();
+        let candidate = parallel.get_proving_key(program.id(), function_name).unwrap();
+        assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+    }
+}
+
+#[test]
+fn test_process_policy() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program policy.aleo;
+
+function roll:
+    input r0 as field.public;
+    async roll r0 into r1;
+    output r1 as policy.aleo/roll.future;
+
+finalize roll:
+    input r0 as field.public;
+    rand.chacha r0 into r1 as field;",
+    )
+    .unwrap();
+
+    // Ensure the program is rejected by a policy that disallows randomness.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    process.set_policy(ProgramPolicy::new().disallow_family("rand"));
+    let error = process.add_program(&program).unwrap_err().to_string();
+    assert!(error.contains("'rand.chacha' in finalize 'policy.aleo/roll'"), "{error}");
+    assert!(!process.contains_program(program.id()));
+    assert!(process.deploy::<CurrentAleo, _>(&program, &mut TestRng::default()).is_err());
+
+    // Ensure the policy does not apply to the verification of a deployment, which must agree across the network.
+    let rng = &mut TestRng::default();
+    let deployment = Process::<CurrentNetwork>::load().unwrap().deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+
+    // Ensure the program is accepted by a policy that does not disallow randomness.
+    process.set_policy(ProgramPolicy::new().disallow_external_calls());
+    process.add_program(&program).unwrap();
+    assert!(process.contains_program(program.id()));
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for EncryptedWitness<N> {
+    /// Reads the encrypted witness from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid encrypted witness version"));
+        }
+
+        // Read the nonce.
+        let nonce = Group::read_le(&mut reader)?;
+        // Read the commitment.
+        let commitment = Field::read_le(&mut reader)?;
+        // Read the number of bytes.
+        let num_bytes = u32::read_le(&mut reader)?;
+        // Read the ciphertext, whose length is determined by the number of bytes.
+        let ciphertext =
+            (0..Self::num_fields(num_bytes)).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the tag.
+        let tag = Field::read_le(&mut reader)?;
+
+        Ok(Self { nonce, commitment, num_bytes, ciphertext, tag })
+    }
+}
+
+impl<N: Network> ToBytes for EncryptedWitness<N> {
+    /// Writes the encrypted witness to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of field elements matches the number of bytes.
+        if self.ciphertext.len() != Self::num_fields(self.num_bytes) {
+            return Err(error("Invalid number of field elements in the encrypted witness"));
+        }
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the commitment.
+        self.commitment.write_le(&mut writer)?;
+        // Write the number of bytes.
+        self.num_bytes.write_le(&mut writer)?;
+        // Write the ciphertext.
+        for field in &self.ciphertext {
+            field.write_le(&mut writer)?;
+        }
+        // Write the tag.
+        self.tag.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for DelegatedWitness<N> {
+    /// Reads the delegated witness from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid delegated witness version"));
+        }
+
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the transition witnesses.
+        let num_transitions = u32::read_le(&mut reader)?;
+        let transitions =
+            (0..num_transitions).map(|_| WitnessExport::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the inclusion assignments.
+        let num_inclusions = u32::read_le(&mut reader)?;
+        let inclusions = (0..num_inclusions)
+            .map(|_| WitnessExport::<N>::read_assignment(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+
+        Ok(Self { global_state_root, transitions, inclusions })
+    }
+}
+
+impl<N: Network> ToBytes for DelegatedWitness<N> {
+    /// Writes the delegated witness to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the global state root.
+        self.global_state_root.write_le(&mut writer)?;
+        // Write the transition witnesses.
+        u32::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for transition in &self.transitions {
+            transition.write_le(&mut writer)?;
+        }
+        // Write the inclusion assignments.
+        u32::try_from(self.inclusions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for inclusion in &self.inclusions {
+            WitnessExport::<N>::write_assignment(inclusion, &mut writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+use crate::Process;
+use console::{
+    account::{Address, ViewKey},
+    types::{Field, Group, Scalar},
+};
+use utilities::bytes_from_bits_le;
+
+use indexmap::IndexMap;
+
+/// The witness of a trace, committed to by the client and encrypted to a prover, for delegated proving.
+///
+/// A client that cannot afford to prove runs synthesis itself, with `Process::execute` and `Trace::prepare`,
+/// and sends the resulting witness to a prover with `Trace::delegate`. The prover holds the proving keys,
+/// and runs the MSM-heavy proving step with `Process::prove_delegated`, without synthesizing any circuit.
+/// The client completes the execution or fee with the returned proof, and checks it with `Process::verify_execution`
+/// or `Process::verify_fee`.
+///
+/// The witness is encrypted to the prover under a key derived from the prover address and a fresh nonce, as for
+/// records. The commitment is a blinded hash of the witness, which the prover checks after decryption, so it
+/// proves exactly the witness the client committed to. A tag over the ciphertext ensures the witness is not
+/// modified in transit.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptedWitness<N: Network> {
+    /// The nonce, from which the prover derives the encryption key with its view key.
+    nonce: Group<N>,
+    /// The commitment to the witness.
+    commitment: Field<N>,
+    /// The number of bytes in the witness.
+    num_bytes: u32,
+    /// The encrypted blinding factor of the commitment, followed by the witness bytes packed into field elements.
+    ciphertext: Vec<Field<N>>,
+    /// The tag over the ciphertext.
+    tag: Field<N>,
+}
+
+/// The witness of a trace, as proved by the prover.
+struct DelegatedWitness<N: Network> {
+    /// The global state root of the inclusion assignments.
+    global_state_root: N::StateRoot,
+    /// The witness of each transition circuit.
+    transitions: Vec<WitnessExport<N>>,
+    /// The assignments of the inclusion circuit.
+    inclusions: Vec<Assignment<N::Field>>,
+}
+
+impl<N: Network> Trace<N> {
+    /// Returns the witness of the trace, committed to and encrypted to the given prover address.
+    /// The trace must be prepared with `Trace::prepare` first.
+    pub fn delegate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        prover: &Address<N>,
+        rng: &mut R,
+    ) -> Result<EncryptedWitness<N>> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Synthesize the inclusion circuits.
+        let inclusions = inclusion_assignments
+            .iter()
+            .map(|assignment| assignment.to_circuit_assignment::<A>())
+            .collect::<Result<Vec<_>>>()?;
+
+        // Construct the witness.
+        let transitions = self.to_witness_exports()?;
+        let witness = DelegatedWitness { global_state_root: *global_state_root, transitions, inclusions };
+        // Commit to the witness, and encrypt it to the prover.
+        EncryptedWitness::encrypt(&witness, prover, rng)
+    }
+
+    /// Returns a new execution with the proof computed by the prover, for the delegated witness of the trace.
+    pub fn complete_delegated_execution(&self, proof: Proof<N>) -> Result<Execution<N>> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot complete an execution for a fee type");
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Return the execution.
+        Execution::from(self.transitions.iter().cloned(), *global_state_root, Some(proof))
+    }
+
+    /// Returns a new fee with the proof computed by the prover, for the delegated witness of the trace.
+    pub fn complete_delegated_fee(&self, proof: Proof<N>) -> Result<Fee<N>> {
+        // Ensure this is a fee.
+        ensure!(self.is_fee(), "The trace cannot complete a fee for an execution type");
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Return the fee.
+        Ok(Fee::from_unchecked(self.transitions[0].clone(), *global_state_root, Some(proof)))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the proof for the given delegated witness, which is decrypted with the view key of the prover.
+    /// The proof is computed with the proving keys of the process, so the programs of the witness must be added first.
+    pub fn prove_delegated<R: Rng + CryptoRng>(
+        &self,
+        witness: &EncryptedWitness<N>,
+        view_key: &ViewKey<N>,
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        // Decrypt the witness.
+        let witness = witness.decrypt(view_key)?;
+        // Retrieve the locator of the last transition, which is the root call of the trace.
+        let locator = match witness.transitions.last() {
+            Some(transition) => transition.locator().to_string(),
+            None => bail!("The delegated witness does not contain any transitions"),
+        };
+
+        // Construct the proving tasks, grouping the assignments by function.
+        let mut proving_tasks = IndexMap::<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)>::new();
+        for transition in witness.transitions {
+            let transition_locator = *transition.locator();
+            if !proving_tasks.contains_key(&transition_locator) {
+                let proving_key =
+                    self.get_proving_key(*transition_locator.program_id(), *transition_locator.resource())?;
+                proving_tasks.insert(transition_locator, (proving_key, vec![]));
+            }
+            if let Some((_, assignments)) = proving_tasks.get_mut(&transition_locator) {
+                assignments.push(transition.assignment().clone());
+            }
+        }
+        let mut proving_tasks: Vec<_> = proving_tasks.into_values().collect();
+
+        if !witness.inclusions.is_empty() {
+            // Ensure the global state root is not zero.
+            if witness.global_state_root == N::StateRoot::default() {
+                bail!("Inclusion expected the global state root in the delegated witness to *not* be zero")
+            }
+            // Insert the inclusion proving key and assignments.
+            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+            proving_tasks.push((proving_key, witness.inclusions));
+        }
+
+        // Compute the proof.
+        ProvingKey::prove_batch(&locator, &proving_tasks, rng)
+    }
+}
+
+impl<N: Network> EncryptedWitness<N> {
+    /// The number of witness bytes packed into each field element.
+    const BYTES_PER_FIELD: usize = Field::<N>::SIZE_IN_DATA_BITS / 8;
+
+    /// Returns the nonce.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the commitment to the witness.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Commits to the given witness, and encrypts it to the given prover address.
+    fn encrypt<R: Rng + CryptoRng>(witness: &DelegatedWitness<N>, prover: &Address<N>, rng: &mut R) -> Result<Self> {
+        // Sample a randomizer, and compute the nonce and the encryption key.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        let encryption_key = (**prover * randomizer).to_x_coordinate();
+
+        // Pack the witness bytes into field elements, after the blinding factor of the commitment.
+        let bytes = witness.to_bytes_le()?;
+        let num_bytes = u32::try_from(bytes.len())?;
+        let mut plaintext = Vec::with_capacity(1 + bytes.len() / Self::BYTES_PER_FIELD + 1);
+        plaintext.push(Field::rand(rng));
+        for chunk in bytes.chunks(Self::BYTES_PER_FIELD) {
+            plaintext.push(Field::from_bits_le(&chunk.to_bits_le())?);
+        }
+        // Commit to the witness.
+        let commitment = Self::compute_commitment(num_bytes, &plaintext)?;
+
+        // Encrypt the field elements, and compute the tag.
+        let randomizers = Self::randomizers(encryption_key, plaintext.len());
+        let ciphertext: Vec<_> =
+            plaintext.into_iter().zip_eq(randomizers).map(|(field, randomizer)| field + randomizer).collect();
+        let tag = Self::compute_tag(encryption_key, commitment, num_bytes, &ciphertext)?;
+
+        Ok(Self { nonce, commitment, num_bytes, ciphertext, tag })
+    }
+
+    /// Decrypts the witness with the view key of the prover.
+    ///
+    /// # Errors
+    /// This method will halt if the witness was not encrypted to the given view key, if it was modified,
+    /// or if it does not match the commitment.
+    fn decrypt(&self, view_key: &ViewKey<N>) -> Result<DelegatedWitness<N>> {
+        // Compute the encryption key.
+        let encryption_key = (self.nonce * **view_key).to_x_coordinate();
+        // Ensure the tag is valid.
+        ensure!(
+            Self::compute_tag(encryption_key, self.commitment, self.num_bytes, &self.ciphertext)? == self.tag,
+            "The encrypted witness is invalid, or is not for this view key"
+        );
+
+        // Decrypt the field elements.
+        let randomizers = Self::randomizers(encryption_key, self.ciphertext.len());
+        let plaintext: Vec<_> =
+            self.ciphertext.iter().zip_eq(randomizers).map(|(field, randomizer)| *field - randomizer).collect();
+        // Ensure the witness matches the commitment.
+        ensure!(
+            Self::compute_commitment(self.num_bytes, &plaintext)? == self.commitment,
+            "The encrypted witness does not match its commitment"
+        );
+
+        // Unpack the witness bytes, skipping the blinding factor.
+        let mut bytes = Vec::with_capacity(plaintext.len().saturating_sub(1) * Self::BYTES_PER_FIELD);
+        for field in plaintext.iter().skip(1) {
+            bytes.extend(bytes_from_bits_le(&field.to_bits_le()[..Self::BYTES_PER_FIELD * 8]));
+        }
+        // Ensure the number of bytes is valid.
+        let num_bytes = usize::try_from(self.num_bytes)?;
+        ensure!(num_bytes <= bytes.len(), "The encrypted witness has an invalid length");
+        bytes.truncate(num_bytes);
+
+        Ok(DelegatedWitness::read_le(&bytes[..])?)
+    }
+
+    /// Returns the number of field elements in the ciphertext, for the given number of witness bytes.
+    fn num_fields(num_bytes: u32) -> usize {
+        1 + (num_bytes as usize + Self::BYTES_PER_FIELD - 1) / Self::BYTES_PER_FIELD
+    }
+
+    /// Returns the randomizers for the given number of field elements.
+    fn randomizers(encryption_key: Field<N>, num_fields: usize) -> Vec<Field<N>> {
+        // Derive the randomizers in blocks, as each hash returns at most `u16::MAX` field elements.
+        let mut randomizers = Vec::with_capacity(num_fields);
+        for block in 0u64.. {
+            let remaining = num_fields - randomizers.len();
+            if remaining == 0 {
+                break;
+            }
+            let num_outputs = u16::try_from(remaining).unwrap_or(u16::MAX);
+            let preimage = [N::encryption_domain(), encryption_key, Field::from_u64(block)];
+            randomizers.extend(N::hash_many_psd8(&preimage, num_outputs));
+        }
+        randomizers
+    }
+
+    /// Returns the commitment to the given plaintext, which starts with the blinding factor.
+    fn compute_commitment(num_bytes: u32, plaintext: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(plaintext.len() + 1);
+        preimage.push(Field::from_u32(num_bytes));
+        preimage.extend_from_slice(plaintext);
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the tag over the given ciphertext.
+    fn compute_tag(
+        encryption_key: Field<N>,
+        commitment: Field<N>,
+        num_bytes: u32,
+        ciphertext: &[Field<N>],
+    ) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(ciphertext.len() + 3);
+        preimage.push(encryption_key);
+        preimage.push(commitment);
+        preimage.push(Field::from_u32(num_bytes));
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3, program::Identifier};
+    use ledger_query::Query;
+    use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_delegated_proving() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program delegated.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )?;
+
+        // Construct the process of the client, and the process of the prover.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        process.add_program(&program)?;
+        let prover = process.clone();
+
+        // Sample the accounts of the client and the prover.
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let prover_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let prover_view_key = ViewKey::try_from(&prover_private_key)?;
+        let prover_address = Address::try_from(&prover_private_key)?;
+
+        // Synthesize the witness on the client.
+        let authorization = process.authorize::<CurrentAleo, _>(
+            &private_key,
+            program.id(),
+            Identifier::from_str("compute")?,
+            ["5u32", "10u32"].into_iter(),
+            rng,
+        )?;
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng)?;
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None)?;
+        trace.prepare(Query::from(block_store))?;
+
+        // Delegate the witness to the prover, and ensure it round-trips through bytes.
+        let witness = trace.delegate::<CurrentAleo, _>(&prover_address, rng)?;
+        let witness = EncryptedWitness::read_le(&witness.to_bytes_le()?[..])?;
+
+        // Ensure another account cannot prove the witness.
+        let other = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(prover.prove_delegated(&witness, &other, rng).is_err());
+        // Ensure a modified witness is rejected.
+        let mut modified = witness.clone();
+        modified.ciphertext[1] += Field::one();
+        assert!(prover.prove_delegated(&modified, &prover_view_key, rng).is_err());
+        // Ensure a witness with a different commitment is rejected.
+        let mut modified = witness.clone();
+        modified.commitment += Field::one();
+        assert!(prover.prove_delegated(&modified, &prover_view_key, rng).is_err());
+
+        // Prove the witness on the prover, and complete the execution on the client.
+        let proof = prover.prove_delegated(&witness, &prover_view_key, rng)?;
+        let execution = trace.complete_delegated_execution(proof)?;
+        // Ensure the execution is valid.
+        process.verify_execution(&execution)?;
+        Ok(())
+    }
+}
@@ -15,9 +15,15 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod delegated;
+pub use delegated::*;
+
 mod inclusion;
 pub use inclusion::*;
 
+mod witness;
+pub use witness::*;
+
 use circuit::Assignment;
 use console::{
     network::prelude::*,
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for WitnessExport<N> {
+    /// Reads the witness from a buffer, in the format written by `WitnessExport::write_le`.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid witness version"));
+        }
+        // Read the locator.
+        let locator = Locator::read_le(&mut reader)?;
+        // Read the assignment.
+        let assignment = Self::read_assignment(&mut reader)?;
+        // Return the witness.
+        Ok(Self::new(locator, assignment))
+    }
+}
+
+impl<N: Network> ToBytes for WitnessExport<N> {
+    /// Writes the witness to a buffer, with integers and field elements in little-endian order, as follows:
+    /// - the version, as a `u8`;
+    /// - the locator of the function;
+    /// - the assignment of the circuit, as written by `WitnessExport::write_assignment`.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the locator.
+        self.locator.write_le(&mut writer)?;
+        // Write the assignment.
+        Self::write_assignment(&self.assignment, &mut writer)
+    }
+}
+
+impl<N: Network> WitnessExport<N> {
+    /// Reads a circuit assignment from a buffer, in the format written by `WitnessExport::write_assignment`.
+    pub(crate) fn read_assignment<R: Read>(mut reader: R) -> IoResult<Assignment<N::Field>> {
+        // Read the public and private variables, which are indexed in order.
+        let public = Self::read_variables(&mut reader)?;
+        let private = Self::read_variables(&mut reader)?;
+        // Read the constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        let constraints = (0..num_constraints)
+            .map(|_| Ok((Self::read_lc(&mut reader)?, Self::read_lc(&mut reader)?, Self::read_lc(&mut reader)?)))
+            .collect::<IoResult<Arc<[_]>>>()?;
+        // Return the assignment.
+        Ok(Assignment::new(public, private, constraints))
+    }
+
+    /// Reads the number of variables, followed by their values in index order.
+    fn read_variables<R: Read>(reader: &mut R) -> IoResult<Arc<[(u64, N::Field)]>> {
+        let num_variables = u64::read_le(&mut *reader)?;
+        (0..num_variables).map(|index| Ok((index, N::Field::read_le(&mut *reader)?))).collect()
+    }
+
+    /// Reads a linear combination, as its constant, the number of terms, and each term.
+    fn read_lc<R: Read>(reader: &mut R) -> IoResult<AssignmentLC<N::Field>> {
+        // Read the constant.
+        let constant = N::Field::read_le(&mut *reader)?;
+        // Read the terms.
+        let num_terms = u64::read_le(&mut *reader)?;
+        let terms = (0..num_terms)
+            .map(|_| {
+                let variable = match u8::read_le(&mut *reader)? {
+                    0 => AssignmentVariable::Public(u64::read_le(&mut *reader)?),
+                    1 => AssignmentVariable::Private(u64::read_le(&mut *reader)?),
+                    _ => return Err(error("Invalid variable kind in a witness term")),
+                };
+                Ok((variable, N::Field::read_le(&mut *reader)?))
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(AssignmentLC::new(constant, terms))
+    }
+
+    /// Writes a circuit assignment to a buffer, with integers and field elements in little-endian order, as follows:
+    /// - the number of public variables, as a `u64`, followed by their values in index order;
+    /// - the number of private variables, as a `u64`, followed by their values in index order;
+    /// - the number of constraints, as a `u64`, followed by the linear combinations `a`, `b`, and `c` of each.
+    ///
+    /// A linear combination is written as its constant, the number of terms as a `u64`, and each term as
+    /// a `u8` that is `0` for a public variable and `1` for a private variable, the `u64` index, and the coefficient.
+    pub(crate) fn write_assignment<W: Write>(assignment: &Assignment<N::Field>, mut writer: W) -> IoResult<()> {
+        // Write the public and private variables.
+        for variables in [assignment.public_inputs(), assignment.private_inputs()] {
+            (variables.len() as u64).write_le(&mut writer)?;
+            for (expected, (index, value)) in variables.iter().enumerate() {
+                // Ensure the variables are indexed in order, as the indices are not written.
+                if *index != expected as u64 {
+                    return Err(error("The witness variables are not indexed in order"));
+                }
+                value.write_le(&mut writer)?;
+            }
+        }
+        // Write the constraints.
+        assignment.num_constraints().write_le(&mut writer)?;
+        for (a, b, c) in assignment.constraints().iter() {
+            for lc in [a, b, c] {
+                // Write the constant.
+                lc.constant().write_le(&mut writer)?;
+                // Write the terms.
+                (lc.terms().len() as u64).write_le(&mut writer)?;
+                for (variable, coefficient) in lc.terms() {
+                    let (kind, index) = match variable {
+                        AssignmentVariable::Public(index) => (0u8, index),
+                        AssignmentVariable::Private(index) => (1u8, index),
+                        AssignmentVariable::Constant(..) => return Err(error("A constant term cannot be exported")),
+                    };
+                    kind.write_le(&mut writer)?;
+                    index.write_le(&mut writer)?;
+                    coefficient.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
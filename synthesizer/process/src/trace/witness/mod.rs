@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+use circuit::environment::{AssignmentLC, AssignmentVariable};
+use console::types::Field;
+
+use std::sync::Arc;
+
+/// The witness of a transition circuit, exported for use with an external proving backend.
+///
+/// The witness is the full assignment of the circuit, which consists of the values of the public and private
+/// variables, and the constraints over them. Each constraint is of the form `a * b = c`, where `a`, `b`, and `c`
+/// are linear combinations of the variables. The public variables are the verifier inputs of the transition,
+/// starting with the constant `1`, and the variables of each kind are indexed from `0` in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WitnessExport<N: Network> {
+    /// The locator of the function.
+    locator: Locator<N>,
+    /// The assignment of the circuit.
+    assignment: Assignment<N::Field>,
+}
+
+impl<N: Network> WitnessExport<N> {
+    /// Initializes a new witness export for the given function and circuit assignment.
+    pub const fn new(locator: Locator<N>, assignment: Assignment<N::Field>) -> Self {
+        Self { locator, assignment }
+    }
+
+    /// Returns the locator of the function.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the assignment of the circuit.
+    pub const fn assignment(&self) -> &Assignment<N::Field> {
+        &self.assignment
+    }
+
+    /// Returns the values of the public variables, in order.
+    pub fn public_inputs(&self) -> Vec<N::Field> {
+        self.assignment.public_inputs().iter().map(|(_, value)| *value).collect()
+    }
+
+    /// Returns the values of the private variables, in order.
+    pub fn private_inputs(&self) -> Vec<N::Field> {
+        self.assignment.private_inputs().iter().map(|(_, value)| *value).collect()
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> u64 {
+        self.assignment.num_constraints()
+    }
+
+    /// Returns `true` if the variables satisfy every constraint.
+    pub fn is_satisfied(&self) -> bool {
+        // Returns the value of the given variable, if it exists.
+        let value_of = |variable: &AssignmentVariable<N::Field>| match variable {
+            AssignmentVariable::Constant(value) => Some(*value),
+            AssignmentVariable::Public(index) => Self::lookup(self.assignment.public_inputs(), *index),
+            AssignmentVariable::Private(index) => Self::lookup(self.assignment.private_inputs(), *index),
+        };
+        // Returns the value of the given linear combination, if all of its variables exist.
+        let evaluate = |lc: &AssignmentLC<N::Field>| {
+            lc.terms().iter().try_fold(lc.constant(), |sum, (variable, coefficient)| {
+                Some(sum + value_of(variable)? * *coefficient)
+            })
+        };
+
+        self.assignment.constraints().iter().all(|(a, b, c)| match (evaluate(a), evaluate(b), evaluate(c)) {
+            (Some(a), Some(b), Some(c)) => a * b == c,
+            _ => false,
+        })
+    }
+
+    /// Returns the value of the variable with the given index.
+    fn lookup(variables: &[(u64, N::Field)], index: u64) -> Option<N::Field> {
+        let (candidate, value) = variables.get(usize::try_from(index).ok()?)?;
+        (*candidate == index).then_some(*value)
+    }
+}
+
+impl<N: Network> Trace<N> {
+    /// Returns the witness of each transition circuit, in the order of the transitions.
+    /// The witness of the inclusion circuit is not included, as it is derived from the ledger state.
+    pub fn to_witness_exports(&self) -> Result<Vec<WitnessExport<N>>> {
+        // Track the number of assignments used for each locator.
+        let mut counters: HashMap<Locator<N>, usize> = HashMap::new();
+
+        self.transitions
+            .iter()
+            .map(|transition| {
+                let locator = Locator::new(*transition.program_id(), *transition.function_name());
+                // Retrieve the next assignment for the locator.
+                let counter = counters.entry(locator).or_default();
+                let assignment = self
+                    .transition_tasks
+                    .get(&locator)
+                    .and_then(|(_, assignments)| assignments.get(*counter))
+                    .ok_or_else(|| anyhow!("Missing the assignment for a transition of '{locator}'"))?;
+                *counter += 1;
+                Ok(WitnessExport::new(locator, assignment.clone()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::{account::PrivateKey, network::Testnet3, program::Identifier};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_witness_export() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program witness.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )?;
+        let function_name = Identifier::from_str("compute")?;
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        process.add_program(&program)?;
+
+        // Execute the function.
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = process.authorize::<CurrentAleo, _>(
+            &private_key,
+            program.id(),
+            function_name,
+            ["5u32", "10u32"].into_iter(),
+            rng,
+        )?;
+        let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng)?;
+
+        // Export the witnesses.
+        let witnesses = trace.to_witness_exports()?;
+        assert_eq!(witnesses.len(), 1);
+        let witness = &witnesses[0];
+        assert_eq!(witness.locator(), &Locator::new(*program.id(), function_name));
+        assert!(witness.is_satisfied());
+
+        // Ensure the public inputs start with the constant `1`.
+        let public_inputs = witness.public_inputs();
+        assert_eq!(public_inputs[0], <CurrentNetwork as Environment>::Field::one());
+        assert!(!witness.private_inputs().is_empty());
+
+        // Ensure the assignment encoding round-trips.
+        let mut assignment_bytes = Vec::new();
+        WitnessExport::<CurrentNetwork>::write_assignment(witness.assignment(), &mut assignment_bytes)?;
+        let assignment = WitnessExport::<CurrentNetwork>::read_assignment(&assignment_bytes[..])?;
+        assert_eq!(witness.assignment(), &assignment);
+        assert_eq!(witness, &WitnessExport::new(*witness.locator(), assignment));
+
+        // Ensure the byte encoding round-trips.
+        let bytes = witness.to_bytes_le()?;
+        assert_eq!(witness, &WitnessExport::read_le(&bytes[..])?);
+        assert!(WitnessExport::<CurrentNetwork>::read_le(&bytes[..bytes.len() - 1]).is_err());
+
+        // Ensure the JSON encoding contains every variable and constraint.
+        let json: serde_json::Value = serde_json::to_value(witness)?;
+        assert_eq!(json["locator"], serde_json::Value::String("witness.aleo/compute".to_string()));
+        assert_eq!(json["public"].as_array().unwrap().len(), public_inputs.len());
+        assert_eq!(json["private"].as_array().unwrap().len(), witness.private_inputs().len());
+        assert_eq!(json["constraints"].as_array().unwrap().len() as u64, witness.num_constraints());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A term of a linear combination, serialized as the kind and index of its variable, and its coefficient.
+struct SerializeTerm<'a, N: Network>(&'a (AssignmentVariable<N::Field>, N::Field));
+
+impl<'a, N: Network> Serialize for SerializeTerm<'a, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (variable, coefficient) = self.0;
+        let (kind, index) = match variable {
+            AssignmentVariable::Public(index) => ("public", index),
+            AssignmentVariable::Private(index) => ("private", index),
+            AssignmentVariable::Constant(..) => return Err(ser::Error::custom("A constant term cannot be exported")),
+        };
+
+        let mut term = serializer.serialize_struct("Term", 3)?;
+        term.serialize_field("kind", kind)?;
+        term.serialize_field("index", index)?;
+        term.serialize_field("coefficient", &Field::<N>::new(*coefficient))?;
+        term.end()
+    }
+}
+
+/// A linear combination, serialized as its constant and its terms.
+struct SerializeLC<'a, N: Network>(&'a AssignmentLC<N::Field>);
+
+impl<'a, N: Network> Serialize for SerializeLC<'a, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let terms = self.0.terms().iter().map(SerializeTerm::<N>).collect::<Vec<_>>();
+
+        let mut lc = serializer.serialize_struct("LinearCombination", 2)?;
+        lc.serialize_field("constant", &Field::<N>::new(self.0.constant()))?;
+        lc.serialize_field("terms", &terms)?;
+        lc.end()
+    }
+}
+
+/// A constraint `a * b = c`, serialized as its linear combinations.
+struct SerializeConstraint<'a, N: Network>(
+    &'a (AssignmentLC<N::Field>, AssignmentLC<N::Field>, AssignmentLC<N::Field>),
+);
+
+impl<'a, N: Network> Serialize for SerializeConstraint<'a, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (a, b, c) = self.0;
+
+        let mut constraint = serializer.serialize_struct("Constraint", 3)?;
+        constraint.serialize_field("a", &SerializeLC::<N>(a))?;
+        constraint.serialize_field("b", &SerializeLC::<N>(b))?;
+        constraint.serialize_field("c", &SerializeLC::<N>(c))?;
+        constraint.end()
+    }
+}
+
+impl<N: Network> Serialize for WitnessExport<N> {
+    /// Serializes the witness into the function locator, the values of the public and private variables,
+    /// and the constraints, where each constraint is an object with the linear combinations `a`, `b`, and `c`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let values = |variables: &[(u64, N::Field)]| -> Vec<Field<N>> {
+            variables.iter().map(|(_, value)| Field::new(*value)).collect()
+        };
+        let constraints = self.assignment.constraints().iter().map(SerializeConstraint::<N>).collect::<Vec<_>>();
+
+        let mut witness = serializer.serialize_struct("WitnessExport", 4)?;
+        witness.serialize_field("locator", &self.locator)?;
+        witness.serialize_field("public", &values(self.assignment.public_inputs()))?;
+        witness.serialize_field("private", &values(self.assignment.private_inputs()))?;
+        witness.serialize_field("constraints", &constraints)?;
+        witness.end()
+    }
+}
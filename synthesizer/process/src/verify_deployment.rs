@@ -28,6 +28,13 @@ impl<N: Network> Process<N> {
         let program_id = deployment.program().id();
         // Ensure the program does not already exist in the process.
         ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure a program in the namespace of the standard library is the standard library program.
+        if Program::is_stdlib_namespace(program_id) {
+            ensure!(
+                matches!(Program::stdlib_program(program_id), Ok(program) if &program == deployment.program()),
+                "Program '{program_id}' is reserved for the standard library"
+            );
+        }
 
         // Ensure the program is well-formed, by computing the stack.
         let stack = Stack::new(self, deployment.program())?;
@@ -55,6 +55,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the restriction.
+                5 => program.add_restriction(Restriction::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the metadata.
+                6 => {
+                    // Ensure the metadata is declared at most once.
+                    if program.metadata.is_some() {
+                        return Err(error("Failed to parse program. Duplicate metadata"));
+                    }
+                    program.set_metadata(ProgramMetadata::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?
+                }
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -82,7 +92,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
         }
 
         // Write the number of components.
-        u16::try_from(self.identifiers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        let num_restrictions = self.restrictions.values().map(Vec::len).sum::<usize>();
+        let num_metadata = usize::from(self.metadata.is_some());
+        u16::try_from(self.identifiers.len() + num_restrictions + num_metadata)
+            .map_err(|e| error(e.to_string()))?
+            .write_le(&mut writer)?;
         // Write the components.
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
@@ -128,11 +142,27 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                         4u8.write_le(&mut writer)?;
                         // Write the function.
                         function.write_le(&mut writer)?;
+                        // Write the restrictions of the function, if any.
+                        if let Some(callers) = self.restrictions.get(identifier) {
+                            for caller in callers {
+                                // Write the variant.
+                                5u8.write_le(&mut writer)?;
+                                // Write the restriction.
+                                Restriction::new(*identifier, *caller).write_le(&mut writer)?;
+                            }
+                        }
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
             }
         }
+        // Write the metadata, if any.
+        if let Some(metadata) = &self.metadata {
+            // Write the variant.
+            6u8.write_le(&mut writer)?;
+            // Write the metadata.
+            metadata.write_le(&mut writer)?;
+        }
 
         Ok(())
     }
@@ -39,6 +39,17 @@ pub use logic::*;
 mod mapping;
 pub use mapping::*;
 
+mod metadata;
+pub use metadata::*;
+
+mod policy;
+pub use policy::*;
+
+mod restriction;
+pub use restriction::*;
+
+mod stdlib;
+
 pub mod traits;
 pub use traits::*;
 
@@ -59,6 +70,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -81,10 +93,12 @@ use console::{
         Serializer,
         ToBytes,
         ToBytesSerializer,
+        ToBits,
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Address, Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -121,6 +135,10 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of the restricted functions to their allowed callers.
+    restrictions: IndexMap<Identifier<N>, Vec<Address<N>>>,
+    /// The metadata of the program, if declared.
+    metadata: Option<ProgramMetadata<N>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -139,6 +157,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            restrictions: IndexMap::new(),
+            metadata: None,
         })
     }
 
@@ -183,6 +203,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the restricted functions in the program, and their allowed callers.
+    pub const fn restrictions(&self) -> &IndexMap<Identifier<N>, Vec<Address<N>>> {
+        &self.restrictions
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -213,6 +238,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns the allowed callers of the function with the given name.
+    /// If the function is not restricted, this method returns `None`, and any caller is allowed.
+    pub fn get_restriction(&self, name: &Identifier<N>) -> Option<&[Address<N>]> {
+        self.restrictions.get(name).map(|callers| callers.as_slice())
+    }
+
+    /// Returns the metadata of the program, if declared.
+    pub const fn metadata(&self) -> Option<&ProgramMetadata<N>> {
+        self.metadata.as_ref()
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -283,6 +319,51 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the hash of the program interface, which covers the program ID, mappings, structs, records,
+    /// and the input and output types of each function. Closures and instructions are not included.
+    pub fn to_abi_hash(&self) -> Result<Field<N>> {
+        // Initialize the preimage with the program ID.
+        let mut preimage = self.id.to_bytes_le()?;
+        // Append the interface of each component, in program order.
+        for (identifier, definition) in self.identifiers.iter() {
+            match definition {
+                ProgramDefinition::Mapping => self.get_mapping(identifier)?.write_le(&mut preimage)?,
+                ProgramDefinition::Struct => self.get_struct(identifier)?.write_le(&mut preimage)?,
+                ProgramDefinition::Record => self.get_record(identifier)?.write_le(&mut preimage)?,
+                ProgramDefinition::Closure => continue,
+                ProgramDefinition::Function => {
+                    let function = self.get_function_ref(identifier)?;
+                    let input_types = function.input_types();
+                    let output_types = function.output_types();
+                    let finalize_types = function.finalize_logic().map(|finalize| finalize.input_types());
+                    let finalize_types = finalize_types.unwrap_or_default();
+                    // Write the function name, followed by each list of types and its length.
+                    function.name().write_le(&mut preimage)?;
+                    u16::try_from(input_types.len())?.write_le(&mut preimage)?;
+                    input_types.write_le(&mut preimage)?;
+                    u16::try_from(output_types.len())?.write_le(&mut preimage)?;
+                    output_types.write_le(&mut preimage)?;
+                    u16::try_from(finalize_types.len())?.write_le(&mut preimage)?;
+                    finalize_types.write_le(&mut preimage)?;
+                }
+            }
+        }
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+
+    /// Returns the hash of the executable content of the program, which excludes the metadata.
+    /// Updating the metadata of a program does not change its content hash.
+    pub fn to_content_hash(&self) -> Result<Field<N>> {
+        // Serialize the program without its metadata.
+        let bytes = match self.metadata {
+            Some(_) => Self { metadata: None, ..self.clone() }.to_bytes_le()?,
+            None => self.to_bytes_le()?,
+        };
+        // Hash the program bytes.
+        N::hash_bhp1024(&bytes.to_bits_le())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -549,6 +630,64 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds a new restriction to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the restricted function is not already defined.
+    /// This method will halt if the caller is already allowed for the function.
+    /// This method will halt if the function exceeds the maximum number of allowed callers.
+    #[inline]
+    fn add_restriction(&mut self, restriction: Restriction<N>) -> Result<()> {
+        // Retrieve the function name.
+        let function_name = *restriction.function_name();
+
+        // Ensure the function is already defined in the program.
+        ensure!(self.functions.contains_key(&function_name), "Restricted function '{function_name}' is not defined.");
+
+        // Retrieve the allowed callers of the function.
+        let callers = self.restrictions.entry(function_name).or_default();
+        // Ensure the caller is new.
+        ensure!(
+            !callers.contains(restriction.caller()),
+            "Caller '{}' is already allowed for '{function_name}'.",
+            restriction.caller()
+        );
+        // Ensure the function has not exceeded the maximum number of allowed callers.
+        ensure!(
+            callers.len() < Restriction::<N>::MAX_CALLERS,
+            "Function '{function_name}' exceeds the maximum number of allowed callers"
+        );
+
+        // Add the caller to the allowed callers of the function.
+        callers.push(*restriction.caller());
+        Ok(())
+    }
+
+    /// Sets the metadata of the program, replacing any existing metadata.
+    /// If the given metadata is empty, the metadata of the program is removed.
+    ///
+    /// # Errors
+    /// This method will halt if the declared ABI hash does not match the interface of the program.
+    #[inline]
+    pub fn set_metadata(&mut self, metadata: ProgramMetadata<N>) -> Result<()> {
+        // Ensure the declared ABI hash matches the program interface.
+        if let Some(abi_hash) = metadata.abi_hash() {
+            ensure!(*abi_hash == self.to_abi_hash()?, "The declared ABI hash does not match program '{}'", self.id);
+        }
+        // Set the metadata.
+        self.metadata = match metadata.is_empty() {
+            true => None,
+            false => Some(metadata),
+        };
+        Ok(())
+    }
+
+    /// Removes the metadata of the program, returning the metadata if it was declared.
+    #[inline]
+    pub fn clear_metadata(&mut self) -> Option<ProgramMetadata<N>> {
+        self.metadata.take()
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -602,6 +741,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "value",
         "async",
         "finalize",
+        "restrict",
+        "metadata",
         // Reserved (catch all)
         "global",
         "block",
@@ -813,4 +954,132 @@ function swap:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_restriction() -> Result<()> {
+        let caller_0 = "aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw";
+        let caller_1 = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program restricted.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;
+
+restrict compute to {caller_0};
+restrict compute to {caller_1};
+
+function open:
+    input r0 as u64.private;
+    output r0 as u64.private;"
+        ))?;
+
+        // Ensure the restricted function has the allowed callers.
+        let callers = program.get_restriction(&Identifier::from_str("compute")?).unwrap();
+        assert_eq!(callers, [Address::from_str(caller_0)?, Address::from_str(caller_1)?]);
+        // Ensure the unrestricted function has no allowed callers.
+        assert!(program.get_restriction(&Identifier::from_str("open")?).is_none());
+
+        // Ensure the program round-trips through its string and byte representations.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure a restriction on an undefined function fails.
+        let result = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program restricted.aleo;
+
+restrict compute to {caller_0};
+
+function compute:
+    input r0 as u64.private;
+    output r0 as u64.private;"
+        ));
+        assert!(result.is_err());
+
+        // Ensure a duplicate restriction fails.
+        let result = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program restricted.aleo;
+
+function compute:
+    input r0 as u64.private;
+    output r0 as u64.private;
+
+restrict compute to {caller_0};
+restrict compute to {caller_0};"
+        ));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_metadata() -> Result<()> {
+        let source = r"
+program annotated.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a program without metadata.
+        let mut program = Program::<CurrentNetwork>::from_str(source)?;
+        assert!(program.metadata().is_none());
+        let abi_hash = program.to_abi_hash()?;
+        let content_hash = program.to_content_hash()?;
+
+        // Set the metadata, and ensure it is returned.
+        let metadata = ProgramMetadata::new(
+            Some("Aleo Systems".to_string()),
+            Some("1.0.0".to_string()),
+            Some("Apache-2.0".to_string()),
+            Some(abi_hash),
+        )?;
+        program.set_metadata(metadata.clone())?;
+        assert_eq!(program.metadata(), Some(&metadata));
+
+        // Ensure the metadata does not change the ABI hash or the content hash.
+        assert_eq!(abi_hash, program.to_abi_hash()?);
+        assert_eq!(content_hash, program.to_content_hash()?);
+
+        // Ensure the program round-trips through its string and byte representations.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure the metadata block is parsed from a program string.
+        let candidate = Program::<CurrentNetwork>::from_str(&source.replacen(
+            "program annotated.aleo;",
+            &format!("program annotated.aleo;\n\n{metadata}"),
+            1,
+        ))?;
+        assert_eq!(program, candidate);
+
+        // Ensure a mismatched ABI hash is rejected.
+        let mismatched = ProgramMetadata::new(None, None, None, Some(Field::from_u64(7)))?;
+        assert!(program.set_metadata(mismatched).is_err());
+        assert_eq!(program.metadata(), Some(&metadata));
+
+        // Ensure the metadata can be removed.
+        assert_eq!(program.clear_metadata(), Some(metadata));
+        assert!(program.metadata().is_none());
+
+        // Ensure the ABI hash changes with the interface, but not with the instructions.
+        let program = Program::<CurrentNetwork>::from_str(&source.replace("add r0 r0", "mul r0 r0"))?;
+        assert_eq!(abi_hash, program.to_abi_hash()?);
+        assert_ne!(content_hash, program.to_content_hash()?);
+        let program = Program::<CurrentNetwork>::from_str(&source.replace("output r1 as u64", "output r1 as u128"))?;
+        assert_ne!(abi_hash, program.to_abi_hash()?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    FinalizeRegistersState,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, Value},
+};
+
+/// Halts if the block height is **not** greater than or equal to `operand`.
+pub type AssertHeightGe<N> = AssertBlock<N, { Variant::HeightGe as u8 }>;
+/// Halts if the block height is **not** less than or equal to `operand`.
+pub type AssertHeightLe<N> = AssertBlock<N, { Variant::HeightLe as u8 }>;
+/// Halts if the block timestamp is **not** greater than or equal to `operand`.
+pub type AssertTimestampGe<N> = AssertBlock<N, { Variant::TimestampGe as u8 }>;
+/// Halts if the block timestamp is **not** less than or equal to `operand`.
+pub type AssertTimestampLe<N> = AssertBlock<N, { Variant::TimestampLe as u8 }>;
+
+enum Variant {
+    HeightGe,
+    HeightLe,
+    TimestampGe,
+    TimestampLe,
+}
+
+/// Compares the block height or block timestamp to `operand`, and halts if the condition is not met,
+/// e.g. `assert.height.ge r0;` or `assert.timestamp.le r1;`.
+/// The block height operand must be a `u32`, and the block timestamp operand must be an `i64`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AssertBlock<N: Network, const VARIANT: u8> {
+    /// The operand.
+    operand: Operand<N>,
+}
+
+impl<N: Network, const VARIANT: u8> AssertBlock<N, VARIANT> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Command("assert.height.ge"),
+            1 => Opcode::Command("assert.height.le"),
+            2 => Opcode::Command("assert.timestamp.ge"),
+            3 => Opcode::Command("assert.timestamp.le"),
+            _ => panic!("Invalid 'assert' command opcode"),
+        }
+    }
+
+    /// Returns the operand.
+    #[inline]
+    pub fn operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+
+    /// Returns the expected literal type of the operand.
+    #[inline]
+    pub const fn operand_type() -> LiteralType {
+        match VARIANT {
+            0 | 1 => LiteralType::U32,
+            2 | 3 => LiteralType::I64,
+            _ => panic!("Invalid 'assert' command opcode"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> AssertBlock<N, VARIANT> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + FinalizeRegistersState<N>),
+    ) -> Result<()> {
+        // Load the operand, and compare it to the block height or block timestamp.
+        let is_satisfied = match (VARIANT, registers.load(stack, &self.operand)?) {
+            (0, Value::Plaintext(Plaintext::Literal(Literal::U32(height), _))) => {
+                registers.state().block_height() >= *height
+            }
+            (1, Value::Plaintext(Plaintext::Literal(Literal::U32(height), _))) => {
+                registers.state().block_height() <= *height
+            }
+            (2, Value::Plaintext(Plaintext::Literal(Literal::I64(timestamp), _))) => {
+                registers.state().block_timestamp() >= *timestamp
+            }
+            (3, Value::Plaintext(Plaintext::Literal(Literal::I64(timestamp), _))) => {
+                registers.state().block_timestamp() <= *timestamp
+            }
+            (_, value) => {
+                bail!("Command '{}' expects a '{}', but found '{value}'", Self::opcode(), Self::operand_type())
+            }
+        };
+
+        // Ensure the condition is met.
+        match is_satisfied {
+            true => Ok(()),
+            false => bail!("Command '{}' failed: '{}' is not satisfied", Self::opcode(), self.operand),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for AssertBlock<N, VARIANT> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { operand }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for AssertBlock<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into a command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for AssertBlock<N, VARIANT> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for AssertBlock<N, VARIANT> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} {};", Self::opcode(), self.operand)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for AssertBlock<N, VARIANT> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+
+        // Return the command.
+        Ok(Self { operand })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for AssertBlock<N, VARIANT> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the operand.
+        self.operand.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, assert) = AssertHeightGe::<CurrentNetwork>::parse("assert.height.ge r0;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operand, Operand::Register(Register::Locator(0)), "The operand is incorrect");
+
+        let (string, assert) = AssertHeightLe::<CurrentNetwork>::parse("assert.height.le 100u32;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operand, Operand::from_str("100u32").unwrap(), "The operand is incorrect");
+
+        let (string, assert) = AssertTimestampGe::<CurrentNetwork>::parse("assert.timestamp.ge r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operand, Operand::Register(Register::Locator(1)), "The operand is incorrect");
+
+        let (string, assert) = AssertTimestampLe::<CurrentNetwork>::parse("assert.timestamp.le r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operand, Operand::Register(Register::Locator(2)), "The operand is incorrect");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(AssertHeightGe::<CurrentNetwork>::parse("assert.height.le r0;").is_err());
+        assert!(AssertTimestampLe::<CurrentNetwork>::parse("assert.height.le r0;").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = "assert.height.ge r0;";
+        assert_eq!(expected, AssertHeightGe::<CurrentNetwork>::from_str(expected).unwrap().to_string());
+
+        let expected = "assert.timestamp.le 1700000000i64;";
+        assert_eq!(expected, AssertTimestampLe::<CurrentNetwork>::from_str(expected).unwrap().to_string());
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod assert_block;
+pub use assert_block::*;
+
 mod await_;
 pub use await_::*;
 
@@ -53,6 +56,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
 };
 use console::{
     network::prelude::*,
@@ -84,6 +88,14 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Halts, if the block height is **not** greater than or equal to `operand`.
+    AssertHeightGe(AssertHeightGe<N>),
+    /// Halts, if the block height is **not** less than or equal to `operand`.
+    AssertHeightLe(AssertHeightLe<N>),
+    /// Halts, if the block timestamp is **not** greater than or equal to `operand`.
+    AssertTimestampGe(AssertTimestampGe<N>),
+    /// Halts, if the block timestamp is **not** less than or equal to `operand`.
+    AssertTimestampLe(AssertTimestampLe<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -97,6 +109,10 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
             Command::Await(_)
+            | Command::AssertHeightGe(_)
+            | Command::AssertHeightLe(_)
+            | Command::AssertTimestampGe(_)
+            | Command::AssertTimestampLe(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
             | Command::Position(_)
@@ -145,6 +161,28 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+            Command::AssertHeightGe(_) => AssertHeightGe::<N>::opcode(),
+            Command::AssertHeightLe(_) => AssertHeightLe::<N>::opcode(),
+            Command::AssertTimestampGe(_) => AssertTimestampGe::<N>::opcode(),
+            Command::AssertTimestampLe(_) => AssertTimestampLe::<N>::opcode(),
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -176,6 +214,11 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // Finalize the `assert.height.*` and `assert.timestamp.*` commands, and return no finalize operation.
+            Command::AssertHeightGe(assert) => assert.finalize(stack, registers).map(|_| None),
+            Command::AssertHeightLe(assert) => assert.finalize(stack, registers).map(|_| None),
+            Command::AssertTimestampGe(assert) => assert.finalize(stack, registers).map(|_| None),
+            Command::AssertTimestampLe(assert) => assert.finalize(stack, registers).map(|_| None),
         }
     }
 }
@@ -208,8 +251,16 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `assert.height.ge` command.
+            11 => Ok(Self::AssertHeightGe(AssertHeightGe::read_le(&mut reader)?)),
+            // Read the `assert.height.le` command.
+            12 => Ok(Self::AssertHeightLe(AssertHeightLe::read_le(&mut reader)?)),
+            // Read the `assert.timestamp.ge` command.
+            13 => Ok(Self::AssertTimestampGe(AssertTimestampGe::read_le(&mut reader)?)),
+            // Read the `assert.timestamp.le` command.
+            14 => Ok(Self::AssertTimestampLe(AssertTimestampLe::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            15.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +335,30 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::AssertHeightGe(assert) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `assert.height.ge` command.
+                assert.write_le(&mut writer)
+            }
+            Self::AssertHeightLe(assert) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `assert.height.le` command.
+                assert.write_le(&mut writer)
+            }
+            Self::AssertTimestampGe(assert) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `assert.timestamp.ge` command.
+                assert.write_le(&mut writer)
+            }
+            Self::AssertTimestampLe(assert) => {
+                // Write the variant.
+                14u8.write_le(&mut writer)?;
+                // Write the `assert.timestamp.le` command.
+                assert.write_le(&mut writer)
+            }
         }
     }
 }
@@ -305,6 +380,10 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(AssertHeightGe::parse, |assert| Self::AssertHeightGe(assert)),
+            map(AssertHeightLe::parse, |assert| Self::AssertHeightLe(assert)),
+            map(AssertTimestampGe::parse, |assert| Self::AssertTimestampGe(assert)),
+            map(AssertTimestampLe::parse, |assert| Self::AssertTimestampLe(assert)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -350,6 +429,10 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::AssertHeightGe(assert) => Display::fmt(assert, f),
+            Self::AssertHeightLe(assert) => Display::fmt(assert, f),
+            Self::AssertTimestampGe(assert) => Display::fmt(assert, f),
+            Self::AssertTimestampLe(assert) => Display::fmt(assert, f),
         }
     }
 }
@@ -442,6 +525,30 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // AssertHeightGe
+        let expected = "assert.height.ge r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // AssertHeightLe
+        let expected = "assert.height.le 100u32;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // AssertTimestampGe
+        let expected = "assert.timestamp.ge r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // AssertTimestampLe
+        let expected = "assert.timestamp.le r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -519,5 +626,29 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // AssertHeightGe
+        let expected = "assert.height.ge r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::AssertHeightGe(AssertHeightGe::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // AssertHeightLe
+        let expected = "assert.height.le 100u32;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::AssertHeightLe(AssertHeightLe::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // AssertTimestampGe
+        let expected = "assert.timestamp.ge r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::AssertTimestampGe(AssertTimestampGe::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // AssertTimestampLe
+        let expected = "assert.timestamp.le r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::AssertTimestampLe(AssertTimestampLe::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -87,6 +87,8 @@ pub enum Instruction<N: Network> {
     Cast(Cast<N>),
     /// Casts the operands into the declared type, with lossy truncation if applicable.
     CastLossy(CastLossy<N>),
+    /// Clamps `first` to the range from `second` to `third`, storing the outcome in `destination`.
+    Clamp(Clamp<N>),
     /// Performs a BHP commitment on inputs of 256-bit chunks.
     CommitBHP256(CommitBHP256<N>),
     /// Performs a BHP commitment on inputs of 512-bit chunks.
@@ -99,12 +101,44 @@ pub enum Instruction<N: Network> {
     CommitPED64(CommitPED64<N>),
     /// Performs a Pedersen commitment on up to a 128-bit input.
     CommitPED128(CommitPED128<N>),
+    /// Opens the commitment `first` of a commit-reveal scheme, storing whether it is valid in `destination`.
+    CommitOpen(CommitOpen<N>),
+    /// Seals a message into the commitment of a commit-reveal scheme, storing the outcome in `destination`.
+    CommitSeal(CommitSeal<N>),
+    /// Verifies a BHP commitment on inputs of 256-bit chunks.
+    CommitVerifyBHP256(CommitVerifyBHP256<N>),
+    /// Verifies a BHP commitment on inputs of 512-bit chunks.
+    CommitVerifyBHP512(CommitVerifyBHP512<N>),
+    /// Verifies a BHP commitment on inputs of 768-bit chunks.
+    CommitVerifyBHP768(CommitVerifyBHP768<N>),
+    /// Verifies a BHP commitment on inputs of 1024-bit chunks.
+    CommitVerifyBHP1024(CommitVerifyBHP1024<N>),
+    /// Verifies a Pedersen commitment on up to a 64-bit input.
+    CommitVerifyPED64(CommitVerifyPED64<N>),
+    /// Verifies a Pedersen commitment on up to a 128-bit input.
+    CommitVerifyPED128(CommitVerifyPED128<N>),
     /// Divides `first` by `second`, storing the outcome in `destination`.
     Div(Div<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     DivWrapped(DivWrapped<N>),
+    /// Computes the dot product of the vectors `first` and `second`, storing the outcome in `destination`.
+    Dot(Dot<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Adds `first` with `second` as fixed-point values, storing the outcome in `destination`.
+    Fixed64Add(Fixed64Add<N>),
+    /// Divides `first` by `second` as fixed-point values with `third` fractional bits, storing the outcome in `destination`.
+    Fixed64Div(Fixed64Div<N>),
+    /// Computes the exponential of `first` as a fixed-point value with `second` fractional bits, storing the outcome in `destination`.
+    Fixed64Exp(Fixed64Exp<N>),
+    /// Computes the natural logarithm of `first` as a fixed-point value with `second` fractional bits, storing the outcome in `destination`.
+    Fixed64Ln(Fixed64Ln<N>),
+    /// Multiplies `first` with `second` as fixed-point values with `third` fractional bits, storing the outcome in `destination`.
+    Fixed64Mul(Fixed64Mul<N>),
+    /// Computes the sine of `first` as a fixed-point value with `second` fractional bits, storing the outcome in `destination`.
+    Fixed64Sin(Fixed64Sin<N>),
+    /// Subtracts `second` from `first` as fixed-point values, storing the outcome in `destination`.
+    Fixed64Sub(Fixed64Sub<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -155,6 +189,12 @@ pub enum Instruction<N: Network> {
     LessThan(LessThan<N>),
     /// Computes whether `first` is less than or equal to `second` as a boolean, storing the outcome in `destination`.
     LessThanOrEqual(LessThanOrEqual<N>),
+    /// Multiplies the matrix `first` by the vector or matrix `second`, storing the outcome in `destination`.
+    MatMul(MatMul<N>),
+    /// Computes the maximum of `first` and `second`, storing the outcome in `destination`.
+    Max(Max<N>),
+    /// Computes the minimum of `first` and `second`, storing the outcome in `destination`.
+    Min(Min<N>),
     /// Computes `first` mod `second`, storing the outcome in `destination`.
     Modulo(Modulo<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
@@ -199,6 +239,8 @@ pub enum Instruction<N: Network> {
     SubWrapped(SubWrapped<N>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<N>),
+    /// Verifies the VRF proof `(proof, gamma)` for `address` and `input`, storing the VRF output in `destination`.
+    VRFVerify(VRFVerify<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
 }
@@ -243,15 +285,32 @@ macro_rules! instruction {
             Call,
             Cast,
             CastLossy,
+            Clamp,
             CommitBHP256,
             CommitBHP512,
             CommitBHP768,
             CommitBHP1024,
             CommitPED64,
             CommitPED128,
+            CommitOpen,
+            CommitSeal,
+            CommitVerifyBHP256,
+            CommitVerifyBHP512,
+            CommitVerifyBHP768,
+            CommitVerifyBHP1024,
+            CommitVerifyPED64,
+            CommitVerifyPED128,
             Div,
             DivWrapped,
+            Dot,
             Double,
+            Fixed64Add,
+            Fixed64Div,
+            Fixed64Exp,
+            Fixed64Ln,
+            Fixed64Mul,
+            Fixed64Sin,
+            Fixed64Sub,
             GreaterThan,
             GreaterThanOrEqual,
             HashBHP256,
@@ -277,6 +336,9 @@ macro_rules! instruction {
             IsNeq,
             LessThan,
             LessThanOrEqual,
+            MatMul,
+            Max,
+            Min,
             Modulo,
             Mul,
             MulWrapped,
@@ -299,6 +361,7 @@ macro_rules! instruction {
             Sub,
             SubWrapped,
             Ternary,
+            VRFVerify,
             Xor,
         }}
     };
@@ -469,7 +532,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            89,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -33,10 +33,14 @@ pub enum Opcode {
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
     Is(&'static str),
+    /// The opcode is for a linear algebra operation (i.e. `dot`).
+    Linalg(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for VRF verification (i.e. `vrf.verify`).
+    VRF,
 }
 
 impl Deref for Opcode {
@@ -53,8 +57,10 @@ impl Deref for Opcode {
             Opcode::Commit(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
+            Opcode::Linalg(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::VRF => &"vrf.verify",
         }
     }
 }
@@ -78,8 +84,10 @@ impl Display for Opcode {
             Self::Commit(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
+            Self::Linalg(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::VRF => write!(f, "{}", self.deref()),
         }
     }
 }
@@ -40,6 +40,9 @@ pub enum Operand<N: Network> {
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
+    /// The operand is the Poseidon digest of the function inputs.
+    /// Note: This variant is only accessible in the `function` scope.
+    InputDigest,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -26,6 +26,7 @@ impl<N: Network> Parser for Operand<N> {
             map(tag("self.signer"), |_| Self::Signer),
             map(tag("self.caller"), |_| Self::Caller),
             map(tag("block.height"), |_| Self::BlockHeight),
+            map(tag("input.digest"), |_| Self::InputDigest),
             // Note that `Operand::ProgramID`s must be parsed before `Operand::Literal`s, since a program ID can be implicitly parsed as a literal address.
             // This ensures that the string representation of a program uses the `Operand::ProgramID` variant.
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -76,6 +77,8 @@ impl<N: Network> Display for Operand<N> {
             Self::Caller => write!(f, "self.caller"),
             // Prints the identifier for the block height, i.e. block.height
             Self::BlockHeight => write!(f, "block.height"),
+            // Prints the identifier for the input digest, i.e. input.digest
+            Self::InputDigest => write!(f, "input.digest"),
         }
     }
 }
@@ -98,6 +101,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("r0.owner").unwrap().1;
         assert_eq!(Operand::Register(Register::from_str("r0.owner")?), operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("r0.sides[1u32].start.x").unwrap().1;
+        assert_eq!(Operand::Register(Register::from_str("r0.sides[1u32].start.x")?), operand);
+
         let operand = Operand::<CurrentNetwork>::parse("howard.aleo").unwrap().1;
         assert_eq!(Operand::ProgramID(ProgramID::from_str("howard.aleo")?), operand);
 
@@ -110,9 +116,24 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
         assert_eq!(Operand::BlockHeight, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("input.digest").unwrap().1;
+        assert_eq!(Operand::InputDigest, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
+        let operand =
+            Operand::<CurrentNetwork>::parse("aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw")
+                .unwrap()
+                .1;
+        assert_eq!(
+            Operand::Literal(Literal::from_str("aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw")?),
+            operand
+        );
+
+        let operand = Operand::<CurrentNetwork>::parse("-2group").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("-2group")?), operand);
+
         // Sanity check a failure case.
         let (remainder, operand) = Operand::<CurrentNetwork>::parse("1field.private").unwrap();
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
@@ -132,6 +153,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("r0.owner").unwrap().1;
         assert_eq!(format!("{operand}"), "r0.owner");
 
+        let operand = Operand::<CurrentNetwork>::parse("r0.sides[1u32].start.x").unwrap().1;
+        assert_eq!(format!("{operand}"), "r0.sides[1u32].start.x");
+
         let operand = Operand::<CurrentNetwork>::parse("howard.aleo").unwrap().1;
         assert_eq!(format!("{operand}"), "howard.aleo");
 
@@ -151,6 +175,13 @@ mod tests {
     #[test]
     fn test_operand_from_str_fails() -> Result<()> {
         assert!(Operand::<CurrentNetwork>::from_str("1field.private").is_err());
+        // Ensure an address with an invalid checksum is rejected.
+        assert!(
+            Operand::<CurrentNetwork>::from_str("aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayq")
+                .is_err()
+        );
+        // Ensure a group element that is not on the curve is rejected.
+        assert!(Operand::<CurrentNetwork>::from_str("1group").is_err());
         Ok(())
     }
 }
@@ -0,0 +1,408 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, Field},
+};
+
+/// Seals a message into a commitment, for a commit-reveal scheme.
+pub type CommitSeal<N> = CommitRevealInstruction<N, { CommitReveal::Seal as u8 }>;
+/// Opens a commitment that was produced by `commit.seal`.
+pub type CommitOpen<N> = CommitRevealInstruction<N, { CommitReveal::Open as u8 }>;
+
+enum CommitReveal {
+    Seal,
+    Open,
+}
+
+/// The domain separator of the commitments, which distinguishes them from the commitments of the `commit` instructions.
+const COMMIT_REVEAL_DOMAIN: &str = "AleoCommitReveal0";
+
+/// Seals a message into a commitment, or opens a commitment, for a commit-reveal scheme.
+///
+/// `commit.seal {message} {salt} {domain} into {commitment};` commits to the message under a scalar salt,
+/// and `commit.open {commitment} {message} {salt} {domain} into {is_valid};` checks the commitment.
+/// A commitment is a BHP256 commitment over the domain separator, the address of the program, the domain,
+/// and the message, so a commitment cannot be opened in another program, or under another domain.
+/// The domain is a field element chosen by the program, e.g. the ID of an auction and its round.
+///
+/// The deadline of each phase is enforced in the finalize scope with `assert.height.le` and `assert.height.ge`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CommitRevealInstruction<N: Network, const VARIANT: u8> {
+    /// The operands, as `message`, `salt`, and `domain` to seal,
+    /// and as `commitment`, `message`, `salt`, and `domain` to open.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> CommitRevealInstruction<N, VARIANT> {
+    /// Initializes a new `commit.seal` or `commit.open` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Commit("commit.seal"),
+            1 => Opcode::Commit("commit.open"),
+            2.. => panic!("Invalid 'commit.seal' or 'commit.open' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(self.operands.len() == Self::num_operands(), "Invalid number of operands");
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the number of operands.
+    const fn num_operands() -> usize {
+        match VARIANT == CommitReveal::Seal as u8 {
+            true => 3,
+            false => 4,
+        }
+    }
+
+    /// Returns the fixed prefix of the commitment preimage, for the given program.
+    fn prefix(stack: &impl StackProgram<N>) -> Result<[Field<N>; 2]> {
+        Ok([Field::new_domain_separator(COMMIT_REVEAL_DOMAIN), stack.program_id().to_address()?.to_x_coordinate()])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> CommitRevealInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Load the operands, where the message, salt, and domain are the last three operands.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        let [message, salt, domain] = &inputs[inputs.len() - 3..] else {
+            bail!("Invalid operands for '{}'", Self::opcode())
+        };
+        let salt = match salt {
+            Value::Plaintext(Plaintext::Literal(Literal::Scalar(salt), ..)) => salt,
+            _ => bail!("Invalid salt type for '{}', expected a scalar", Self::opcode()),
+        };
+        let domain = match domain {
+            Value::Plaintext(Plaintext::Literal(Literal::Field(domain), ..)) => domain,
+            _ => bail!("Invalid domain type for '{}', expected a field", Self::opcode()),
+        };
+
+        // Compute the commitment.
+        let [separator, program] = Self::prefix(stack)?;
+        let preimage =
+            [separator.to_bits_le(), program.to_bits_le(), domain.to_bits_le(), message.to_bits_le()].concat();
+        let commitment = N::commit_bhp256(&preimage, salt)?;
+
+        let output = match VARIANT {
+            0 => Literal::Field(commitment),
+            1 => match &inputs[0] {
+                Value::Plaintext(Plaintext::Literal(Literal::Field(candidate), ..)) => {
+                    Literal::Boolean(Boolean::new(*candidate == commitment))
+                }
+                _ => bail!("Invalid commitment type for '{}', expected a field", Self::opcode()),
+            },
+            2.. => bail!("Invalid 'commit.seal' or 'commit.open' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{traits::ToBits, Inject};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Load the operands, where the message, salt, and domain are the last three operands.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        let [message, salt, domain] = &inputs[inputs.len() - 3..] else {
+            bail!("Invalid operands for '{}'", Self::opcode())
+        };
+        let salt = match salt {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(salt), ..)) => salt,
+            _ => bail!("Invalid salt type for '{}', expected a scalar", Self::opcode()),
+        };
+        let domain = match domain {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(domain), ..)) => domain,
+            _ => bail!("Invalid domain type for '{}', expected a field", Self::opcode()),
+        };
+
+        // Compute the commitment.
+        let [separator, program] = Self::prefix(stack)?.map(circuit::Field::<A>::constant);
+        let preimage =
+            [separator.to_bits_le(), program.to_bits_le(), domain.to_bits_le(), message.to_bits_le()].concat();
+        let commitment = A::commit_bhp256(&preimage, salt);
+
+        let output = match VARIANT {
+            0 => circuit::Literal::Field(commitment),
+            1 => match &inputs[0] {
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(candidate), ..)) => {
+                    circuit::Literal::Boolean(candidate.is_equal(&commitment))
+                }
+                _ => bail!("Invalid commitment type for '{}', expected a field", Self::opcode()),
+            },
+            2.. => bail!("Invalid 'commit.seal' or 'commit.open' variant: {VARIANT}"),
+        };
+        // Store the output.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+
+        // Retrieve the types of the salt and domain.
+        let salt_type = &input_types[input_types.len() - 2];
+        let domain_type = &input_types[input_types.len() - 1];
+        // Ensure the salt is a scalar.
+        if salt_type != &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+            bail!("Instruction '{}' expects the salt to be a scalar. Found '{salt_type}'", Self::opcode())
+        }
+        // Ensure the domain is a field.
+        if domain_type != &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!("Instruction '{}' expects the domain to be a field. Found '{domain_type}'", Self::opcode())
+        }
+
+        match VARIANT {
+            0 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
+            1 => {
+                // Ensure the commitment is a field.
+                if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+                    bail!(
+                        "Instruction '{}' expects the commitment to be a field. Found input of type '{}'",
+                        Self::opcode(),
+                        input_types[0]
+                    )
+                }
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+            }
+            2.. => bail!("Invalid 'commit.seal' or 'commit.open' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for CommitRevealInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string, preceded by whitespace.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand::<N>, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for CommitRevealInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for CommitRevealInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for CommitRevealInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for CommitRevealInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = (0..Self::num_operands()).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<_>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, destination).map_err(error)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for CommitRevealInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!("Invalid number of operands for '{}'", Self::opcode())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, seal) = CommitSeal::<CurrentNetwork>::parse("commit.seal r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(seal.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(seal.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(seal.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, open) = CommitOpen::<CurrentNetwork>::parse("commit.open r0 r1 r2 1field into r4").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(open.operands.len(), 4, "The number of operands is incorrect");
+        assert_eq!(open.operands[3], Operand::from_str("1field").unwrap(), "The fourth operand is incorrect");
+        assert_eq!(open.destination, Register::Locator(4), "The destination register is incorrect");
+
+        // Ensure the number of operands is enforced.
+        assert!(CommitOpen::<CurrentNetwork>::from_str("commit.open r0 r1 r2 into r3").is_err());
+        assert!(CommitSeal::<CurrentNetwork>::from_str("commit.seal r0 r1 r2 r3 into r4").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        let expected = "commit.seal r0 r1 r2 into r3";
+        let seal = CommitSeal::<CurrentNetwork>::from_str(expected).unwrap();
+        assert_eq!(seal.to_string(), expected);
+        assert_eq!(seal, CommitSeal::<CurrentNetwork>::read_le(&seal.to_bytes_le().unwrap()[..]).unwrap());
+
+        let expected = "commit.open r0 r1 r2 r3 into r4";
+        let open = CommitOpen::<CurrentNetwork>::from_str(expected).unwrap();
+        assert_eq!(open.to_string(), expected);
+        assert_eq!(open, CommitOpen::<CurrentNetwork>::read_le(&open.to_bytes_le().unwrap()[..]).unwrap());
+    }
+}
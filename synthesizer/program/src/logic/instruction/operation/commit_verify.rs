@@ -0,0 +1,388 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Verifies a BHP256 commitment, which processes inputs in 256-bit chunks.
+pub type CommitVerifyBHP256<N> = CommitVerifyInstruction<N, { Committer::CommitBHP256 as u8 }>;
+/// Verifies a BHP512 commitment, which processes inputs in 512-bit chunks.
+pub type CommitVerifyBHP512<N> = CommitVerifyInstruction<N, { Committer::CommitBHP512 as u8 }>;
+/// Verifies a BHP768 commitment, which processes inputs in 768-bit chunks.
+pub type CommitVerifyBHP768<N> = CommitVerifyInstruction<N, { Committer::CommitBHP768 as u8 }>;
+/// Verifies a BHP1024 commitment, which processes inputs in 1024-bit chunks.
+pub type CommitVerifyBHP1024<N> = CommitVerifyInstruction<N, { Committer::CommitBHP1024 as u8 }>;
+
+/// Verifies a Pedersen64 commitment, which processes inputs in 64-bit chunks.
+pub type CommitVerifyPED64<N> = CommitVerifyInstruction<N, { Committer::CommitPED64 as u8 }>;
+/// Verifies a Pedersen128 commitment, which processes inputs in 128-bit chunks.
+pub type CommitVerifyPED128<N> = CommitVerifyInstruction<N, { Committer::CommitPED128 as u8 }>;
+
+enum Committer {
+    CommitBHP256,
+    CommitBHP512,
+    CommitBHP768,
+    CommitBHP1024,
+    CommitPED64,
+    CommitPED128,
+}
+
+/// Returns 'true' if the commitment type is valid.
+fn is_valid_commitment_type(commitment_type: LiteralType) -> bool {
+    matches!(commitment_type, LiteralType::Address | LiteralType::Field | LiteralType::Group)
+}
+
+/// Checks that `commitment` is the commitment of `message` with `randomizer`, storing the outcome in `destination`.
+///
+/// The commitment may be an address, field, or group, as produced by the matching `commit` instruction.
+/// In the circuit, a field commitment is compared against the x-coordinate of the recomputed commitment,
+/// so only the coordinate that was committed to is constrained.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CommitVerifyInstruction<N: Network, const VARIANT: u8> {
+    /// The operands as `commitment`, `message`, and `randomizer`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> CommitVerifyInstruction<N, VARIANT> {
+    /// Initializes a new `commit.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check that the operands is exactly three inputs.
+        ensure!(operands.len() == 3, "Commit verify instructions must have three operands");
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Commit("commit.verify.bhp256"),
+            1 => Opcode::Commit("commit.verify.bhp512"),
+            2 => Opcode::Commit("commit.verify.bhp768"),
+            3 => Opcode::Commit("commit.verify.bhp1024"),
+            4 => Opcode::Commit("commit.verify.ped64"),
+            5 => Opcode::Commit("commit.verify.ped128"),
+            6.. => panic!("Invalid 'commit.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Commit verify operations must have three operands");
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> CommitVerifyInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the commitment, message, and randomizer.
+        let commitment = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Literal(commitment, ..)) if is_valid_commitment_type(commitment.to_type()) => {
+                commitment
+            }
+            _ => bail!("Invalid commitment type for the commit verify evaluation, expected address, field, or group"),
+        };
+        let message = registers.load(stack, &self.operands[1])?;
+        let randomizer = match registers.load(stack, &self.operands[2])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..)) => randomizer,
+            _ => bail!("Invalid randomizer type for the commit verify evaluation, expected a scalar"),
+        };
+
+        // Recompute the commitment.
+        let candidate = match VARIANT {
+            0 => N::commit_to_group_bhp256(&message.to_bits_le(), &randomizer)?,
+            1 => N::commit_to_group_bhp512(&message.to_bits_le(), &randomizer)?,
+            2 => N::commit_to_group_bhp768(&message.to_bits_le(), &randomizer)?,
+            3 => N::commit_to_group_bhp1024(&message.to_bits_le(), &randomizer)?,
+            4 => N::commit_to_group_ped64(&message.to_bits_le(), &randomizer)?,
+            5 => N::commit_to_group_ped128(&message.to_bits_le(), &randomizer)?,
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        };
+        // Compare the recomputed commitment against the commitment, in the type of the commitment.
+        let is_valid = Literal::Group(candidate).cast_lossy(commitment.to_type())? == commitment;
+        let output = Literal::Boolean(Boolean::new(is_valid));
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::ToBits;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the commitment, message, and randomizer.
+        let commitment = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(commitment, ..)) => commitment,
+            _ => bail!("Invalid commitment type for the commit verify execution, expected an address, field, or group"),
+        };
+        let message = registers.load_circuit(stack, &self.operands[1])?;
+        let randomizer = match registers.load_circuit(stack, &self.operands[2])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)) => {
+                randomizer
+            }
+            _ => bail!("Invalid randomizer type for the commit verify execution, expected a scalar"),
+        };
+
+        // Recompute the commitment.
+        let candidate = match VARIANT {
+            0 => A::commit_to_group_bhp256(&message.to_bits_le(), &randomizer),
+            1 => A::commit_to_group_bhp512(&message.to_bits_le(), &randomizer),
+            2 => A::commit_to_group_bhp768(&message.to_bits_le(), &randomizer),
+            3 => A::commit_to_group_bhp1024(&message.to_bits_le(), &randomizer),
+            4 => A::commit_to_group_ped64(&message.to_bits_le(), &randomizer),
+            5 => A::commit_to_group_ped128(&message.to_bits_le(), &randomizer),
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        };
+        // Compare the recomputed commitment against the commitment, in the type of the commitment.
+        let output = match commitment {
+            circuit::Literal::Address(address) => candidate.is_equal(&circuit::Group::from(address)),
+            // Note: A field commitment only commits to the x-coordinate, so only the x-coordinate is compared.
+            circuit::Literal::Field(field) => candidate.to_x_coordinate().is_equal(&field),
+            circuit::Literal::Group(group) => candidate.is_equal(&group),
+            _ => bail!("Invalid commitment type for the commit verify execution, expected an address, field, or group"),
+        };
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(
+            circuit::Literal::Boolean(output),
+            Default::default(),
+        ));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the commitment is an address, field, or group.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Literal(commitment_type))
+                if is_valid_commitment_type(*commitment_type) => {}
+            _ => bail!(
+                "Instruction '{}' expects the first input to be an address, field, or group. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+        }
+        // Ensure the randomizer is a scalar.
+        if input_types[2] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a scalar. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        match VARIANT {
+            0..=5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))]),
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for CommitVerifyInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the commitment from the string.
+        let (string, commitment) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the message from the string.
+        let (string, message) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the randomizer from the string.
+        let (string, randomizer) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![commitment, message, randomizer], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for CommitVerifyInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for CommitVerifyInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for CommitVerifyInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for CommitVerifyInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, destination).map_err(error)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for CommitVerifyInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, commit_verify) =
+            CommitVerifyBHP256::<CurrentNetwork>::parse("commit.verify.bhp256 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit_verify.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(commit_verify.operands[0], Operand::Register(Register::Locator(0)), "The first operand is wrong");
+        assert_eq!(commit_verify.operands[1], Operand::Register(Register::Locator(1)), "The second operand is wrong");
+        assert_eq!(commit_verify.operands[2], Operand::Register(Register::Locator(2)), "The third operand is wrong");
+        assert_eq!(commit_verify.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the commit instruction is not parsed as a commit verify instruction.
+        assert!(CommitVerifyPED64::<CurrentNetwork>::parse("commit.ped64 r0 r1 into r2 as field").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        let expected = "commit.verify.ped128 r0 r1 r2 into r3";
+        let commit_verify = CommitVerifyPED128::<CurrentNetwork>::from_str(expected).unwrap();
+        assert_eq!(commit_verify.to_string(), expected);
+
+        let bytes = commit_verify.to_bytes_le().unwrap();
+        assert_eq!(commit_verify, CommitVerifyPED128::<CurrentNetwork>::read_le(&bytes[..]).unwrap());
+    }
+}
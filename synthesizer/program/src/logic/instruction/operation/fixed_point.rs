@@ -0,0 +1,460 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BinaryLiteral, Instruction, Opcode, Operand, Operation, TernaryLiteral};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+    types::I64,
+};
+
+use core::marker::PhantomData;
+
+/// The maximum number of fractional bits supported by the `fixed64` operations.
+pub const FIXED64_MAX_FRACTIONAL_BITS: u8 = 63;
+
+/// Adds `first` with `second` as signed 64-bit fixed-point values, storing the outcome in `destination`.
+pub type Fixed64Add<N> = BinaryLiteral<N, Fixed64AddOperation<N>>;
+/// Subtracts `second` from `first` as signed 64-bit fixed-point values, storing the outcome in `destination`.
+pub type Fixed64Sub<N> = BinaryLiteral<N, Fixed64SubOperation<N>>;
+/// Multiplies `first` with `second` as signed 64-bit fixed-point values with `third` fractional bits,
+/// rounding half away from zero, and storing the outcome in `destination`.
+pub type Fixed64Mul<N> = TernaryLiteral<N, Fixed64MulOperation<N>>;
+/// Divides `first` by `second` as signed 64-bit fixed-point values with `third` fractional bits,
+/// rounding half away from zero, and storing the outcome in `destination`.
+pub type Fixed64Div<N> = TernaryLiteral<N, Fixed64DivOperation<N>>;
+
+/// Returns the fractional bits if they are within the supported range.
+fn check_fractional_bits(opcode: Opcode, fractional_bits: u8) -> Result<u8> {
+    match fractional_bits <= FIXED64_MAX_FRACTIONAL_BITS {
+        true => Ok(fractional_bits),
+        false => {
+            bail!("'{opcode}' supports at most {FIXED64_MAX_FRACTIONAL_BITS} fractional bits, found {fractional_bits}")
+        }
+    }
+}
+
+/// Ensures the fractional bits of the given `fixed64` instruction are a `u8` literal within the supported range.
+/// The fractional bits set the scaling factor in the circuit, so they must be known when the program is checked.
+pub fn check_fractional_bits_operand<N: Network>(instruction: &Instruction<N>) -> Result<()> {
+    // Retrieve the fractional bits, which are the last operand of each `fixed64` instruction that takes them.
+    let operand = match instruction {
+        Instruction::Fixed64Mul(operation) => operation.operands().last(),
+        Instruction::Fixed64Div(operation) => operation.operands().last(),
+        Instruction::Fixed64Exp(operation) => operation.operands().last(),
+        Instruction::Fixed64Ln(operation) => operation.operands().last(),
+        Instruction::Fixed64Sin(operation) => operation.operands().last(),
+        _ => return Ok(()),
+    };
+    let opcode = instruction.opcode();
+    match operand {
+        Some(Operand::Literal(Literal::U8(fractional_bits))) => {
+            check_fractional_bits(opcode, **fractional_bits).map(|_| ())
+        }
+        _ => bail!("'{opcode}' expects the fractional bits to be a 'u8' literal in '{instruction}'"),
+    }
+}
+
+/// Returns `numerator / denominator`, rounding half away from zero.
+pub(crate) fn fixed_round_div(numerator: i128, denominator: i128) -> Result<i128> {
+    ensure!(denominator != 0, "Cannot divide by zero");
+    // Compute the quotient and remainder on the magnitudes.
+    let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+    let (quotient, remainder) = (n / d, n % d);
+    // Round the magnitude up, if the remainder is at least half of the denominator.
+    let quotient = match remainder >= d - remainder {
+        true => quotient + 1,
+        false => quotient,
+    };
+    let quotient = i128::try_from(quotient)?;
+    // Restore the sign of the result.
+    match (numerator < 0) != (denominator < 0) {
+        true => Ok(-quotient),
+        false => Ok(quotient),
+    }
+}
+
+/// Returns `first * second` in fixed-point with the given number of fractional bits.
+pub(crate) fn fixed_mul(first: i64, second: i64, fractional_bits: u8) -> Result<i64> {
+    let product = i128::from(first) * i128::from(second);
+    i64::try_from(fixed_round_div(product, 1i128 << fractional_bits)?)
+        .map_err(|_| anyhow!("Fixed-point multiplication overflowed"))
+}
+
+/// Returns `first / second` in fixed-point with the given number of fractional bits.
+pub(crate) fn fixed_div(first: i64, second: i64, fractional_bits: u8) -> Result<i64> {
+    let numerator = i128::from(first) << fractional_bits;
+    i64::try_from(fixed_round_div(numerator, i128::from(second))?)
+        .map_err(|_| anyhow!("Fixed-point division overflowed"))
+}
+
+/// Returns `numerator / denominator` in the circuit, rounding half away from zero.
+pub(crate) fn fixed_round_div_circuit<A: circuit::Aleo>(
+    numerator: &circuit::I128<A>,
+    denominator: &circuit::I128<A>,
+) -> circuit::I128<A> {
+    use circuit::traits::{One, MSB};
+
+    // Compute the quotient and remainder on the magnitudes.
+    let n = numerator.abs_checked().cast_as_dual();
+    let d = denominator.abs_checked().cast_as_dual();
+    let quotient = &n / &d;
+    let remainder = &n % &d;
+    // Round the magnitude up, if the remainder is at least half of the denominator.
+    let round_up = remainder.is_greater_than_or_equal(&(&d - &remainder));
+    let quotient = circuit::U128::ternary(&round_up, &(&quotient + &circuit::U128::one()), &quotient);
+    let quotient = quotient.cast_as_dual();
+    // Restore the sign of the result.
+    let is_negative = numerator.msb() ^ denominator.msb();
+    circuit::I128::ternary(&is_negative, &-quotient.clone(), &quotient)
+}
+
+/// Returns the number of fractional bits from the given circuit literal, which must be a constant.
+pub(crate) fn fractional_bits_circuit<A: circuit::Aleo>(opcode: Opcode, third: &circuit::Literal<A>) -> Result<u8> {
+    use circuit::Eject;
+
+    match third {
+        circuit::Literal::U8(fractional_bits) => {
+            ensure!(fractional_bits.is_constant(), "'{opcode}' expects the fractional bits to be a constant");
+            check_fractional_bits(opcode, *fractional_bits.eject_value())
+        }
+        _ => bail!("Invalid operands for the '{opcode}' instruction"),
+    }
+}
+
+/// The implementation of the `fixed64.add` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Fixed64AddOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for Fixed64AddOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("fixed64.add");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::I64(first), Literal::I64(second)] => match (**first).checked_add(**second) {
+                Some(output) => Ok(Literal::I64(I64::new(output))),
+                None => bail!("Fixed-point addition overflowed"),
+            },
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::I64(first), circuit::Literal::I64(second)] => Ok(circuit::Literal::I64(first + second)),
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::I64, LiteralType::I64] => Ok(LiteralType::I64),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `fixed64.sub` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Fixed64SubOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for Fixed64SubOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("fixed64.sub");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::I64(first), Literal::I64(second)] => match (**first).checked_sub(**second) {
+                Some(output) => Ok(Literal::I64(I64::new(output))),
+                None => bail!("Fixed-point subtraction overflowed"),
+            },
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::I64(first), circuit::Literal::I64(second)] => Ok(circuit::Literal::I64(first - second)),
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::I64, LiteralType::I64] => Ok(LiteralType::I64),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `fixed64.mul` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Fixed64MulOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 3> for Fixed64MulOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("fixed64.mul");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 3]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::I64(first), Literal::I64(second), Literal::U8(fractional_bits)] => {
+                let fractional_bits = check_fractional_bits(Self::OPCODE, **fractional_bits)?;
+                Ok(Literal::I64(I64::new(fixed_mul(**first, **second, fractional_bits)?)))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 3]) -> Result<circuit::Literal<A>> {
+        use circuit::{Cast, Inject};
+
+        match inputs {
+            [circuit::Literal::I64(first), circuit::Literal::I64(second), third] => {
+                let fractional_bits = fractional_bits_circuit(Self::OPCODE, third)?;
+                // Compute the full-width product.
+                let first: circuit::I128<A> = first.cast();
+                let second: circuit::I128<A> = second.cast();
+                let product = first * second;
+                // Rescale the product by the fixed-point scaling factor.
+                let scale = circuit::I128::constant(console::types::I128::new(1i128 << fractional_bits));
+                let output: circuit::I64<A> = fixed_round_div_circuit(&product, &scale).cast();
+                Ok(circuit::Literal::I64(output))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 3]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::I64, LiteralType::I64, LiteralType::U8] => Ok(LiteralType::I64),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `fixed64.div` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Fixed64DivOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 3> for Fixed64DivOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("fixed64.div");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 3]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::I64(first), Literal::I64(second), Literal::U8(fractional_bits)] => {
+                let fractional_bits = check_fractional_bits(Self::OPCODE, **fractional_bits)?;
+                Ok(Literal::I64(I64::new(fixed_div(**first, **second, fractional_bits)?)))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 3]) -> Result<circuit::Literal<A>> {
+        use circuit::{Cast, Inject};
+
+        match inputs {
+            [circuit::Literal::I64(first), circuit::Literal::I64(second), third] => {
+                let fractional_bits = fractional_bits_circuit(Self::OPCODE, third)?;
+                // Scale the numerator by the fixed-point scaling factor.
+                let first: circuit::I128<A> = first.cast();
+                let scale = circuit::I128::constant(console::types::I128::new(1i128 << fractional_bits));
+                let numerator = first * scale;
+                // Divide the scaled numerator by the denominator.
+                let denominator: circuit::I128<A> = second.cast();
+                let output: circuit::I64<A> = fixed_round_div_circuit(&numerator, &denominator).cast();
+                Ok(circuit::Literal::I64(output))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 3]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::I64, LiteralType::I64, LiteralType::U8] => Ok(LiteralType::I64),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{Eject, Environment, Inject};
+    use console::{
+        network::Testnet3,
+        types::{I64, U8},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    const ITERATIONS: usize = 25;
+
+    /// Returns `numerator / denominator` as a fixed-point number with 16 fractional bits.
+    fn q16(numerator: i64, denominator: i64) -> i64 {
+        (numerator << 16) / denominator
+    }
+
+    fn check_ternary<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 3>>(
+        first: i64,
+        second: i64,
+        fractional_bits: u8,
+        mode: circuit::Mode,
+    ) -> Result<Option<i64>> {
+        let inputs =
+            [Literal::I64(I64::new(first)), Literal::I64(I64::new(second)), Literal::U8(U8::new(fractional_bits))];
+        // Evaluate the operation.
+        let expected = match O::evaluate(&inputs) {
+            Ok(Literal::I64(output)) => Some(*output),
+            Ok(_) => bail!("Unexpected output type"),
+            Err(_) => None,
+        };
+        // Execute the operation.
+        let circuit_inputs = [
+            circuit::Literal::I64(circuit::I64::new(mode, I64::new(first))),
+            circuit::Literal::I64(circuit::I64::new(mode, I64::new(second))),
+            circuit::Literal::U8(circuit::U8::constant(U8::new(fractional_bits))),
+        ];
+        if let Some(expected) = expected {
+            match O::execute::<CurrentAleo>(&circuit_inputs)? {
+                circuit::Literal::I64(candidate) => assert_eq!(expected, *candidate.eject_value()),
+                _ => bail!("Unexpected output type"),
+            }
+            assert!(CurrentAleo::is_satisfied());
+        }
+        CurrentAleo::reset();
+        Ok(expected)
+    }
+
+    #[test]
+    fn test_fixed64_mul() -> Result<()> {
+        let modes = [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private];
+        for mode in modes {
+            assert_eq!(Some(q16(15, 4)), check_ternary::<Fixed64MulOperation<_>>(q16(3, 2), q16(5, 2), 16, mode)?);
+            assert_eq!(Some(q16(-15, 4)), check_ternary::<Fixed64MulOperation<_>>(q16(-3, 2), q16(5, 2), 16, mode)?);
+            assert_eq!(Some(q16(15, 4)), check_ternary::<Fixed64MulOperation<_>>(q16(-3, 2), q16(-5, 2), 16, mode)?);
+            // Ensure the product rounds half away from zero, i.e. 3 * 0.5 = 1.5 rounds to 2.
+            assert_eq!(Some(2), check_ternary::<Fixed64MulOperation<_>>(3, 1, 1, mode)?);
+            assert_eq!(Some(-2), check_ternary::<Fixed64MulOperation<_>>(-3, 1, 1, mode)?);
+            assert_eq!(Some(1), check_ternary::<Fixed64MulOperation<_>>(5, 1, 2, mode)?);
+        }
+        // Ensure overflow is detected.
+        assert_eq!(None, check_ternary::<Fixed64MulOperation<_>>(i64::MAX, i64::MAX, 16, circuit::Mode::Private)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_div() -> Result<()> {
+        let modes = [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private];
+        for mode in modes {
+            // Ensure 1.5 / 2.5 = 0.6 rounds to the nearest representable value, i.e. 39321.6 rounds to 39322.
+            assert_eq!(Some(39322), check_ternary::<Fixed64DivOperation<_>>(q16(3, 2), q16(5, 2), 16, mode)?);
+            assert_eq!(Some(-39322), check_ternary::<Fixed64DivOperation<_>>(q16(3, 2), q16(-5, 2), 16, mode)?);
+            // Ensure the quotient rounds half away from zero, i.e. 1 / 4 = 0.25 rounds to 0.5 in Q1.
+            assert_eq!(Some(1), check_ternary::<Fixed64DivOperation<_>>(1, 4, 1, mode)?);
+            assert_eq!(Some(-1), check_ternary::<Fixed64DivOperation<_>>(-1, 4, 1, mode)?);
+        }
+        // Ensure division by zero is detected.
+        assert_eq!(None, check_ternary::<Fixed64DivOperation<_>>(q16(3, 2), 0, 16, circuit::Mode::Private)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_random() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            // Sample values that do not overflow.
+            let first = rng.gen_range(-(1i64 << 40)..(1i64 << 40));
+            let second = rng.gen_range(-(1i64 << 40)..(1i64 << 40));
+            let fractional_bits = rng.gen_range(24..=FIXED64_MAX_FRACTIONAL_BITS);
+
+            let expected = fixed_round_div(i128::from(first) * i128::from(second), 1i128 << fractional_bits)?;
+            let candidate =
+                check_ternary::<Fixed64MulOperation<_>>(first, second, fractional_bits, circuit::Mode::Private)?;
+            assert_eq!(Some(i64::try_from(expected)?), candidate);
+
+            check_ternary::<Fixed64DivOperation<_>>(first, second, fractional_bits, circuit::Mode::Private)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_rejects_invalid_fractional_bits() {
+        let inputs = [Literal::<CurrentNetwork>::I64(I64::new(1)), Literal::I64(I64::new(1)), Literal::U8(U8::new(64))];
+        assert!(Fixed64MulOperation::evaluate(&inputs).is_err());
+        assert!(Fixed64DivOperation::evaluate(&inputs).is_err());
+
+        // Ensure the fractional bits must be a constant in the circuit.
+        let circuit_inputs = [
+            circuit::Literal::<CurrentAleo>::I64(circuit::I64::new(circuit::Mode::Private, I64::new(1))),
+            circuit::Literal::I64(circuit::I64::new(circuit::Mode::Private, I64::new(1))),
+            circuit::Literal::U8(circuit::U8::new(circuit::Mode::Private, U8::new(16))),
+        ];
+        assert!(Fixed64MulOperation::execute(&circuit_inputs).is_err());
+        CurrentAleo::reset();
+    }
+
+    #[test]
+    fn test_check_fractional_bits_operand() -> Result<()> {
+        let check =
+            |instruction: &str| check_fractional_bits_operand(&Instruction::<CurrentNetwork>::from_str(instruction)?);
+
+        // Ensure a literal within the supported range is accepted.
+        check("fixed64.mul r0 r1 16u8 into r2;")?;
+        check("fixed64.div r0 r1 63u8 into r2;")?;
+        check("fixed64.add r0 r1 into r2;")?;
+        // Ensure a register, or a literal outside the supported range, is rejected.
+        assert!(check("fixed64.mul r0 r1 r2 into r3;").is_err());
+        assert!(check("fixed64.div r0 r1 r2 into r3;").is_err());
+        assert!(check("fixed64.mul r0 r1 64u8 into r2;").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_add_sub() -> Result<()> {
+        let inputs = [Literal::<CurrentNetwork>::I64(I64::new(q16(3, 2))), Literal::I64(I64::new(q16(9, 4)))];
+        assert_eq!(Literal::I64(I64::new(q16(15, 4))), Fixed64AddOperation::evaluate(&inputs)?);
+        assert_eq!(Literal::I64(I64::new(q16(-3, 4))), Fixed64SubOperation::evaluate(&inputs)?);
+
+        let overflow = [Literal::<CurrentNetwork>::I64(I64::new(i64::MAX)), Literal::I64(I64::new(1))];
+        assert!(Fixed64AddOperation::evaluate(&overflow).is_err());
+        Ok(())
+    }
+}
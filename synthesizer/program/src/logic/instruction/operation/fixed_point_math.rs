@@ -0,0 +1,447 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transcendental functions over signed 64-bit fixed-point values.
+//!
+//! Each function reduces its argument into a small interval with exact integer arithmetic,
+//! evaluates a truncated power series on the reduced argument with `FIXED64_MATH_PRECISION`
+//! fractional bits, and rounds the outcome half away from zero to the requested number of
+//! fractional bits. The evaluation and the circuit perform the identical sequence of rounded
+//! integer operations, so both produce bit-for-bit identical outputs on every platform.
+//!
+//! For up to 56 fractional bits, the outputs are within one unit in the last place of the
+//! exact result (absolute error for `sin` and `ln`, relative error for `exp` above one).
+
+use super::fixed_point::{fixed_round_div, fixed_round_div_circuit, fractional_bits_circuit};
+use crate::{BinaryLiteral, Opcode, Operation};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+    types::I64,
+};
+
+use core::marker::PhantomData;
+
+/// The number of fractional bits used for the intermediate values of the `fixed64` math operations.
+pub const FIXED64_MATH_PRECISION: u8 = 60;
+
+/// The fixed-point representation of one, with `FIXED64_MATH_PRECISION` fractional bits.
+const ONE: i128 = 1 << FIXED64_MATH_PRECISION;
+/// The fixed-point representation of pi, with `FIXED64_MATH_PRECISION` fractional bits.
+const PI: i128 = 3_622_009_729_038_561_421;
+/// The fixed-point representation of pi / 2, with `FIXED64_MATH_PRECISION` fractional bits.
+const HALF_PI: i128 = 1_811_004_864_519_280_711;
+/// The fixed-point representation of ln(2), with `FIXED64_MATH_PRECISION` fractional bits.
+const LN_2: i128 = 799_144_290_325_165_979;
+
+/// The number of nested terms in the sine series, which has an error below 2^-59 on [-pi/2, pi/2].
+const SIN_TERMS: i128 = 10;
+/// The number of nested terms in the exponential series, which has an error below 2^-70 on [-ln(2)/2, ln(2)/2].
+const EXP_TERMS: i128 = 16;
+/// The number of terms in the inverse hyperbolic tangent series, which has an error below 2^-60 on [0, 1/3].
+const LN_TERMS: i128 = 18;
+/// The largest right shift applied to the reduced exponential, beyond which every output rounds to zero.
+const EXP_MAX_SHIFT: i128 = 66;
+
+/// Computes the sine of `first` as a signed 64-bit fixed-point value with `second` fractional bits,
+/// storing the outcome in `destination`.
+pub type Fixed64Sin<N> = BinaryLiteral<N, Fixed64SinOperation<N>>;
+/// Computes the exponential of `first` as a signed 64-bit fixed-point value with `second` fractional bits,
+/// storing the outcome in `destination`.
+pub type Fixed64Exp<N> = BinaryLiteral<N, Fixed64ExpOperation<N>>;
+/// Computes the natural logarithm of `first` as a signed 64-bit fixed-point value with `second` fractional bits,
+/// storing the outcome in `destination`.
+pub type Fixed64Ln<N> = BinaryLiteral<N, Fixed64LnOperation<N>>;
+
+/// Returns the fractional bits if they are within the supported range.
+fn check_math_fractional_bits(opcode: Opcode, fractional_bits: u8) -> Result<u8> {
+    match fractional_bits <= FIXED64_MATH_PRECISION {
+        true => Ok(fractional_bits),
+        false => {
+            bail!("'{opcode}' supports at most {FIXED64_MATH_PRECISION} fractional bits, found {fractional_bits}")
+        }
+    }
+}
+
+/// Returns `first * second` with `FIXED64_MATH_PRECISION` fractional bits.
+fn mul(first: i128, second: i128) -> Result<i128> {
+    fixed_round_div(first * second, ONE)
+}
+
+/// Returns the given value with `fractional_bits` fractional bits, from `FIXED64_MATH_PRECISION` fractional bits.
+fn to_fixed64(value: i128, fractional_bits: u8, operation: &str) -> Result<i64> {
+    let output = fixed_round_div(value, 1i128 << (FIXED64_MATH_PRECISION - fractional_bits))?;
+    i64::try_from(output).map_err(|_| anyhow!("Fixed-point {operation} overflowed"))
+}
+
+/// Returns the sine of `input` in fixed-point with the given number of fractional bits.
+pub(crate) fn fixed_sin(input: i64, fractional_bits: u8) -> Result<i64> {
+    // Rescale the input to the working precision.
+    let input = i128::from(input) << (FIXED64_MATH_PRECISION - fractional_bits);
+    // Reduce the input into [-pi, pi].
+    let periods = fixed_round_div(input, 2 * PI)?;
+    let reduced = input - periods * 2 * PI;
+    // Reflect the reduced input into [-pi/2, pi/2], using sin(x) = sin(pi - x).
+    let reduced = match reduced > HALF_PI {
+        true => PI - reduced,
+        false => reduced,
+    };
+    let reduced = match reduced < -HALF_PI {
+        true => -PI - reduced,
+        false => reduced,
+    };
+    // Evaluate x * (1 - x^2/(2*3) * (1 - x^2/(4*5) * (1 - ...))) from the innermost term.
+    let square = mul(reduced, reduced)?;
+    let mut series = ONE;
+    for k in (1..=SIN_TERMS).rev() {
+        series = ONE - fixed_round_div(mul(square, series)?, (2 * k) * (2 * k + 1))?;
+    }
+    to_fixed64(mul(reduced, series)?, fractional_bits, "sine")
+}
+
+/// Returns the exponential of `input` in fixed-point with the given number of fractional bits.
+pub(crate) fn fixed_exp(input: i64, fractional_bits: u8) -> Result<i64> {
+    // Rescale the input to the working precision.
+    let input = i128::from(input) << (FIXED64_MATH_PRECISION - fractional_bits);
+    // Reduce the input into [-ln(2)/2, ln(2)/2], using exp(x) = 2^k * exp(x - k * ln(2)).
+    let exponent = fixed_round_div(input, LN_2)?;
+    let reduced = input - exponent * LN_2;
+    // Evaluate 1 + x * (1 + x/2 * (1 + x/3 * (1 + ...))) from the innermost term.
+    let mut series = ONE;
+    for n in (1..=EXP_TERMS).rev() {
+        series = ONE + fixed_round_div(mul(reduced, series)?, n)?;
+    }
+    // Scale the series by 2^k, as a right shift of the series scaled by 4.
+    let shift = 2 - (exponent + i128::from(fractional_bits) - i128::from(FIXED64_MATH_PRECISION));
+    ensure!(shift >= 0, "Fixed-point exponential overflowed");
+    let output = fixed_round_div(series * 4, 1i128 << shift.min(EXP_MAX_SHIFT))?;
+    i64::try_from(output).map_err(|_| anyhow!("Fixed-point exponential overflowed"))
+}
+
+/// Returns the natural logarithm of `input` in fixed-point with the given number of fractional bits.
+pub(crate) fn fixed_ln(input: i64, fractional_bits: u8) -> Result<i64> {
+    ensure!(input > 0, "Fixed-point logarithm is undefined for non-positive values");
+    // Normalize the input as m * 2^e, where m is in [1, 2) with the working precision.
+    let exponent = i128::from(63 - input.leading_zeros());
+    let mantissa = fixed_round_div(i128::from(input) << 62, 4i128 << exponent)?;
+    // Compute s = (m - 1) / (m + 1), which is in [0, 1/3].
+    let s = fixed_round_div((mantissa - ONE) << FIXED64_MATH_PRECISION, mantissa + ONE)?;
+    // Evaluate ln(m) = 2 * (s + s^3/3 + s^5/5 + ...).
+    let square = mul(s, s)?;
+    let (mut sum, mut power) = (0, s);
+    for k in 0..LN_TERMS {
+        sum += fixed_round_div(power, 2 * k + 1)?;
+        power = mul(power, square)?;
+    }
+    // Compute ln(m) + (e - f) * ln(2).
+    let output = 2 * sum + (exponent - i128::from(fractional_bits)) * LN_2;
+    to_fixed64(output, fractional_bits, "logarithm")
+}
+
+/// Returns a constant circuit integer for the given value.
+fn constant<A: circuit::Aleo>(value: i128) -> circuit::I128<A> {
+    use circuit::Inject;
+
+    circuit::I128::constant(console::types::I128::new(value))
+}
+
+/// Returns `first * second` in the circuit with `FIXED64_MATH_PRECISION` fractional bits.
+fn mul_circuit<A: circuit::Aleo>(first: &circuit::I128<A>, second: &circuit::I128<A>) -> circuit::I128<A> {
+    fixed_round_div_circuit(&(first * second), &constant(ONE))
+}
+
+/// Returns the given circuit value with `fractional_bits` fractional bits, from `FIXED64_MATH_PRECISION` fractional bits.
+fn to_fixed64_circuit<A: circuit::Aleo>(value: &circuit::I128<A>, fractional_bits: u8) -> circuit::I64<A> {
+    use circuit::Cast;
+
+    fixed_round_div_circuit(value, &constant(1i128 << (FIXED64_MATH_PRECISION - fractional_bits))).cast()
+}
+
+/// Returns the sine of `input` in the circuit with the given number of fractional bits.
+fn fixed_sin_circuit<A: circuit::Aleo>(input: &circuit::I64<A>, fractional_bits: u8) -> circuit::I64<A> {
+    use circuit::Cast;
+
+    // Rescale the input to the working precision.
+    let input: circuit::I128<A> = input.cast();
+    let input = input * constant(1i128 << (FIXED64_MATH_PRECISION - fractional_bits));
+    // Reduce the input into [-pi, pi].
+    let two_pi = constant(2 * PI);
+    let periods = fixed_round_div_circuit(&input, &two_pi);
+    let reduced = &input - &(&periods * &two_pi);
+    // Reflect the reduced input into [-pi/2, pi/2], using sin(x) = sin(pi - x).
+    let is_above = reduced.is_greater_than(&constant(HALF_PI));
+    let reduced = circuit::I128::ternary(&is_above, &(&constant(PI) - &reduced), &reduced);
+    let is_below = reduced.is_less_than(&constant(-HALF_PI));
+    let reduced = circuit::I128::ternary(&is_below, &(&constant(-PI) - &reduced), &reduced);
+    // Evaluate x * (1 - x^2/(2*3) * (1 - x^2/(4*5) * (1 - ...))) from the innermost term.
+    let square = mul_circuit(&reduced, &reduced);
+    let mut series = constant(ONE);
+    for k in (1..=SIN_TERMS).rev() {
+        let term = fixed_round_div_circuit(&mul_circuit(&square, &series), &constant((2 * k) * (2 * k + 1)));
+        series = constant(ONE) - term;
+    }
+    to_fixed64_circuit(&mul_circuit(&reduced, &series), fractional_bits)
+}
+
+/// Returns the exponential of `input` in the circuit with the given number of fractional bits.
+fn fixed_exp_circuit<A: circuit::Aleo>(input: &circuit::I64<A>, fractional_bits: u8) -> circuit::I64<A> {
+    use circuit::Cast;
+
+    // Rescale the input to the working precision.
+    let input: circuit::I128<A> = input.cast();
+    let input = input * constant(1i128 << (FIXED64_MATH_PRECISION - fractional_bits));
+    // Reduce the input into [-ln(2)/2, ln(2)/2], using exp(x) = 2^k * exp(x - k * ln(2)).
+    let exponent = fixed_round_div_circuit(&input, &constant(LN_2));
+    let reduced = &input - &(&exponent * &constant(LN_2));
+    // Evaluate 1 + x * (1 + x/2 * (1 + x/3 * (1 + ...))) from the innermost term.
+    let mut series = constant(ONE);
+    for n in (1..=EXP_TERMS).rev() {
+        series = constant(ONE) + fixed_round_div_circuit(&mul_circuit(&reduced, &series), &constant(n));
+    }
+    // Scale the series by 2^k, as a right shift of the series scaled by 4.
+    let shift = constant(2 - i128::from(fractional_bits) + i128::from(FIXED64_MATH_PRECISION)) - exponent;
+    let is_capped = shift.is_greater_than(&constant(EXP_MAX_SHIFT));
+    let shift = circuit::I128::ternary(&is_capped, &constant(EXP_MAX_SHIFT), &shift);
+    // Ensure the shift is non-negative, as the output overflows otherwise.
+    let shift: circuit::U8<A> = shift.cast();
+    let denominator = constant(1) << shift;
+    fixed_round_div_circuit(&(series * constant(4)), &denominator).cast()
+}
+
+/// Returns the natural logarithm of `input` in the circuit with the given number of fractional bits.
+fn fixed_ln_circuit<A: circuit::Aleo>(input: &circuit::I64<A>, fractional_bits: u8) -> circuit::I64<A> {
+    use circuit::{Cast, Eject, Inject};
+
+    // Witness the exponent e, such that 2^e <= input < 2^(e + 1).
+    let exponent = match *input.eject_value() {
+        value if value > 0 => u8::try_from(63 - value.leading_zeros()).unwrap_or_default(),
+        _ => 0,
+    };
+    let mode = if input.is_constant() { circuit::Mode::Constant } else { circuit::Mode::Private };
+    let exponent = circuit::U8::new(mode, console::types::U8::new(exponent));
+    // Enforce the bounds on the exponent, which also ensures the input is positive.
+    let input: circuit::I128<A> = input.cast();
+    let lower = constant(1) << exponent.clone();
+    A::assert(lower.is_less_than_or_equal(&input));
+    A::assert(input.is_less_than(&(&lower * &constant(2))));
+    // Normalize the input as m * 2^e, where m is in [1, 2) with the working precision.
+    let mantissa = fixed_round_div_circuit(&(&input * &constant(1i128 << 62)), &(&lower * &constant(4)));
+    // Compute s = (m - 1) / (m + 1), which is in [0, 1/3].
+    let numerator = (&mantissa - &constant(ONE)) * constant(ONE);
+    let s = fixed_round_div_circuit(&numerator, &(&mantissa + &constant(ONE)));
+    // Evaluate ln(m) = 2 * (s + s^3/3 + s^5/5 + ...).
+    let square = mul_circuit(&s, &s);
+    let (mut sum, mut power) = (constant(0), s);
+    for k in 0..LN_TERMS {
+        sum += fixed_round_div_circuit(&power, &constant(2 * k + 1));
+        power = mul_circuit(&power, &square);
+    }
+    // Compute ln(m) + (e - f) * ln(2).
+    let exponent: circuit::I128<A> = exponent.cast();
+    let exponent = exponent - constant(i128::from(fractional_bits));
+    let output = sum * constant(2) + exponent * constant(LN_2);
+    to_fixed64_circuit(&output, fractional_bits)
+}
+
+/// Implements a `fixed64` math operation, which takes the value and its number of fractional bits.
+macro_rules! fixed64_math_operation {
+    ($(#[$doc:meta])* $name:ident, $opcode:literal, $evaluate:ident, $execute:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        pub struct $name<N: Network>(PhantomData<N>);
+
+        impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for $name<N> {
+            /// The opcode of the operation.
+            const OPCODE: Opcode = Opcode::Literal($opcode);
+
+            /// Returns the result of evaluating the operation on the given inputs.
+            #[inline]
+            fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+                match inputs {
+                    [Literal::I64(first), Literal::U8(fractional_bits)] => {
+                        let fractional_bits = check_math_fractional_bits(Self::OPCODE, **fractional_bits)?;
+                        Ok(Literal::I64(I64::new($evaluate(**first, fractional_bits)?)))
+                    }
+                    _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+                }
+            }
+
+            /// Returns the result of executing the operation on the given circuit inputs.
+            #[inline]
+            fn execute<A: circuit::Aleo<Network = N>>(
+                inputs: &[circuit::Literal<A>; 2],
+            ) -> Result<circuit::Literal<A>> {
+                match inputs {
+                    [circuit::Literal::I64(first), second] => {
+                        let fractional_bits = fractional_bits_circuit(Self::OPCODE, second)?;
+                        let fractional_bits = check_math_fractional_bits(Self::OPCODE, fractional_bits)?;
+                        Ok(circuit::Literal::I64($execute(first, fractional_bits)))
+                    }
+                    _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+                }
+            }
+
+            /// Returns the output type from the given input types.
+            #[inline]
+            fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+                match inputs {
+                    [LiteralType::I64, LiteralType::U8] => Ok(LiteralType::I64),
+                    _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+                }
+            }
+        }
+    };
+}
+
+fixed64_math_operation!(
+    /// The implementation of the `fixed64.sin` operation.
+    Fixed64SinOperation,
+    "fixed64.sin",
+    fixed_sin,
+    fixed_sin_circuit
+);
+fixed64_math_operation!(
+    /// The implementation of the `fixed64.exp` operation.
+    Fixed64ExpOperation,
+    "fixed64.exp",
+    fixed_exp,
+    fixed_exp_circuit
+);
+fixed64_math_operation!(
+    /// The implementation of the `fixed64.ln` operation.
+    Fixed64LnOperation,
+    "fixed64.ln",
+    fixed_ln,
+    fixed_ln_circuit
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{Eject, Environment, Inject};
+    use console::{
+        network::Testnet3,
+        types::{I64, U8},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the fixed-point representation of `value` with 16 fractional bits.
+    /// The test values are small, so the representation always fits in an `i64`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn q16(value: f64) -> i64 {
+        (value * 65536.0).round() as i64
+    }
+
+    /// Evaluates and executes the operation, checking the outputs match, and returns the output.
+    fn check_binary<O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, 2>>(
+        input: i64,
+        fractional_bits: u8,
+        mode: circuit::Mode,
+    ) -> Result<Option<i64>> {
+        let inputs = [Literal::I64(I64::new(input)), Literal::U8(U8::new(fractional_bits))];
+        // Evaluate the operation.
+        let expected = match O::evaluate(&inputs) {
+            Ok(Literal::I64(output)) => Some(*output),
+            Ok(_) => bail!("Unexpected output type"),
+            Err(_) => None,
+        };
+        // Execute the operation.
+        let circuit_inputs = [
+            circuit::Literal::I64(circuit::I64::new(mode, I64::new(input))),
+            circuit::Literal::U8(circuit::U8::constant(U8::new(fractional_bits))),
+        ];
+        if let Some(expected) = expected {
+            match O::execute::<CurrentAleo>(&circuit_inputs)? {
+                circuit::Literal::I64(candidate) => assert_eq!(expected, *candidate.eject_value()),
+                _ => bail!("Unexpected output type"),
+            }
+            assert!(CurrentAleo::is_satisfied());
+        }
+        CurrentAleo::reset();
+        Ok(expected)
+    }
+
+    #[test]
+    fn test_fixed64_sin() -> Result<()> {
+        for mode in [circuit::Mode::Constant, circuit::Mode::Private] {
+            assert_eq!(Some(0), check_binary::<Fixed64SinOperation<_>>(0, 16, mode)?);
+            assert_eq!(Some(55147), check_binary::<Fixed64SinOperation<_>>(q16(1.0), 16, mode)?);
+            assert_eq!(Some(-31420), check_binary::<Fixed64SinOperation<_>>(q16(-0.5), 16, mode)?);
+            assert_eq!(Some(65536), check_binary::<Fixed64SinOperation<_>>(q16(core::f64::consts::FRAC_PI_2), 16, mode)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_exp() -> Result<()> {
+        for mode in [circuit::Mode::Constant, circuit::Mode::Private] {
+            assert_eq!(Some(65536), check_binary::<Fixed64ExpOperation<_>>(0, 16, mode)?);
+            assert_eq!(Some(178145), check_binary::<Fixed64ExpOperation<_>>(q16(1.0), 16, mode)?);
+            assert_eq!(Some(24109), check_binary::<Fixed64ExpOperation<_>>(q16(-1.0), 16, mode)?);
+            // Ensure a vanishing output rounds to zero.
+            assert_eq!(Some(0), check_binary::<Fixed64ExpOperation<_>>(q16(-100.0), 16, mode)?);
+        }
+        // Ensure overflow is detected.
+        assert_eq!(None, check_binary::<Fixed64ExpOperation<_>>(q16(64.0), 16, circuit::Mode::Private)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_ln() -> Result<()> {
+        for mode in [circuit::Mode::Constant, circuit::Mode::Private] {
+            assert_eq!(Some(0), check_binary::<Fixed64LnOperation<_>>(q16(1.0), 16, mode)?);
+            assert_eq!(Some(45426), check_binary::<Fixed64LnOperation<_>>(q16(2.0), 16, mode)?);
+            assert_eq!(Some(150902), check_binary::<Fixed64LnOperation<_>>(q16(10.0), 16, mode)?);
+            assert_eq!(Some(-726817), check_binary::<Fixed64LnOperation<_>>(1, 16, mode)?);
+        }
+        // Ensure non-positive inputs are rejected.
+        assert_eq!(None, check_binary::<Fixed64LnOperation<_>>(0, 16, circuit::Mode::Private)?);
+        assert_eq!(None, check_binary::<Fixed64LnOperation<_>>(-q16(1.0), 16, circuit::Mode::Private)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_math_random() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let fractional_bits = rng.gen_range(16..=48);
+            let one = 1i64 << fractional_bits;
+            let value = rng.gen_range(1..(8 * one));
+            let to_f64 = |output: Option<i64>| output.map(|output| output as f64 / one as f64).unwrap();
+            let tolerance = 1.0 / one as f64;
+
+            // Ensure the outputs are close to the floating-point reference.
+            let input = value as f64 / one as f64;
+            let sin = to_f64(check_binary::<Fixed64SinOperation<_>>(value, fractional_bits, circuit::Mode::Private)?);
+            assert!((sin - input.sin()).abs() < tolerance);
+            let exp = to_f64(check_binary::<Fixed64ExpOperation<_>>(-value, fractional_bits, circuit::Mode::Private)?);
+            assert!((exp - (-input).exp()).abs() < tolerance);
+            let ln = to_f64(check_binary::<Fixed64LnOperation<_>>(value, fractional_bits, circuit::Mode::Private)?);
+            assert!((ln - input.ln()).abs() < tolerance);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed64_math_rejects_invalid_fractional_bits() {
+        let inputs = [Literal::<CurrentNetwork>::I64(I64::new(1)), Literal::U8(U8::new(FIXED64_MATH_PRECISION + 1))];
+        assert!(Fixed64SinOperation::evaluate(&inputs).is_err());
+        assert!(Fixed64ExpOperation::evaluate(&inputs).is_err());
+        assert!(Fixed64LnOperation::evaluate(&inputs).is_err());
+    }
+}
@@ -57,11 +57,11 @@ pub type HashSha3_384<N> = HashInstruction<N, { Hasher::HashSha3_384 as u8 }>;
 /// SHA3-512 is a cryptographic hash function that outputs a 512-bit digest.
 pub type HashSha3_512<N> = HashInstruction<N, { Hasher::HashSha3_512 as u8 }>;
 
-/// Poseidon2 is a cryptographic hash function that processes inputs in 2-field chunks.
+/// Poseidon2 over many operands, which absorbs the field elements of each operand in order into one sponge.
 pub type HashManyPSD2<N> = HashInstruction<N, { Hasher::HashManyPSD2 as u8 }>;
-/// Poseidon4 is a cryptographic hash function that processes inputs in 4-field chunks.
+/// Poseidon4 over many operands, which absorbs the field elements of each operand in order into one sponge.
 pub type HashManyPSD4<N> = HashInstruction<N, { Hasher::HashManyPSD4 as u8 }>;
-/// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
+/// Poseidon8 over many operands, which absorbs the field elements of each operand in order into one sponge.
 pub type HashManyPSD8<N> = HashInstruction<N, { Hasher::HashManyPSD8 as u8 }>;
 
 enum Hasher {
@@ -85,20 +85,28 @@ enum Hasher {
     HashManyPSD8,
 }
 
-/// Returns the expected number of operands given the variant.
-const fn expected_num_operands(variant: u8) -> usize {
-    match variant {
-        15..=17 => 2,
-        _ => 1,
-    }
+/// Returns `true` if the variant hashes many operands.
+const fn is_hash_many(variant: u8) -> bool {
+    matches!(variant, 15..=17)
 }
 
 /// Returns 'Ok(())' if the number of operands is correct.
 /// Otherwise, returns an error.
-fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) -> Result<()> {
-    let expected = expected_num_operands(variant);
-    if expected != num_operands {
-        bail!("Instruction '{opcode}' expects {expected} operands, found {num_operands} operands")
+fn check_number_of_operands<N: Network>(variant: u8, opcode: Opcode, num_operands: usize) -> Result<()> {
+    match is_hash_many(variant) {
+        true => {
+            if !(2..=N::MAX_OPERANDS).contains(&num_operands) {
+                bail!(
+                    "Instruction '{opcode}' expects between 2 and {} operands, found {num_operands} operands",
+                    N::MAX_OPERANDS
+                )
+            }
+        }
+        false => {
+            if num_operands != 1 {
+                bail!("Instruction '{opcode}' expects 1 operands, found {num_operands} operands")
+            }
+        }
     }
     Ok(())
 }
@@ -134,7 +142,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         destination_type: PlaintextType<N>,
     ) -> Result<Self> {
         // Sanity check the number of operands.
-        check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
         if !is_valid_destination_type(&destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
@@ -174,7 +182,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn operands(&self) -> &[Operand<N>] {
         // Sanity check that the operands is the correct length.
         debug_assert!(
-            check_number_of_operands(VARIANT, Self::opcode(), self.operands.len()).is_ok(),
+            check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len()).is_ok(),
             "Invalid number of operands for '{}'",
             Self::opcode()
         );
@@ -204,12 +212,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
         ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
 
-        // Load the operand.
-        let input = registers.load(stack, &self.operands[0])?;
+        // Load the operands.
+        let inputs = self.operands.iter().map(|operand| registers.load(stack, operand)).collect::<Result<Vec<_>>>()?;
+        let input = &inputs[0];
+        // Returns the field elements of the operands, in order.
+        let many_fields =
+            || inputs.iter().map(|input| input.to_fields()).collect::<Result<Vec<_>>>().map(|f| f.concat());
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -248,9 +260,18 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&input.to_bits_le())?)?)
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
+            (15, PlaintextType::Literal(LiteralType::Address)) | (15, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_psd2(&many_fields()?)?)
+            }
+            (15, PlaintextType::Literal(..)) => Literal::Field(N::hash_psd2(&many_fields()?)?),
+            (16, PlaintextType::Literal(LiteralType::Address)) | (16, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_psd4(&many_fields()?)?)
+            }
+            (16, PlaintextType::Literal(..)) => Literal::Field(N::hash_psd4(&many_fields()?)?),
+            (17, PlaintextType::Literal(LiteralType::Address)) | (17, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_psd8(&many_fields()?)?)
+            }
+            (17, PlaintextType::Literal(..)) => Literal::Field(N::hash_psd8(&many_fields()?)?),
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
@@ -275,12 +296,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         use circuit::traits::{ToBits, ToFields};
 
         // Ensure the number of operands is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
         ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
 
-        // Load the operand.
-        let input = registers.load_circuit(stack, &self.operands[0])?;
+        // Load the operands.
+        let inputs =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).collect::<Result<Vec<_>>>()?;
+        let input = &inputs[0];
+        // Returns the field elements of the operands, in order.
+        let many_fields = || inputs.iter().flat_map(|input| input.to_fields()).collect::<Vec<_>>();
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -319,9 +344,18 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_512(&input.to_bits_le())))
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
+            (15, PlaintextType::Literal(LiteralType::Address)) | (15, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_psd2(&many_fields()))
+            }
+            (15, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_psd2(&many_fields())),
+            (16, PlaintextType::Literal(LiteralType::Address)) | (16, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_psd4(&many_fields()))
+            }
+            (16, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_psd4(&many_fields())),
+            (17, PlaintextType::Literal(LiteralType::Address)) | (17, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_psd8(&many_fields()))
+            }
+            (17, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_psd8(&many_fields())),
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
@@ -356,17 +390,16 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         input_types: &[RegisterType<N>],
     ) -> Result<Vec<RegisterType<N>>> {
         // Ensure the number of input types is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), input_types.len())?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), input_types.len())?;
         // Ensure the number of operands is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
         ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
-            15..=17 => bail!("'hash_many' is not yet implemented"),
+            0..=17 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
@@ -376,29 +409,27 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        /// Parse the operands from the string.
-        fn parse_operands<N: Network>(string: &str, num_operands: usize) -> ParserResult<Vec<Operand<N>>> {
-            let mut operands = Vec::with_capacity(num_operands);
-            let mut string = string;
-
-            for _ in 0..num_operands {
-                // Parse the whitespace from the string.
-                let (next_string, _) = Sanitizer::parse_whitespaces(string)?;
-                // Parse the operand from the string.
-                let (next_string, operand) = Operand::parse(next_string)?;
-                // Update the string.
-                string = next_string;
-                // Push the operand.
-                operands.push(operand);
-            }
-
-            Ok((string, operands))
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
         }
 
         // Parse the opcode from the string.
         let (string, _) = tag(*Self::opcode())(string)?;
         // Parse the operands from the string.
-        let (string, operands) = parse_operands(string, expected_num_operands(VARIANT))?;
+        let (string, operands) = match is_hash_many(VARIANT) {
+            true => map_res(many1(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+                // Ensure the number of operands is within the bounds.
+                match check_number_of_operands::<N>(VARIANT, Self::opcode(), operands.len()) {
+                    Ok(()) => Ok(operands),
+                    Err(e) => Err(error(format!("Failed to parse '{}': {e}", Self::opcode()))),
+                }
+            })(string)?,
+            false => map(parse_operand, |operand| vec![operand])(string)?,
+        };
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
@@ -456,7 +487,7 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
     /// Prints the operation to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Ensure the number of operands is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), self.operands.len()).map_err(|_| fmt::Error)?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len()).map_err(|_| fmt::Error)?;
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
@@ -468,7 +499,12 @@ impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Prepare the number of operands.
-        let num_operands = expected_num_operands(VARIANT);
+        let num_operands = match is_hash_many(VARIANT) {
+            true => u8::read_le(&mut reader)? as usize,
+            false => 1,
+        };
+        // Ensure the number of operands is correct.
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), num_operands).map_err(|e| error(format!("{e}")))?;
         // Read the operands.
         let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<_, _>>()?;
         // Read the destination register.
@@ -484,7 +520,11 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Ensure the number of operands is correct.
-        check_number_of_operands(VARIANT, Self::opcode(), self.operands.len()).map_err(|e| error(format!("{e}")))?;
+        check_number_of_operands::<N>(VARIANT, Self::opcode(), self.operands.len()).map_err(|e| error(format!("{e}")))?;
+        // Write the number of operands, if the variant hashes many operands.
+        if is_hash_many(VARIANT) {
+            u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        }
         // Write the operands.
         self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
         // Write the destination register.
@@ -533,4 +573,25 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_hash_many() {
+        let instruction = "hash_many.psd2 r0 r1 r2 into r3 as field";
+        let (string, hash) = HashManyPSD2::<CurrentNetwork>::parse(instruction).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(hash.operands[2], Operand::Register(Register::Locator(2)), "The last operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(hash.to_string(), instruction);
+
+        // Ensure the instruction round-trips through bytes.
+        let bytes = hash.to_bytes_le().unwrap();
+        assert_eq!(hash, HashManyPSD2::<CurrentNetwork>::read_le(&bytes[..]).unwrap());
+
+        // Ensure the number of operands is bounded.
+        assert!(HashManyPSD2::<CurrentNetwork>::parse("hash_many.psd2 r0 into r1 as field").is_err());
+        let operands = (0..=CurrentNetwork::MAX_OPERANDS).map(|i| format!("r{i}")).collect::<Vec<_>>().join(" ");
+        let instruction = format!("hash_many.psd2 {operands} into r100 as field");
+        assert!(HashManyPSD2::<CurrentNetwork>::parse(&instruction).is_err());
+    }
 }
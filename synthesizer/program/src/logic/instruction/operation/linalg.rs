@@ -0,0 +1,657 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    AddOperation,
+    MulOperation,
+    Opcode,
+    Operand,
+    Operation,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Computes the dot product of the vectors `first` and `second`, storing the outcome in `destination`.
+pub type Dot<N> = LinalgInstruction<N, { Variant::Dot as u8 }>;
+/// Multiplies the matrix `first` by the vector or matrix `second`, storing the outcome in `destination`.
+pub type MatMul<N> = LinalgInstruction<N, { Variant::MatMul as u8 }>;
+
+enum Variant {
+    Dot,
+    MatMul,
+}
+
+/// Computes a linear algebra operation on two array operands, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LinalgInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> LinalgInstruction<N, VARIANT> {
+    /// Initializes a new linear algebra instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Linalg("dot"),
+            1 => Opcode::Linalg("matmul"),
+            _ => panic!("Invalid linear algebra instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> LinalgInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let (input_a, input_b) =
+            match (registers.load(stack, &self.operands[0])?, registers.load(stack, &self.operands[1])?) {
+                (Value::Plaintext(input_a), Value::Plaintext(input_b)) => (input_a, input_b),
+                _ => bail!("Instruction '{}' expects plaintext array operands", Self::opcode()),
+            };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => Plaintext::from(dot_product(&to_vector(&input_a)?, &to_vector(&input_b)?)?),
+            1 => {
+                // Retrieve the rows of the first operand.
+                let rows = to_matrix(&input_a)?;
+                // Multiply each row by the second operand.
+                match is_matrix(&input_b) {
+                    // Compute the matrix-vector product.
+                    false => {
+                        let vector = to_vector(&input_b)?;
+                        let output = rows
+                            .iter()
+                            .map(|row| Ok(Plaintext::from(dot_product(row, &vector)?)))
+                            .collect::<Result<Vec<_>>>()?;
+                        Plaintext::Array(output, Default::default())
+                    }
+                    // Compute the matrix-matrix product.
+                    true => {
+                        let columns = transpose(Self::opcode(), to_matrix(&input_b)?)?;
+                        let output = rows
+                            .iter()
+                            .map(|row| {
+                                let row = columns
+                                    .iter()
+                                    .map(|column| Ok(Plaintext::from(dot_product(row, column)?)))
+                                    .collect::<Result<Vec<_>>>()?;
+                                Ok(Plaintext::Array(row, Default::default()))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        Plaintext::Array(output, Default::default())
+                    }
+                }
+            }
+            _ => bail!("Invalid linear algebra variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let (input_a, input_b) = match (
+            registers.load_circuit(stack, &self.operands[0])?,
+            registers.load_circuit(stack, &self.operands[1])?,
+        ) {
+            (circuit::Value::Plaintext(input_a), circuit::Value::Plaintext(input_b)) => (input_a, input_b),
+            _ => bail!("Instruction '{}' expects plaintext array operands", Self::opcode()),
+        };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => circuit::Plaintext::Literal(
+                dot_product_circuit(&to_vector_circuit(&input_a)?, &to_vector_circuit(&input_b)?)?,
+                Default::default(),
+            ),
+            1 => {
+                // Retrieve the rows of the first operand.
+                let rows = to_matrix_circuit(&input_a)?;
+                // Multiply each row by the second operand.
+                match is_matrix_circuit(&input_b) {
+                    // Compute the matrix-vector product.
+                    false => {
+                        let vector = to_vector_circuit(&input_b)?;
+                        let output = rows
+                            .iter()
+                            .map(|row| {
+                                Ok(circuit::Plaintext::Literal(dot_product_circuit(row, &vector)?, Default::default()))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        circuit::Plaintext::Array(output, Default::default())
+                    }
+                    // Compute the matrix-matrix product.
+                    true => {
+                        let columns = transpose(Self::opcode(), to_matrix_circuit(&input_b)?)?;
+                        let output = rows
+                            .iter()
+                            .map(|row| {
+                                let row = columns
+                                    .iter()
+                                    .map(|column| {
+                                        Ok(circuit::Plaintext::Literal(
+                                            dot_product_circuit(row, column)?,
+                                            Default::default(),
+                                        ))
+                                    })
+                                    .collect::<Result<Vec<_>>>()?;
+                                Ok(circuit::Plaintext::Array(row, Default::default()))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        circuit::Plaintext::Array(output, Default::default())
+                    }
+                }
+            }
+            _ => bail!("Invalid linear algebra variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the array types of the inputs.
+        let (first, second) = match (&input_types[0], &input_types[1]) {
+            (
+                RegisterType::Plaintext(PlaintextType::Array(first)),
+                RegisterType::Plaintext(PlaintextType::Array(second)),
+            ) => (first, second),
+            _ => bail!(
+                "Instruction '{}' expects array inputs. Found inputs of type '{}' and '{}'",
+                Self::opcode(),
+                input_types[0],
+                input_types[1]
+            ),
+        };
+
+        match VARIANT {
+            0 => {
+                // Ensure the operands are vectors of the same type.
+                let element_type = vector_element_type(Self::opcode(), first)?;
+                if first != second {
+                    bail!(
+                        "Instruction '{}' expects vectors of the same type. Found inputs of type '{first}' and '{second}'",
+                        Self::opcode()
+                    )
+                }
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(element_type))])
+            }
+            1 => {
+                // Ensure the first operand is a matrix.
+                let row_type = match first.next_element_type() {
+                    PlaintextType::Array(row_type) => row_type,
+                    _ => bail!("Instruction '{}' expects a matrix as the first input, found '{first}'", Self::opcode()),
+                };
+                let element_type = vector_element_type(Self::opcode(), row_type)?;
+                match second.next_element_type() {
+                    // Ensure the vector is as long as each row of the matrix.
+                    PlaintextType::Literal(..) => {
+                        if row_type != second {
+                            bail!(
+                                "Instruction '{}' cannot multiply a matrix of type '{first}' by a vector of type '{second}'",
+                                Self::opcode()
+                            )
+                        }
+                        let output_type = ArrayType::new(PlaintextType::Literal(element_type), vec![*first.length()])?;
+                        Ok(vec![RegisterType::Plaintext(PlaintextType::Array(output_type))])
+                    }
+                    // Ensure the number of columns in the first matrix matches the number of rows in the second matrix.
+                    PlaintextType::Array(column_type) => {
+                        if vector_element_type(Self::opcode(), column_type)? != element_type
+                            || row_type.length() != second.length()
+                        {
+                            bail!(
+                                "Instruction '{}' cannot multiply a matrix of type '{first}' by a matrix of type '{second}'",
+                                Self::opcode()
+                            )
+                        }
+                        let output_type = ArrayType::new(PlaintextType::Literal(element_type), vec![
+                            *first.length(),
+                            *column_type.length(),
+                        ])?;
+                        Ok(vec![RegisterType::Plaintext(PlaintextType::Array(output_type))])
+                    }
+                    PlaintextType::Struct(..) => bail!(
+                        "Instruction '{}' expects a vector or matrix as the second input, found '{second}'",
+                        Self::opcode()
+                    ),
+                }
+            }
+            _ => bail!("Invalid linear algebra variant: {VARIANT}"),
+        }
+    }
+}
+
+/// Returns the element type of the given vector type, ensuring it is a field or integer type.
+fn vector_element_type<N: Network>(opcode: Opcode, vector_type: &ArrayType<N>) -> Result<LiteralType> {
+    match vector_type.next_element_type() {
+        PlaintextType::Literal(
+            literal_type @ (LiteralType::Field
+            | LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128),
+        ) => Ok(*literal_type),
+        _ => bail!("Instruction '{opcode}' expects a vector of fields or integers, found '{vector_type}'"),
+    }
+}
+
+/// Returns `true` if the given plaintext is an array of arrays.
+fn is_matrix<N: Network>(plaintext: &Plaintext<N>) -> bool {
+    matches!(plaintext, Plaintext::Array(elements, _) if matches!(elements.first(), Some(Plaintext::Array(..))))
+}
+
+/// Returns the literals in the given one-dimensional array.
+fn to_vector<N: Network>(plaintext: &Plaintext<N>) -> Result<Vec<Literal<N>>> {
+    match plaintext {
+        Plaintext::Array(elements, _) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(literal, _) => Ok(literal.clone()),
+                _ => bail!("Expected a vector of literals, found '{plaintext}'"),
+            })
+            .collect(),
+        _ => bail!("Expected a vector of literals, found '{plaintext}'"),
+    }
+}
+
+/// Returns the rows of the given two-dimensional array.
+fn to_matrix<N: Network>(plaintext: &Plaintext<N>) -> Result<Vec<Vec<Literal<N>>>> {
+    match plaintext {
+        Plaintext::Array(rows, _) => rows.iter().map(to_vector).collect(),
+        _ => bail!("Expected a matrix of literals, found '{plaintext}'"),
+    }
+}
+
+/// Returns `true` if the given circuit plaintext is an array of arrays.
+fn is_matrix_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> bool {
+    matches!(plaintext, circuit::Plaintext::Array(elements, _) if matches!(elements.first(), Some(circuit::Plaintext::Array(..))))
+}
+
+/// Returns the circuit literals in the given one-dimensional array.
+fn to_vector_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> Result<Vec<circuit::Literal<A>>> {
+    match plaintext {
+        circuit::Plaintext::Array(elements, _) => elements
+            .iter()
+            .map(|element| match element {
+                circuit::Plaintext::Literal(literal, _) => Ok(literal.clone()),
+                _ => bail!("Expected a vector of literals"),
+            })
+            .collect(),
+        _ => bail!("Expected a vector of literals"),
+    }
+}
+
+/// Returns the rows of the given two-dimensional circuit array.
+fn to_matrix_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> Result<Vec<Vec<circuit::Literal<A>>>> {
+    match plaintext {
+        circuit::Plaintext::Array(rows, _) => rows.iter().map(to_vector_circuit).collect(),
+        _ => bail!("Expected a matrix of literals"),
+    }
+}
+
+/// Returns the columns of the given matrix.
+fn transpose<T: Clone>(opcode: Opcode, rows: Vec<Vec<T>>) -> Result<Vec<Vec<T>>> {
+    // Retrieve the number of columns.
+    let num_columns = rows.first().map(|row| row.len()).unwrap_or_default();
+    // Ensure the matrix is rectangular.
+    ensure!(rows.iter().all(|row| row.len() == num_columns), "Instruction '{opcode}' expects a rectangular matrix");
+    // Collect the columns.
+    Ok((0..num_columns).map(|j| rows.iter().map(|row| row[j].clone()).collect()).collect())
+}
+
+/// Returns the dot product of `first` and `second`, halting on overflow.
+fn dot_product<N: Network>(first: &[Literal<N>], second: &[Literal<N>]) -> Result<Literal<N>> {
+    // Ensure the vectors are non-empty and of the same length.
+    ensure!(!first.is_empty(), "Cannot compute the dot product of empty vectors");
+    ensure!(first.len() == second.len(), "Cannot compute the dot product of vectors of different lengths");
+
+    // Compute the products, and accumulate them with checked additions.
+    let mut products = first.iter().zip_eq(second).map(|(a, b)| MulOperation::<N>::evaluate(&[a.clone(), b.clone()]));
+    // Note: This unwrap is safe, as the vectors are non-empty.
+    let sum = products.next().unwrap()?;
+    products.try_fold(sum, |sum, product| AddOperation::<N>::evaluate(&[sum, product?]))
+}
+
+/// Returns the dot product of `first` and `second` in the circuit, halting on overflow.
+///
+/// For unsigned integers of at most 64 bits, the products are accumulated in the base field,
+/// and the sum is range-checked once, instead of performing a checked multiply-add per element.
+fn dot_product_circuit<A: circuit::Aleo>(
+    first: &[circuit::Literal<A>],
+    second: &[circuit::Literal<A>],
+) -> Result<circuit::Literal<A>> {
+    use circuit::traits::{FromField, ToField, Zero};
+
+    // Ensure the vectors are non-empty and of the same length.
+    ensure!(!first.is_empty(), "Cannot compute the dot product of empty vectors");
+    ensure!(first.len() == second.len(), "Cannot compute the dot product of vectors of different lengths");
+
+    /// Accumulates the products of the given unsigned integers in the base field.
+    macro_rules! field_dot_product {
+        ($variant:ident) => {{
+            let mut sum = circuit::Field::<A>::zero();
+            for (a, b) in first.iter().zip_eq(second) {
+                match (a, b) {
+                    (circuit::Literal::$variant(a), circuit::Literal::$variant(b)) => {
+                        sum += a.to_field() * b.to_field()
+                    }
+                    _ => bail!("Cannot compute the dot product of vectors of different types"),
+                }
+            }
+            // Note: Each product is less than 2^128 and there are at most `N::MAX_ARRAY_ELEMENTS` terms,
+            // so the sum does not wrap around the base field, and is range-checked by `from_field`.
+            circuit::Literal::$variant(circuit::$variant::from_field(sum))
+        }};
+    }
+
+    match first[0].to_type() {
+        LiteralType::U8 => Ok(field_dot_product!(U8)),
+        LiteralType::U16 => Ok(field_dot_product!(U16)),
+        LiteralType::U32 => Ok(field_dot_product!(U32)),
+        LiteralType::U64 => Ok(field_dot_product!(U64)),
+        _ => {
+            // Compute the products, and accumulate them with checked additions.
+            let mut products =
+                first.iter().zip_eq(second).map(|(a, b)| MulOperation::<A::Network>::execute(&[a.clone(), b.clone()]));
+            // Note: This unwrap is safe, as the vectors are non-empty.
+            let sum = products.next().unwrap()?;
+            products.try_fold(sum, |sum, product| AddOperation::<A::Network>::execute(&[sum, product?]))
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for LinalgInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for LinalgInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for LinalgInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for LinalgInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for LinalgInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for LinalgInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{AleoV0, Eject, Environment, Inject, Mode};
+    use console::{
+        network::Testnet3,
+        types::{Field, I8, U64, U8},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Checks that the circuit dot product matches the console dot product.
+    fn check_dot_product(first: Vec<Literal<CurrentNetwork>>, second: Vec<Literal<CurrentNetwork>>) -> Result<()> {
+        let expected = dot_product(&first, &second)?;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let first = first.iter().map(|literal| circuit::Literal::new(mode, literal.clone())).collect::<Vec<_>>();
+            let second = second.iter().map(|literal| circuit::Literal::new(mode, literal.clone())).collect::<Vec<_>>();
+            let candidate = dot_product_circuit::<CurrentAleo>(&first, &second)?;
+            assert_eq!(expected, candidate.eject_value());
+            assert!(CurrentAleo::is_satisfied());
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, dot) = Dot::<CurrentNetwork>::parse("dot r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(dot.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(dot.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(dot.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(dot.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, matmul) = MatMul::<CurrentNetwork>::parse("matmul r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(matmul.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(matmul.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(matmul.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(matmul.destination, Register::Locator(2), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_dot_product() -> Result<()> {
+        // Check field vectors.
+        let first = (1..=4).map(|i| Literal::Field(Field::from_u64(i))).collect::<Vec<_>>();
+        let second = (5..=8).map(|i| Literal::Field(Field::from_u64(i))).collect::<Vec<_>>();
+        assert_eq!(dot_product(&first, &second)?, Literal::Field(Field::from_u64(70)));
+        check_dot_product(first, second)?;
+
+        // Check unsigned integer vectors, which are accumulated in the base field.
+        let first = (1..=4).map(|i| Literal::U64(U64::new(i))).collect::<Vec<_>>();
+        let second = (5..=8).map(|i| Literal::U64(U64::new(i))).collect::<Vec<_>>();
+        assert_eq!(dot_product(&first, &second)?, Literal::U64(U64::new(70)));
+        check_dot_product(first, second)?;
+
+        // Check signed integer vectors.
+        let first = [3, -2, 1].into_iter().map(|i| Literal::I8(I8::new(i))).collect::<Vec<_>>();
+        let second = [4, 5, -6].into_iter().map(|i| Literal::I8(I8::new(i))).collect::<Vec<_>>();
+        assert_eq!(dot_product(&first, &second)?, Literal::I8(I8::new(-4)));
+        check_dot_product(first, second)
+    }
+
+    #[test]
+    #[should_panic(expected = "Integer addition failed on: 225u8 and 225u8")]
+    fn test_dot_product_overflow_halts() {
+        // Each product fits, but the sum overflows.
+        let first = vec![Literal::<CurrentNetwork>::U8(U8::new(15)); 2];
+        let second = vec![Literal::<CurrentNetwork>::U8(U8::new(15)); 2];
+        let _ = dot_product(&first, &second);
+    }
+
+    #[test]
+    fn test_dot_product_circuit_overflow_is_not_satisfied() {
+        // Each product fits, but the sum overflows.
+        let first = vec![Literal::<CurrentNetwork>::U8(U8::new(15)); 2];
+        let second = vec![Literal::<CurrentNetwork>::U8(U8::new(15)); 2];
+
+        let first =
+            first.iter().map(|literal| circuit::Literal::new(Mode::Private, literal.clone())).collect::<Vec<_>>();
+        let second =
+            second.iter().map(|literal| circuit::Literal::new(Mode::Private, literal.clone())).collect::<Vec<_>>();
+        let _candidate = dot_product_circuit::<CurrentAleo>(&first, &second).unwrap();
+        assert!(!CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+    }
+
+    #[test]
+    fn test_dot_product_rejects_mismatched_lengths() {
+        let first = vec![Literal::Field(Field::<CurrentNetwork>::from_u64(1)); 2];
+        let second = vec![Literal::Field(Field::<CurrentNetwork>::from_u64(1)); 3];
+        assert!(dot_product(&first, &second).is_err());
+        assert!(dot_product::<CurrentNetwork>(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_transpose() -> Result<()> {
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(transpose(MatMul::<CurrentNetwork>::opcode(), rows)?, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert!(transpose(MatMul::<CurrentNetwork>::opcode(), vec![vec![1, 2], vec![3]]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BinaryLiteral, Opcode, Operation, TernaryLiteral};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+};
+
+use core::marker::PhantomData;
+
+/// Computes the minimum of `first` and `second`, storing the outcome in `destination`.
+pub type Min<N> = BinaryLiteral<N, MinOperation<N>>;
+/// Computes the maximum of `first` and `second`, storing the outcome in `destination`.
+pub type Max<N> = BinaryLiteral<N, MaxOperation<N>>;
+/// Clamps `first` to the range from `second` to `third`, storing the outcome in `destination`.
+/// If `second` is greater than `third`, the outcome is `third`.
+pub type Clamp<N> = TernaryLiteral<N, ClampOperation<N>>;
+
+/// Applies the given operation to literals of the same ordered type, i.e. a field, integer, or scalar.
+macro_rules! ordered {
+    (@binary $literal:ident, $opcode:expr, ($first:expr, $second:expr), |$a:ident, $b:ident| $operation:expr, [$($variant:ident),+]) => {
+        match ($first, $second) {
+            $(($literal::$variant($a), $literal::$variant($b)) => Ok($literal::$variant($operation)),)+
+            _ => bail!("Invalid operands for the '{}' instruction", $opcode),
+        }
+    };
+    (@ternary $literal:ident, $opcode:expr, ($first:expr, $second:expr, $third:expr), |$a:ident, $b:ident, $c:ident| $operation:expr, [$($variant:ident),+]) => {
+        match ($first, $second, $third) {
+            $(($literal::$variant($a), $literal::$variant($b), $literal::$variant($c)) => Ok($literal::$variant($operation)),)+
+            _ => bail!("Invalid operands for the '{}' instruction", $opcode),
+        }
+    };
+    ($literal:ident, $opcode:expr, ($first:expr, $second:expr), |$a:ident, $b:ident| $operation:expr) => {
+        ordered!(@binary $literal, $opcode, ($first, $second), |$a, $b| $operation,
+            [Field, I8, I16, I32, I64, I128, U8, U16, U32, U64, U128, Scalar])
+    };
+    ($literal:ident, $opcode:expr, ($first:expr, $second:expr, $third:expr), |$a:ident, $b:ident, $c:ident| $operation:expr) => {
+        ordered!(@ternary $literal, $opcode, ($first, $second, $third), |$a, $b, $c| $operation,
+            [Field, I8, I16, I32, I64, I128, U8, U16, U32, U64, U128, Scalar])
+    };
+}
+
+/// Returns `true` if the given literal type is ordered, i.e. a field, integer, or scalar.
+fn is_ordered(literal_type: &LiteralType) -> bool {
+    use LiteralType::*;
+    matches!(literal_type, Field | I8 | I16 | I32 | I64 | I128 | U8 | U16 | U32 | U64 | U128 | Scalar)
+}
+
+/// The implementation of the `min` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MinOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for MinOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("min");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        let [first, second] = inputs;
+        ordered!(Literal, Self::OPCODE, (first, second), |a, b| Ternary::ternary(&a.is_less_than(b), a, b))
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        use circuit::Literal as CircuitLiteral;
+
+        let [first, second] = inputs;
+        ordered!(CircuitLiteral, Self::OPCODE, (first, second), |a, b| Ternary::ternary(&a.is_less_than(b), a, b))
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [first, second] if first == second && is_ordered(first) => Ok(*first),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `max` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MaxOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for MaxOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("max");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        let [first, second] = inputs;
+        ordered!(Literal, Self::OPCODE, (first, second), |a, b| Ternary::ternary(&a.is_less_than(b), b, a))
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        use circuit::Literal as CircuitLiteral;
+
+        let [first, second] = inputs;
+        ordered!(CircuitLiteral, Self::OPCODE, (first, second), |a, b| Ternary::ternary(&a.is_less_than(b), b, a))
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [first, second] if first == second && is_ordered(first) => Ok(*first),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `clamp` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ClampOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 3> for ClampOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("clamp");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 3]) -> Result<Literal<N>> {
+        let [first, second, third] = inputs;
+        ordered!(Literal, Self::OPCODE, (first, second, third), |a, b, c| {
+            // Compute the maximum of the value and the lower bound, and then the minimum with the upper bound.
+            let lower = Ternary::ternary(&a.is_less_than(b), b, a);
+            Ternary::ternary(&lower.is_less_than(c), &lower, c)
+        })
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 3]) -> Result<circuit::Literal<A>> {
+        use circuit::Literal as CircuitLiteral;
+
+        let [first, second, third] = inputs;
+        ordered!(CircuitLiteral, Self::OPCODE, (first, second, third), |a, b, c| {
+            // Compute the maximum of the value and the lower bound, and then the minimum with the upper bound.
+            let lower = Ternary::ternary(&a.is_less_than(b), b, a);
+            Ternary::ternary(&lower.is_less_than(c), &lower, c)
+        })
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 3]) -> Result<LiteralType> {
+        match inputs {
+            [first, second, third] if first == second && first == third && is_ordered(first) => Ok(*first),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{Eject, Environment, Inject};
+    use console::{
+        network::Testnet3,
+        types::{Field, I128, I8, U8},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Checks the operation evaluates and executes to the expected output in every mode.
+    fn check_operation<
+        O: Operation<CurrentNetwork, Literal<CurrentNetwork>, LiteralType, NUM_OPERANDS>,
+        const NUM_OPERANDS: usize,
+    >(
+        inputs: [Literal<CurrentNetwork>; NUM_OPERANDS],
+        expected: Literal<CurrentNetwork>,
+    ) -> Result<()> {
+        // Check the output type.
+        assert_eq!(expected.to_type(), O::output_type(&inputs.clone().map(|input| input.to_type()))?);
+        // Evaluate the operation.
+        assert_eq!(expected, O::evaluate(&inputs)?);
+        // Execute the operation.
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let circuit_inputs = inputs.clone().map(|input| circuit::Literal::<CurrentAleo>::new(mode, input));
+            assert_eq!(expected, O::execute(&circuit_inputs)?.eject_value());
+            assert!(CurrentAleo::is_satisfied());
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_max() -> Result<()> {
+        let (a, b) = (Literal::I8(I8::new(-5)), Literal::I8(I8::new(3)));
+        check_operation::<MinOperation<_>, 2>([a.clone(), b.clone()], a.clone())?;
+        check_operation::<MinOperation<_>, 2>([b.clone(), a.clone()], a.clone())?;
+        check_operation::<MaxOperation<_>, 2>([a.clone(), b.clone()], b.clone())?;
+        check_operation::<MaxOperation<_>, 2>([b.clone(), a.clone()], b.clone())?;
+
+        // Ensure the signed extremes are ordered correctly.
+        let (a, b) = (Literal::I128(I128::new(i128::MIN)), Literal::I128(I128::new(i128::MAX)));
+        check_operation::<MinOperation<_>, 2>([b.clone(), a.clone()], a.clone())?;
+        check_operation::<MaxOperation<_>, 2>([a, b.clone()], b)?;
+
+        let (a, b) = (Literal::Field(Field::from_u8(7)), Literal::Field(-Field::<CurrentNetwork>::one()));
+        check_operation::<MinOperation<_>, 2>([a.clone(), b.clone()], a.clone())?;
+        check_operation::<MaxOperation<_>, 2>([a, b.clone()], b)
+    }
+
+    #[test]
+    fn test_clamp() -> Result<()> {
+        let literal = |value: i8| Literal::<CurrentNetwork>::I8(I8::new(value));
+        check_operation::<ClampOperation<_>, 3>([literal(-20), literal(-10), literal(10)], literal(-10))?;
+        check_operation::<ClampOperation<_>, 3>([literal(5), literal(-10), literal(10)], literal(5))?;
+        check_operation::<ClampOperation<_>, 3>([literal(20), literal(-10), literal(10)], literal(10))?;
+        // Ensure an empty range yields the upper bound.
+        check_operation::<ClampOperation<_>, 3>([literal(0), literal(10), literal(-10)], literal(-10))
+    }
+
+    #[test]
+    fn test_invalid_operands() {
+        let inputs = [Literal::<CurrentNetwork>::I8(I8::new(1)), Literal::U8(U8::new(1))];
+        assert!(MinOperation::evaluate(&inputs).is_err());
+        assert!(MaxOperation::<CurrentNetwork>::output_type(&[LiteralType::I8, LiteralType::U8]).is_err());
+        assert!(MaxOperation::<CurrentNetwork>::output_type(&[LiteralType::Boolean, LiteralType::Boolean]).is_err());
+        assert!(
+            ClampOperation::<CurrentNetwork>::output_type(&[
+                LiteralType::Group,
+                LiteralType::Group,
+                LiteralType::Group
+            ])
+            .is_err()
+        );
+    }
+}
@@ -27,20 +27,41 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod commit_reveal;
+pub use commit_reveal::*;
+
+mod commit_verify;
+pub use commit_verify::*;
+
+mod fixed_point;
+pub use fixed_point::*;
+
+mod fixed_point_math;
+pub use fixed_point_math::*;
+
 mod hash;
 pub use hash::*;
 
 mod is;
 pub use is::*;
 
+mod linalg;
+pub use linalg::*;
+
 mod literals;
 pub use literals::*;
 
 mod macros;
 
+mod min_max;
+pub use min_max::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod vrf_verify;
+pub use vrf_verify::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
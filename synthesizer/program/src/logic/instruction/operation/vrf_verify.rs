@@ -0,0 +1,324 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::prelude::ToFields as CircuitToFields;
+use console::{
+    account::Signature,
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType, ToFields as ConsoleToFields},
+};
+
+/// Verifies a VRF proof `(proof, gamma)` for the given `address` and `input`, storing the VRF output in `destination`.
+/// The instruction halts if the proof is invalid.
+///
+/// The proof and `gamma` are produced off-chain with `Signature::prove_vrf`, which outputs the same field element.
+/// As the output is unique for a given address and input, it is suitable for leader election and fair randomness.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VRFVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> VRFVerify<N> {
+    /// Initializes a new `vrf.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::VRF
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly four operands.
+        debug_assert!(self.operands.len() == 4, "Instruction '{}' must have four operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> VRFVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let proof = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Signature(proof) => proof,
+            _ => bail!("Expected the first operand to be a signature."),
+        };
+        let gamma = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Group(gamma) => gamma,
+            _ => bail!("Expected the second operand to be a group."),
+        };
+        let address = match registers.load_literal(stack, &self.operands[2])? {
+            Literal::Address(address) => address,
+            _ => bail!("Expected the third operand to be an address."),
+        };
+        let input = registers.load(stack, &self.operands[3])?;
+
+        // Verify the VRF proof.
+        if !proof.verify_vrf(&gamma, &address, &input.to_fields()?) {
+            bail!("Failed to verify the VRF proof for '{address}'")
+        }
+        // Compute the VRF output.
+        let output = Literal::Field(Signature::vrf_output(&gamma)?);
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 4 {
+            bail!("Instruction '{}' expects 4 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let proof = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Signature(proof) => proof,
+            _ => bail!("Expected the first operand to be a signature."),
+        };
+        let gamma = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Group(gamma) => gamma,
+            _ => bail!("Expected the second operand to be a group."),
+        };
+        let address = match registers.load_literal_circuit(stack, &self.operands[2])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Expected the third operand to be an address."),
+        };
+        let input = registers.load_circuit(stack, &self.operands[3])?;
+
+        // Enforce the VRF proof is valid.
+        A::assert(proof.verify_vrf(&gamma, &address, &input.to_fields()));
+        // Compute the VRF output.
+        let output = circuit::Literal::Field(circuit::Signature::vrf_output(&gamma));
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 4 {
+            bail!("Instruction '{}' expects 4 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'signature'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a group.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group)) {
+            bail!(
+                "Instruction '{}' expects the second input to be a 'group'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is an address.
+        if input_types[2] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects the third input to be an 'address'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+    }
+}
+
+impl<N: Network> Parser for VRFVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the fourth operand from the string.
+        let (string, fourth) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third, fourth], destination }))
+    }
+}
+
+impl<N: Network> FromStr for VRFVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for VRFVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for VRFVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for VRFVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(4);
+        // Read the operands.
+        for _ in 0..4 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for VRFVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 4.
+        if self.operands.len() != 4 {
+            return Err(error(format!("The number of operands must be 4, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = VRFVerify::<CurrentNetwork>::parse("vrf.verify r0 r1 r2 r3 into r4").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 4, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.operands[3], Operand::Register(Register::Locator(3)), "The fourth operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(4), "The destination register is incorrect");
+        assert_eq!(is.to_string(), "vrf.verify r0 r1 r2 r3 into r4");
+    }
+}
@@ -23,3 +23,6 @@ pub use finalize_operation::*;
 
 pub mod instruction;
 pub use instruction::*;
+
+mod state_diff;
+pub use state_diff::*;
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod recorder;
+mod serialize;
+mod string;
+
+pub use recorder::*;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// A change to a single key in a program mapping, as produced by finalize.
+///
+/// Unlike a `FinalizeOperation`, which only commits to the hashes of the key and value,
+/// a state diff carries the key and the values in the clear, so it can be replayed or inspected.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StateDiff<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The mapping name.
+    mapping_name: Identifier<N>,
+    /// The key.
+    key: Plaintext<N>,
+    /// The value before the change, if the key existed.
+    old_value: Option<Value<N>>,
+    /// The value after the change, if the key still exists.
+    new_value: Option<Value<N>>,
+}
+
+impl<N: Network> StateDiff<N> {
+    /// Initializes a new state diff.
+    pub const fn new(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        old_value: Option<Value<N>>,
+        new_value: Option<Value<N>>,
+    ) -> Self {
+        Self { program_id, mapping_name, key, old_value, new_value }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the mapping name.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping_name
+    }
+
+    /// Returns the key.
+    pub const fn key(&self) -> &Plaintext<N> {
+        &self.key
+    }
+
+    /// Returns the value before the change, if the key existed.
+    pub const fn old_value(&self) -> &Option<Value<N>> {
+        &self.old_value
+    }
+
+    /// Returns the value after the change, if the key still exists.
+    pub const fn new_value(&self) -> &Option<Value<N>> {
+        &self.new_value
+    }
+
+    /// Returns `true` if the key did not exist before the change.
+    pub const fn is_insert(&self) -> bool {
+        self.old_value.is_none() && self.new_value.is_some()
+    }
+
+    /// Returns `true` if the key no longer exists after the change.
+    pub const fn is_remove(&self) -> bool {
+        self.old_value.is_some() && self.new_value.is_none()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of state diffs, covering an insert, an update, and a remove.
+    pub(crate) fn sample_state_diffs() -> Vec<StateDiff<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("testing.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = |string: &str| Plaintext::from_str(string).unwrap();
+        let value = |string: &str| Some(Value::from_str(string).unwrap());
+
+        vec![
+            StateDiff::new(program_id, mapping_name, key("0u8"), None, value("1u64")),
+            StateDiff::new(program_id, mapping_name, key("1u8"), value("1u64"), value("2u64")),
+            StateDiff::new(program_id, mapping_name, key("2u8"), value("{ a: 2u64, b: true }"), None),
+        ]
+    }
+}
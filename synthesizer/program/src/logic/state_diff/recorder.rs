@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{FinalizeOperation, FinalizeStoreTrait};
+
+use core::cell::RefCell;
+use indexmap::{map::Entry, IndexMap};
+
+/// The index of a recorded state diff, as the program ID, mapping name, and key bytes.
+type StateDiffKey<N> = (ProgramID<N>, Identifier<N>, Vec<u8>);
+
+/// A finalize store that records a `StateDiff` for each key written through it,
+/// while forwarding every call to the underlying store.
+///
+/// Repeated writes to the same key are merged, so each key appears at most once,
+/// with its value from before the first write and after the last write.
+pub struct StateDiffRecorder<'a, N: Network, S: FinalizeStoreTrait<N>> {
+    /// The underlying store.
+    store: &'a S,
+    /// The recorded state diffs, indexed by program ID, mapping name, and key bytes,
+    /// in the order the keys were first written.
+    state_diffs: RefCell<IndexMap<StateDiffKey<N>, StateDiff<N>>>,
+}
+
+impl<'a, N: Network, S: FinalizeStoreTrait<N>> StateDiffRecorder<'a, N, S> {
+    /// Initializes a new recorder over the given store.
+    pub fn new(store: &'a S) -> Self {
+        Self { store, state_diffs: Default::default() }
+    }
+
+    /// Returns the recorded state diffs.
+    pub fn into_state_diffs(self) -> Vec<StateDiff<N>> {
+        self.state_diffs.into_inner().into_values().collect()
+    }
+
+    /// Records the change of the given key from `old_value` to `new_value`.
+    fn record(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        old_value: Option<Value<N>>,
+        new_value: Option<Value<N>>,
+    ) -> Result<()> {
+        // Note: The key is indexed by its bytes, as a plaintext is not hashable.
+        let index: StateDiffKey<N> = (program_id, mapping_name, key.to_bytes_le()?);
+        // If the key was already written, keep its original value and update its new value.
+        match self.state_diffs.borrow_mut().entry(index) {
+            Entry::Occupied(mut entry) => entry.get_mut().new_value = new_value,
+            Entry::Vacant(entry) => {
+                entry.insert(StateDiff::new(program_id, mapping_name, key.clone(), old_value, new_value));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, N: Network, S: FinalizeStoreTrait<N>> FinalizeStoreTrait<N> for StateDiffRecorder<'a, N, S> {
+    /// Returns `true` if the given `program ID` and `mapping name` exist.
+    fn contains_mapping_confirmed(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
+        self.store.contains_mapping_confirmed(program_id, mapping_name)
+    }
+
+    /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
+    fn contains_key_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<bool> {
+        self.store.contains_key_speculative(program_id, mapping_name, key)
+    }
+
+    /// Returns the speculative value for the given `program ID`, `mapping name`, and `key`.
+    fn get_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.store.get_value_speculative(program_id, mapping_name, key)
+    }
+
+    /// Stores the given `(key, value)` pair, and records it as an insert.
+    fn insert_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        let operation = self.store.insert_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+        self.record(program_id, mapping_name, &key, None, Some(value))?;
+        Ok(operation)
+    }
+
+    /// Stores the given `(key, value)` pair, and records the change from the previous value.
+    fn update_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        let old_value = self.store.get_value_speculative(program_id, mapping_name, &key)?;
+        let operation = self.store.update_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+        self.record(program_id, mapping_name, &key, old_value, Some(value))?;
+        Ok(operation)
+    }
+
+    /// Removes the key-value pair, and records it as a removal if the key existed.
+    fn remove_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<FinalizeOperation<N>>> {
+        let old_value = self.store.get_value_speculative(program_id, mapping_name, key)?;
+        let operation = self.store.remove_key_value(program_id, mapping_name, key)?;
+        if operation.is_some() {
+            self.record(program_id, mapping_name, key, old_value, None)?;
+        }
+        Ok(operation)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for StateDiff<N> {
+    /// Serializes the state diff to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut state_diff = serializer.serialize_struct("StateDiff", 5)?;
+                state_diff.serialize_field("program_id", &self.program_id)?;
+                state_diff.serialize_field("mapping_name", &self.mapping_name)?;
+                state_diff.serialize_field("key", &self.key)?;
+                state_diff.serialize_field("old_value", &self.old_value)?;
+                state_diff.serialize_field("new_value", &self.new_value)?;
+                state_diff.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for StateDiff<N> {
+    /// Deserializes the state diff from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut state_diff = serde_json::Value::deserialize(deserializer)?;
+                // Recover the state diff.
+                Ok(Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut state_diff, "program_id")?,
+                    // Retrieve the mapping name.
+                    DeserializeExt::take_from_value::<D>(&mut state_diff, "mapping_name")?,
+                    // Retrieve the key.
+                    DeserializeExt::take_from_value::<D>(&mut state_diff, "key")?,
+                    // Retrieve the old value.
+                    DeserializeExt::take_from_value::<D>(&mut state_diff, "old_value")?,
+                    // Retrieve the new value.
+                    DeserializeExt::take_from_value::<D>(&mut state_diff, "new_value")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "state diff"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for expected in crate::logic::state_diff::test_helpers::sample_state_diffs() {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, &candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, StateDiff::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for expected in crate::logic::state_diff::test_helpers::sample_state_diffs() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, StateDiff::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ProgramMetadata<N> {
+    /// Reads the program metadata from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid program metadata version"));
+        }
+
+        // Reads an optional text entry.
+        let read_text = |reader: &mut R| -> IoResult<Option<String>> {
+            match u8::read_le(&mut *reader)? {
+                0 => Ok(None),
+                1 => {
+                    let length = u16::read_le(&mut *reader)?;
+                    if length as usize > Self::MAX_ENTRY_LENGTH {
+                        return Err(error("Program metadata entry exceeds the maximum length"));
+                    }
+                    let mut bytes = vec![0u8; length as usize];
+                    reader.read_exact(&mut bytes)?;
+                    String::from_utf8(bytes).map(Some).map_err(|e| error(e.to_string()))
+                }
+                variant => Err(error(format!("Invalid program metadata entry variant '{variant}'"))),
+            }
+        };
+
+        // Read the entries.
+        let author = read_text(&mut reader)?;
+        let metadata_version = read_text(&mut reader)?;
+        let license = read_text(&mut reader)?;
+        let abi_hash = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Field::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid program metadata entry variant '{variant}'"))),
+        };
+
+        Self::new(author, metadata_version, license, abi_hash).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ProgramMetadata<N> {
+    /// Writes the program metadata to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the text entries.
+        for text in [&self.author, &self.version, &self.license] {
+            match text {
+                None => 0u8.write_le(&mut writer)?,
+                Some(text) => {
+                    1u8.write_le(&mut writer)?;
+                    u16::try_from(text.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                    writer.write_all(text.as_bytes())?;
+                }
+            }
+        }
+        // Write the ABI hash.
+        match &self.abi_hash {
+            None => 0u8.write_le(&mut writer),
+            Some(abi_hash) => {
+                1u8.write_le(&mut writer)?;
+                abi_hash.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for expected in [
+            ProgramMetadata::<CurrentNetwork>::default(),
+            ProgramMetadata::from_str("metadata:\n    author \"Aleo\";\n    license \"Apache-2.0\";")?,
+            ProgramMetadata::from_str(
+                "metadata:\n    author \"Aleo\";\n    version \"1.0.0\";\n    license \"MIT\";\n    abi 7field;",
+            )?,
+        ] {
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ProgramMetadata::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{network::prelude::*, types::Field};
+
+/// The metadata of a program, declared in an optional block of the form:
+/// ```text
+/// metadata:
+///     author "{author}";
+///     version "{version}";
+///     license "{license}";
+///     abi {abi_hash};
+/// ```
+/// The metadata describes the provenance of a program, and does not affect its execution.
+/// It is hashed separately from the executable content of the program, see `Program::to_content_hash`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ProgramMetadata<N: Network> {
+    /// The author of the program.
+    author: Option<String>,
+    /// The version of the program.
+    version: Option<String>,
+    /// The license of the program.
+    license: Option<String>,
+    /// The declared hash of the program interface.
+    abi_hash: Option<Field<N>>,
+}
+
+impl<N: Network> Default for ProgramMetadata<N> {
+    /// Returns empty program metadata.
+    fn default() -> Self {
+        Self { author: None, version: None, license: None, abi_hash: None }
+    }
+}
+
+impl<N: Network> ProgramMetadata<N> {
+    /// The maximum number of bytes in a metadata entry.
+    pub const MAX_ENTRY_LENGTH: usize = 256;
+
+    /// Initializes new program metadata.
+    pub fn new(
+        author: Option<String>,
+        version: Option<String>,
+        license: Option<String>,
+        abi_hash: Option<Field<N>>,
+    ) -> Result<Self> {
+        // Ensure each text entry is valid.
+        for text in [&author, &version, &license].into_iter().flatten() {
+            Self::check_text(text)?;
+        }
+        Ok(Self { author, version, license, abi_hash })
+    }
+
+    /// Returns the author of the program, if declared.
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Returns the version of the program, if declared.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the license of the program, if declared.
+    pub fn license(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+
+    /// Returns the declared hash of the program interface, if declared.
+    pub const fn abi_hash(&self) -> Option<&Field<N>> {
+        self.abi_hash.as_ref()
+    }
+
+    /// Returns `true` if no metadata entry is declared.
+    pub const fn is_empty(&self) -> bool {
+        self.author.is_none() && self.version.is_none() && self.license.is_none() && self.abi_hash.is_none()
+    }
+
+    /// Returns the hash of the metadata.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Ensures the given text is a valid metadata entry.
+    fn check_text(text: &str) -> Result<()> {
+        // Ensure the text is within the maximum length.
+        ensure!(
+            text.len() <= Self::MAX_ENTRY_LENGTH,
+            "Metadata entry exceeds the maximum length of {} bytes",
+            Self::MAX_ENTRY_LENGTH
+        );
+        // Ensure the text only contains printable characters, excluding quotes and backslashes.
+        ensure!(
+            text.chars().all(|character| (' '..='~').contains(&character) && character != '"' && character != '\\'),
+            "Metadata entry '{text}' contains an invalid character"
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> TypeName for ProgramMetadata<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "metadata"
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The characters allowed in a metadata text entry.
+const TEXT_CHARACTERS: &str =
+    " !#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// A metadata entry.
+enum Entry<N: Network> {
+    Author(String),
+    Version(String),
+    License(String),
+    Abi(Field<N>),
+}
+
+impl<N: Network> Parser for ProgramMetadata<N> {
+    /// Parses a string into a metadata block.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parses a quoted text entry from the string.
+        fn parse_text(string: &str) -> ParserResult<String> {
+            // Parse the opening quote from the string.
+            let (string, _) = tag("\"")(string)?;
+            // Parse the text from the string.
+            let (string, text) = recognize(many0_count(one_of(TEXT_CHARACTERS)))(string)?;
+            // Parse the closing quote from the string.
+            let (string, _) = tag("\"")(string)?;
+            Ok((string, text.to_string()))
+        }
+
+        // Parses a metadata entry of the form `{keyword} {value};` from the string.
+        fn parse_entry<N: Network>(string: &str) -> ParserResult<Entry<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the keyword and value from the string.
+            let (string, entry) = alt((
+                map(pair(terminated(tag("author"), Sanitizer::parse_whitespaces), parse_text), |(_, text)| {
+                    Entry::Author(text)
+                }),
+                map(pair(terminated(tag("version"), Sanitizer::parse_whitespaces), parse_text), |(_, text)| {
+                    Entry::Version(text)
+                }),
+                map(pair(terminated(tag("license"), Sanitizer::parse_whitespaces), parse_text), |(_, text)| {
+                    Entry::License(text)
+                }),
+                map(pair(terminated(tag("abi"), Sanitizer::parse_whitespaces), Field::parse), |(_, abi_hash)| {
+                    Entry::Abi(abi_hash)
+                }),
+            ))(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon from the string.
+            let (string, _) = tag(";")(string)?;
+            Ok((string, entry))
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the metadata keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the entries from the string.
+        let (string, entries) = many1(parse_entry)(string)?;
+
+        // Return the metadata.
+        map_res(take(0usize), move |_| {
+            let mut metadata = Self::default();
+            // Insert each entry, ensuring it is declared at most once.
+            for entry in entries.iter() {
+                let is_new = match entry {
+                    Entry::Author(text) => metadata.author.replace(text.clone()).is_none(),
+                    Entry::Version(text) => metadata.version.replace(text.clone()).is_none(),
+                    Entry::License(text) => metadata.license.replace(text.clone()).is_none(),
+                    Entry::Abi(abi_hash) => metadata.abi_hash.replace(*abi_hash).is_none(),
+                };
+                ensure!(is_new, "Duplicate entry in the program metadata");
+            }
+            // Ensure the metadata is well-formed.
+            Self::new(metadata.author, metadata.version, metadata.license, metadata.abi_hash)
+        })(string)
+    }
+}
+
+impl<N: Network> FromStr for ProgramMetadata<N> {
+    type Err = Error;
+
+    /// Parses a string into a metadata block.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ProgramMetadata<N> {
+    /// Prints the metadata as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProgramMetadata<N> {
+    /// Prints the metadata block as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:", Self::type_name())?;
+        if let Some(author) = &self.author {
+            write!(f, "\n    author \"{author}\";")?;
+        }
+        if let Some(version) = &self.version {
+            write!(f, "\n    version \"{version}\";")?;
+        }
+        if let Some(license) = &self.license {
+            write!(f, "\n    license \"{license}\";")?;
+        }
+        if let Some(abi_hash) = &self.abi_hash {
+            write!(f, "\n    abi {abi_hash};")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_metadata_parse() -> Result<()> {
+        let (string, metadata) = ProgramMetadata::<CurrentNetwork>::parse(
+            "metadata:\n    license \"Apache-2.0\";\n    author \"Aleo Systems\";\n    abi 7field;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(metadata.author(), Some("Aleo Systems"));
+        assert_eq!(metadata.version(), None);
+        assert_eq!(metadata.license(), Some("Apache-2.0"));
+        assert_eq!(metadata.abi_hash(), Some(&Field::from_u64(7)));
+
+        // Ensure a duplicate entry fails to parse.
+        assert!(ProgramMetadata::<CurrentNetwork>::parse("metadata:\n    author \"a\";\n    author \"b\";").is_err());
+        // Ensure an empty block fails to parse.
+        assert!(ProgramMetadata::<CurrentNetwork>::from_str("metadata:").is_err());
+        // Ensure an unterminated or escaped text entry fails to parse.
+        assert!(ProgramMetadata::<CurrentNetwork>::from_str("metadata:\n    author \"Aleo;").is_err());
+        assert!(ProgramMetadata::<CurrentNetwork>::from_str("metadata:\n    author \"Al\\\"eo\";").is_err());
+        // Ensure an overly long text entry fails to parse.
+        let author = "a".repeat(ProgramMetadata::<CurrentNetwork>::MAX_ENTRY_LENGTH + 1);
+        assert!(ProgramMetadata::<CurrentNetwork>::from_str(&format!("metadata:\n    author \"{author}\";")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata_display() -> Result<()> {
+        let expected = r#"metadata:
+    author "Aleo Systems";
+    version "0.1.0";
+    license "MIT";
+    abi 7field;"#;
+        let metadata = ProgramMetadata::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(expected, metadata.to_string());
+
+        Ok(())
+    }
+}
@@ -27,6 +27,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
+            X(Restriction<N>),
         }
 
         // Parse the imports from the string.
@@ -43,6 +44,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Parse the metadata (if any) from the string.
+        let (string, metadata) = opt(ProgramMetadata::parse)(string)?;
 
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
@@ -51,6 +54,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
             map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
+            map(Restriction::parse, |restriction| P::<N, Instruction, Command>::X(restriction)),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -73,6 +77,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
+                    P::X(restriction) => program.add_restriction(restriction.clone()),
                 };
 
                 match result {
@@ -83,6 +88,13 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
+            // Set the metadata (if any), now that the program interface is complete.
+            if let Some(metadata) = &metadata {
+                if let Err(error) = program.set_metadata(metadata.clone()) {
+                    eprintln!("{error}");
+                    return Err(error);
+                }
+            }
             // Lastly, add the imports (if any) to the program.
             for import in imports.iter() {
                 match program.add_import(import.clone()) {
@@ -149,6 +161,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         // Print the program name.
         program += &format!("{} {};\n\n", Self::type_name(), self.id);
 
+        // Print the metadata, if any.
+        if let Some(metadata) = &self.metadata {
+            program.push_str(&format!("{metadata}\n\n"));
+        }
+
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
                 ProgramDefinition::Mapping => match self.mappings.get(identifier) {
@@ -168,7 +185,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     None => return Err(fmt::Error),
                 },
                 ProgramDefinition::Function => match self.functions.get(identifier) {
-                    Some(function) => program.push_str(&format!("{function}\n\n")),
+                    Some(function) => {
+                        program.push_str(&format!("{function}\n\n"));
+                        // Print the restrictions of the function, if any.
+                        if let Some(callers) = self.restrictions.get(identifier) {
+                            for caller in callers {
+                                program.push_str(&format!("{}\n", Restriction::new(*identifier, *caller)));
+                            }
+                            program.push('\n');
+                        }
+                    }
                     None => return Err(fmt::Error),
                 },
             }
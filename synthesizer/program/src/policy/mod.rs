@@ -0,0 +1,405 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CallOperator, Instruction, Opcode, Program};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+use indexmap::IndexSet;
+
+/// A program policy restricts the opcodes that a program may use, so that an embedder can
+/// reject programs that rely on features it does not support (e.g. randomness or cross-program calls).
+/// The default policy allows every opcode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramPolicy {
+    /// The disallowed opcodes, e.g. `hash.bhp256`.
+    disallowed_opcodes: IndexSet<String>,
+    /// The disallowed opcode families, e.g. `rand` for `rand.chacha`.
+    disallowed_families: IndexSet<String>,
+    /// If `true`, calls to functions or closures of other programs are disallowed.
+    disallow_external_calls: bool,
+}
+
+impl ProgramPolicy {
+    /// Initializes a new policy that allows every opcode.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disallows the given opcode, e.g. `hash.bhp256`.
+    #[inline]
+    pub fn disallow_opcode(mut self, opcode: impl Into<String>) -> Self {
+        self.disallowed_opcodes.insert(opcode.into());
+        self
+    }
+
+    /// Disallows the given opcode family, i.e. the opcode `{family}` and every opcode of the form `{family}.*`.
+    #[inline]
+    pub fn disallow_family(mut self, family: impl Into<String>) -> Self {
+        self.disallowed_families.insert(family.into());
+        self
+    }
+
+    /// Disallows calls to functions or closures of other programs.
+    #[inline]
+    pub fn disallow_external_calls(mut self) -> Self {
+        self.disallow_external_calls = true;
+        self
+    }
+
+    /// Returns the disallowed opcodes.
+    #[inline]
+    pub const fn disallowed_opcodes(&self) -> &IndexSet<String> {
+        &self.disallowed_opcodes
+    }
+
+    /// Returns the disallowed opcode families.
+    #[inline]
+    pub const fn disallowed_families(&self) -> &IndexSet<String> {
+        &self.disallowed_families
+    }
+
+    /// Returns `true` if calls to other programs are disallowed.
+    #[inline]
+    pub const fn disallows_external_calls(&self) -> bool {
+        self.disallow_external_calls
+    }
+
+    /// Returns `true` if the policy allows every program.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.disallowed_opcodes.is_empty() && self.disallowed_families.is_empty() && !self.disallow_external_calls
+    }
+
+    /// Returns the rule that disallows the given opcode, if any.
+    pub fn check_opcode(&self, opcode: Opcode) -> Option<PolicyRule> {
+        // Check the opcode itself.
+        if self.disallowed_opcodes.contains(*opcode) {
+            return Some(PolicyRule::Opcode(opcode.to_string()));
+        }
+        // Check the families of the opcode.
+        self.disallowed_families.iter().find_map(|family| match opcode.strip_prefix(family.as_str()) {
+            Some(rest) if rest.is_empty() || rest.starts_with('.') => Some(PolicyRule::Family(family.clone())),
+            _ => None,
+        })
+    }
+
+    /// Returns the policy violations of the given program, in the order they appear in the program.
+    pub fn violations<N: Network>(&self, program: &Program<N>) -> Vec<PolicyViolation<N>> {
+        let mut violations = Vec::new();
+        // If the policy is empty, return early.
+        if self.is_empty() {
+            return violations;
+        }
+        // Retrieve the program ID.
+        let program_id = *program.id();
+
+        // Check the closures.
+        for (name, closure) in program.closures() {
+            for (index, instruction) in closure.instructions().iter().enumerate() {
+                if let Some(rule) = self.check_instruction(&program_id, instruction) {
+                    let opcode = instruction.opcode();
+                    violations.push(PolicyViolation::new(program_id, PolicyScope::Closure, *name, index, opcode, rule));
+                }
+            }
+        }
+        // Check the functions and their finalize logic.
+        for (name, function) in program.functions() {
+            for (index, instruction) in function.instructions().iter().enumerate() {
+                if let Some(rule) = self.check_instruction(&program_id, instruction) {
+                    let opcode = instruction.opcode();
+                    violations.push(PolicyViolation::new(
+                        program_id,
+                        PolicyScope::Function,
+                        *name,
+                        index,
+                        opcode,
+                        rule,
+                    ));
+                }
+            }
+            if let Some(finalize) = function.finalize_logic() {
+                for (index, command) in finalize.commands().iter().enumerate() {
+                    let opcode = command.opcode();
+                    if let Some(rule) = self.check_opcode(opcode) {
+                        violations.push(PolicyViolation::new(
+                            program_id,
+                            PolicyScope::Finalize,
+                            *name,
+                            index,
+                            opcode,
+                            rule,
+                        ));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// Ensures the given program does not violate the policy.
+    pub fn check<N: Network>(&self, program: &Program<N>) -> Result<()> {
+        let violations = self.violations(program);
+        if !violations.is_empty() {
+            let violations = violations.iter().map(|violation| format!("\n  - {violation}")).collect::<String>();
+            bail!("Program '{}' violates the program policy:{violations}", program.id())
+        }
+        Ok(())
+    }
+
+    /// Returns the rule that disallows the given instruction, if any.
+    fn check_instruction<N: Network>(
+        &self,
+        program_id: &ProgramID<N>,
+        instruction: &Instruction<N>,
+    ) -> Option<PolicyRule> {
+        // Check for a call to another program.
+        if self.disallow_external_calls {
+            if let Instruction::Call(call) = instruction {
+                if let CallOperator::Locator(locator) = call.operator() {
+                    if locator.program_id() != program_id {
+                        return Some(PolicyRule::ExternalCall(locator.program_id().to_string()));
+                    }
+                }
+            }
+        }
+        self.check_opcode(instruction.opcode())
+    }
+}
+
+/// The policy rule that was violated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyRule {
+    /// The opcode is disallowed.
+    Opcode(String),
+    /// The opcode belongs to a disallowed family.
+    Family(String),
+    /// The instruction calls into the given program, and external calls are disallowed.
+    ExternalCall(String),
+}
+
+impl Display for PolicyRule {
+    /// Prints the rule as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Opcode(opcode) => write!(f, "opcode '{opcode}' is disallowed"),
+            Self::Family(family) => write!(f, "opcode family '{family}' is disallowed"),
+            Self::ExternalCall(program_id) => write!(f, "calls to external program '{program_id}' are disallowed"),
+        }
+    }
+}
+
+/// The part of a program in which a policy violation occurs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyScope {
+    /// The body of a closure.
+    Closure,
+    /// The body of a function.
+    Function,
+    /// The finalize logic of a function.
+    Finalize,
+}
+
+impl Display for PolicyScope {
+    /// Prints the scope as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+            Self::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// A policy violation identifies an instruction or command that a program policy disallows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolicyViolation<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The part of the program in which the violation occurs.
+    scope: PolicyScope,
+    /// The name of the closure or function.
+    resource: Identifier<N>,
+    /// The index of the instruction or command.
+    index: usize,
+    /// The opcode of the instruction or command.
+    opcode: Opcode,
+    /// The violated rule.
+    rule: PolicyRule,
+}
+
+impl<N: Network> PolicyViolation<N> {
+    /// Initializes a new policy violation.
+    #[inline]
+    const fn new(
+        program_id: ProgramID<N>,
+        scope: PolicyScope,
+        resource: Identifier<N>,
+        index: usize,
+        opcode: Opcode,
+        rule: PolicyRule,
+    ) -> Self {
+        Self { program_id, scope, resource, index, opcode, rule }
+    }
+
+    /// Returns the ID of the program.
+    #[inline]
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the part of the program in which the violation occurs.
+    #[inline]
+    pub const fn scope(&self) -> PolicyScope {
+        self.scope
+    }
+
+    /// Returns the name of the closure or function.
+    #[inline]
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the index of the instruction or command.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the opcode of the instruction or command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        self.opcode
+    }
+
+    /// Returns the violated rule.
+    #[inline]
+    pub const fn rule(&self) -> &PolicyRule {
+        &self.rule
+    }
+}
+
+impl<N: Network> Display for PolicyViolation<N> {
+    /// Prints the violation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "'{}' in {} '{}/{}' (at index {}): {}",
+            self.opcode, self.scope, self.program_id, self.resource, self.index, self.rule
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_program() -> Result<Program<CurrentNetwork>> {
+        Program::from_str(
+            r"
+import token.aleo;
+
+program sample.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function roll:
+    input r0 as field.public;
+    hash.bhp256 r0 into r1 as field;
+    call twice r1 into r2;
+    call token.aleo/noop;
+    async roll r2 into r3;
+    output r3 as sample.aleo/roll.future;
+
+finalize roll:
+    input r0 as field.public;
+    rand.chacha r0 into r1 as field;",
+        )
+    }
+
+    #[test]
+    fn test_empty_policy() -> Result<()> {
+        let program = sample_program()?;
+        let policy = ProgramPolicy::new();
+        assert!(policy.is_empty());
+        assert!(policy.violations(&program).is_empty());
+        assert!(policy.check(&program).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_disallow_opcode() -> Result<()> {
+        let program = sample_program()?;
+
+        // Ensure only the exact opcode is disallowed.
+        let violations = ProgramPolicy::new().disallow_opcode("hash.bhp256").violations(&program);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].scope(), PolicyScope::Function);
+        assert_eq!(violations[0].resource(), &Identifier::from_str("roll")?);
+        assert_eq!(violations[0].index(), 0);
+        assert_eq!(violations[0].rule(), &PolicyRule::Opcode("hash.bhp256".to_string()));
+        assert!(ProgramPolicy::new().disallow_opcode("hash.bhp512").check(&program).is_ok());
+
+        // Ensure an instruction in a closure is found.
+        let violations = ProgramPolicy::new().disallow_opcode("add").violations(&program);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].scope(), PolicyScope::Closure);
+        assert_eq!(violations[0].resource(), &Identifier::from_str("twice")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_disallow_family() -> Result<()> {
+        let program = sample_program()?;
+
+        // Ensure the `rand` family matches `rand.chacha` in the finalize logic.
+        let violations = ProgramPolicy::new().disallow_family("rand").violations(&program);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].scope(), PolicyScope::Finalize);
+        assert_eq!(*violations[0].opcode(), "rand.chacha");
+        assert_eq!(violations[0].index(), 0);
+        assert_eq!(violations[0].rule(), &PolicyRule::Family("rand".to_string()));
+
+        // Ensure a family only matches whole segments of the opcode.
+        assert!(ProgramPolicy::new().disallow_family("ran").check(&program).is_ok());
+        assert!(ProgramPolicy::new().disallow_family("hash").check(&program).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_disallow_external_calls() -> Result<()> {
+        let program = sample_program()?;
+
+        // Ensure only the call to `token.aleo` is disallowed.
+        let policy = ProgramPolicy::new().disallow_external_calls();
+        let violations = policy.violations(&program);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].index(), 2);
+        assert_eq!(violations[0].rule(), &PolicyRule::ExternalCall("token.aleo".to_string()));
+
+        // Ensure the error lists the violation.
+        let error = policy.check(&program).unwrap_err().to_string();
+        assert!(error.contains("'call' in function 'sample.aleo/roll' (at index 2)"), "{error}");
+        Ok(())
+    }
+}
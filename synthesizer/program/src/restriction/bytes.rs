@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Restriction<N> {
+    /// Reads the restriction from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let function_name = Identifier::read_le(&mut reader)?;
+        let caller = Address::read_le(&mut reader)?;
+        Ok(Self { function_name, caller })
+    }
+}
+
+impl<N: Network> ToBytes for Restriction<N> {
+    /// Writes the restriction to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.function_name.write_le(&mut writer)?;
+        self.caller.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = Restriction::<CurrentNetwork>::from_str(
+            "restrict transfer to aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw;",
+        )?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Restriction::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
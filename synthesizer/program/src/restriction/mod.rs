@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{
+    network::prelude::*,
+    program::{Address, Identifier},
+};
+
+/// A restriction statement limits the callers of a function, and is of the form `restrict {function} to {address};`.
+/// A function may be restricted to multiple callers, by declaring one restriction for each caller.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Restriction<N: Network> {
+    /// The name of the restricted function.
+    function_name: Identifier<N>,
+    /// The address of the allowed caller.
+    caller: Address<N>,
+}
+
+impl<N: Network> Restriction<N> {
+    /// The maximum number of allowed callers for a function.
+    pub const MAX_CALLERS: usize = 16;
+
+    /// Initializes a new restriction.
+    #[inline]
+    pub const fn new(function_name: Identifier<N>, caller: Address<N>) -> Self {
+        Self { function_name, caller }
+    }
+
+    /// Returns the name of the restricted function.
+    #[inline]
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the address of the allowed caller.
+    #[inline]
+    pub const fn caller(&self) -> &Address<N> {
+        &self.caller
+    }
+}
+
+impl<N: Network> TypeName for Restriction<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "restrict"
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Restriction<N> {
+    /// Parses a string into a restriction statement of the form `restrict {function} to {address};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the restrict keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the function name from the string.
+        let (string, function_name) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "to" keyword from the string.
+        let (string, _) = tag("to")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the caller address from the string.
+        let (string, caller) = Address::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the restriction statement.
+        Ok((string, Self { function_name, caller }))
+    }
+}
+
+impl<N: Network> FromStr for Restriction<N> {
+    type Err = Error;
+
+    /// Parses a string into a restriction statement.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Restriction<N> {
+    /// Prints the restriction as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Restriction<N> {
+    /// Prints the restriction statement as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{type_} {function} to {caller};",
+            type_ = Self::type_name(),
+            function = self.function_name,
+            caller = self.caller
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const CALLER: &str = "aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw";
+
+    #[test]
+    fn test_restriction_parse() -> Result<()> {
+        let restriction = Restriction::<CurrentNetwork>::parse(&format!("restrict transfer to {CALLER};")).unwrap().1;
+        assert_eq!(restriction.function_name(), &Identifier::<CurrentNetwork>::from_str("transfer")?);
+        assert_eq!(restriction.caller(), &Address::<CurrentNetwork>::from_str(CALLER)?);
+
+        // Ensure an invalid caller fails to parse.
+        assert!(Restriction::<CurrentNetwork>::parse("restrict transfer to r0;").is_err());
+        assert!(Restriction::<CurrentNetwork>::parse(&format!("restrict transfer {CALLER};")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restriction_display() -> Result<()> {
+        let expected = format!("restrict transfer to {CALLER};");
+        let restriction = Restriction::<CurrentNetwork>::from_str(&expected)?;
+        assert_eq!(expected, restriction.to_string());
+
+        Ok(())
+    }
+}
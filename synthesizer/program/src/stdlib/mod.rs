@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CommandTrait, InstructionTrait, ProgramCore};
+use console::{network::prelude::*, program::ProgramID};
+
+/// The prefix of the program names reserved for the standard library.
+const STDLIB_PREFIX: &str = "std_";
+
+/// The standard library, as pairs of program ID and source code.
+///
+/// The standard library programs contain audited closures for common tasks, which other programs import
+/// and invoke with `call {program}/{closure}`. The exception is `std_token.aleo`, which is a skeleton to copy,
+/// as a record can only be spent by the program that declares it.
+const STDLIB: [(&str, &str); 4] = [
+    ("std_math.aleo", include_str!("../resources/stdlib/std_math.aleo")),
+    ("std_merkle.aleo", include_str!("../resources/stdlib/std_merkle.aleo")),
+    ("std_access.aleo", include_str!("../resources/stdlib/std_access.aleo")),
+    ("std_token.aleo", include_str!("../resources/stdlib/std_token.aleo")),
+];
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Initializes the standard library programs.
+    #[inline]
+    pub fn stdlib() -> Result<Vec<Self>> {
+        STDLIB.iter().map(|(_, source)| Self::from_str(source)).collect()
+    }
+
+    /// Initializes the standard library program with the given program ID.
+    #[inline]
+    pub fn stdlib_program(program_id: &ProgramID<N>) -> Result<Self> {
+        match STDLIB.iter().find(|(id, _)| *id == program_id.to_string()) {
+            Some((_, source)) => Self::from_str(source),
+            None => bail!("Program '{program_id}' is not in the standard library"),
+        }
+    }
+
+    /// Returns `true` if the given program ID is in the standard library.
+    #[inline]
+    pub fn is_stdlib(program_id: &ProgramID<N>) -> bool {
+        STDLIB.iter().any(|(id, _)| *id == program_id.to_string())
+    }
+
+    /// Returns `true` if the given program ID is in the namespace reserved for the standard library,
+    /// which is any program name that starts with `std_`.
+    #[inline]
+    pub fn is_stdlib_namespace(program_id: &ProgramID<N>) -> bool {
+        program_id.name().to_string().starts_with(STDLIB_PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_stdlib() -> Result<()> {
+        let programs = Program::<CurrentNetwork>::stdlib()?;
+        assert_eq!(programs.len(), STDLIB.len());
+
+        for ((id, _), program) in STDLIB.iter().zip_eq(&programs) {
+            // Ensure the program ID matches the source.
+            assert_eq!(program.id().to_string(), *id);
+            assert!(Program::is_stdlib(program.id()));
+            assert!(Program::is_stdlib_namespace(program.id()));
+            assert_eq!(&Program::stdlib_program(program.id())?, program);
+            // Ensure the standard library does not depend on other programs.
+            assert!(program.imports().is_empty());
+            // Ensure the program declares a function, as it could not be deployed otherwise.
+            assert!(!program.functions().is_empty());
+        }
+
+        // Ensure other programs are not in the standard library.
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        assert!(!Program::is_stdlib(&credits));
+        assert!(Program::<CurrentNetwork>::stdlib_program(&credits).is_err());
+        assert!(!Program::is_stdlib_namespace(&credits));
+
+        // Ensure the namespace of the standard library includes programs that are not in the standard library.
+        let reserved = ProgramID::<CurrentNetwork>::from_str("std_custom.aleo")?;
+        assert!(!Program::is_stdlib(&reserved));
+        assert!(Program::is_stdlib_namespace(&reserved));
+        Ok(())
+    }
+}
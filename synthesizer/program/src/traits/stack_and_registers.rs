@@ -133,6 +133,12 @@ pub trait RegistersSigner<N: Network> {
 
     /// Sets the transition view key.
     fn set_tvk(&mut self, tvk: Field<N>);
+
+    /// Returns the digest of the transition inputs.
+    fn input_digest(&self) -> Result<Field<N>>;
+
+    /// Sets the digest of the transition inputs.
+    fn set_input_digest(&mut self, input_digest: Field<N>);
 }
 
 pub trait RegistersSignerCircuit<N: Network, A: circuit::Aleo<Network = N>> {
@@ -153,6 +159,12 @@ pub trait RegistersSignerCircuit<N: Network, A: circuit::Aleo<Network = N>> {
 
     /// Sets the transition view key, as a circuit.
     fn set_tvk_circuit(&mut self, tvk_circuit: circuit::Field<A>);
+
+    /// Returns the digest of the transition inputs, as a circuit.
+    fn input_digest_circuit(&self) -> Result<circuit::Field<A>>;
+
+    /// Sets the digest of the transition inputs, as a circuit.
+    fn set_input_digest_circuit(&mut self, input_digest_circuit: circuit::Field<A>);
 }
 
 pub trait RegistersLoad<N: Network> {
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::Testnet3,
+    prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+};
+use snarkvm_synthesizer_program::{
+    traits::RegistersStore,
+    AssertHeightGe,
+    AssertHeightLe,
+    AssertTimestampGe,
+    AssertTimestampLe,
+    FinalizeGlobalState,
+    Program,
+};
+use synthesizer_process::{FinalizeRegisters, Process, Stack, StackProgramTypes};
+
+type CurrentNetwork = Testnet3;
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+fn sample_stack(command: &str, operand_type: &str) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+            function run:
+                input r0 as {operand_type}.public;
+                async run r0 into r1;
+                output r1 as testing.aleo/run.future;
+
+            finalize run:
+                input r0 as {operand_type}.public;
+                {command} r0;
+        "
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Samples the finalize registers for the given block height and block timestamp, with `r0` set to `literal`.
+fn sample_finalize_registers(
+    stack: &Stack<CurrentNetwork>,
+    block_height: u32,
+    block_timestamp: i64,
+    literal: Literal<CurrentNetwork>,
+) -> Result<FinalizeRegisters<CurrentNetwork>> {
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run")?;
+
+    // Initialize the registers.
+    let mut finalize_registers = FinalizeRegisters::new(
+        FinalizeGlobalState::from(block_height as u64, block_height, block_timestamp, [0; 32]),
+        <CurrentNetwork as Network>::TransitionID::default(),
+        function_name,
+        stack.get_finalize_types(&function_name)?.clone(),
+    );
+    // Store the literal in `r0`.
+    finalize_registers.store(stack, &Register::Locator(0), Value::Plaintext(Plaintext::from(literal)))?;
+
+    Ok(finalize_registers)
+}
+
+#[test]
+fn test_assert_height() -> Result<()> {
+    let stack = sample_stack("assert.height.ge", "u32")?;
+    let ge = AssertHeightGe::<CurrentNetwork>::from_str("assert.height.ge r0;")?;
+    let le = AssertHeightLe::<CurrentNetwork>::from_str("assert.height.le r0;")?;
+    let operand = Literal::from_str("10u32")?;
+
+    for (block_height, expected_ge, expected_le) in [(9, false, true), (10, true, true), (11, true, false)] {
+        let mut registers = sample_finalize_registers(&stack, block_height, 0, operand.clone())?;
+        assert_eq!(ge.finalize(&stack, &mut registers).is_ok(), expected_ge, "Block height {block_height}");
+        assert_eq!(le.finalize(&stack, &mut registers).is_ok(), expected_le, "Block height {block_height}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_timestamp() -> Result<()> {
+    let stack = sample_stack("assert.timestamp.le", "i64")?;
+    let ge = AssertTimestampGe::<CurrentNetwork>::from_str("assert.timestamp.ge r0;")?;
+    let le = AssertTimestampLe::<CurrentNetwork>::from_str("assert.timestamp.le r0;")?;
+    let operand = Literal::from_str("1700000000i64")?;
+
+    for (block_timestamp, expected_ge, expected_le) in
+        [(1699999999, false, true), (1700000000, true, true), (1700000001, true, false)]
+    {
+        let mut registers = sample_finalize_registers(&stack, 1, block_timestamp, operand.clone())?;
+        assert_eq!(ge.finalize(&stack, &mut registers).is_ok(), expected_ge, "Block timestamp {block_timestamp}");
+        assert_eq!(le.finalize(&stack, &mut registers).is_ok(), expected_le, "Block timestamp {block_timestamp}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_assert_block_operand_type() {
+    // Ensure the operand types are checked when the program is loaded.
+    assert!(sample_stack("assert.height.ge", "u64").is_err());
+    assert!(sample_stack("assert.height.le", "i64").is_err());
+    assert!(sample_stack("assert.timestamp.ge", "u32").is_err());
+    assert!(sample_stack("assert.timestamp.le", "field").is_err());
+}
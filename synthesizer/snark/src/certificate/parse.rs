@@ -14,7 +14,12 @@
 
 use super::*;
 
-static PROOF_PREFIX: &str = "certificate";
+const PROOF_PREFIX: &str = "certificate";
+
+impl<N: Network> Bech32m for Certificate<N> {
+    /// The certificate string prefix.
+    const PREFIX: &'static str = PROOF_PREFIX;
+}
 
 impl<N: Network> Parser for Certificate<N> {
     /// Parses a string into an certificate.
@@ -19,7 +19,9 @@
 
 use console::network::{prelude::*, FiatShamir};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize};
 
+#[cfg(feature = "prover")]
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
 
@@ -34,12 +36,22 @@ pub use certificate::Certificate;
 mod proof;
 pub use proof::Proof;
 
+#[cfg(feature = "prover")]
 mod proving_key;
+#[cfg(feature = "prover")]
 pub use proving_key::ProvingKey;
 
+#[cfg(feature = "prover")]
+pub use varuna::ProvingReport;
+
+#[cfg(feature = "prover")]
 mod universal_srs;
+#[cfg(feature = "prover")]
 pub use universal_srs::UniversalSRS;
 
+mod verifier_registry;
+pub use verifier_registry::VerifierRegistry;
+
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
 
@@ -160,4 +172,23 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_varuna_with_report() {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Prove with a report, and ensure the proof verifies.
+        let (proof, report) = proving_key.prove_with_report("test", &assignment, &mut TestRng::default()).unwrap();
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+
+        // Ensure the report covers the circuit.
+        assert_eq!(report.num_instances, 1);
+        assert_eq!(report.num_constraints, verifying_key.circuit_info.num_constraints);
+        assert_eq!(report.num_variables, verifying_key.circuit_info.num_variables);
+        assert!(report.total_time >= report.synthesis_time + report.fft_time + report.commitment_time);
+        #[cfg(target_os = "linux")]
+        assert!(report.peak_memory.is_some());
+    }
 }
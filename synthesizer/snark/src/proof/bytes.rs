@@ -40,6 +40,46 @@ impl<N: Network> ToBytes for Proof<N> {
     }
 }
 
+impl<N: Network> Proof<N> {
+    /// Returns the proof in compressed form, which is its canonical byte representation.
+    /// This is the encoding used by `ToBytes`, and by serde in both human-readable and binary formats.
+    pub fn to_bytes_compressed(&self) -> Result<Vec<u8>> {
+        self.to_bytes_le()
+    }
+
+    /// Returns the proof in uncompressed form, which stores both coordinates of each group element.
+    /// This is larger than the compressed form, but is faster to read, as no point
+    /// decompression is needed. As the two forms are not interchangeable, it must be read with
+    /// `from_bytes_uncompressed`.
+    pub fn to_bytes_uncompressed(&self) -> Result<Vec<u8>> {
+        // Write the version.
+        let mut bytes = vec![1u8];
+        // Write the bytes.
+        self.proof.serialize_uncompressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads the proof from its compressed form.
+    pub fn from_bytes_compressed(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(bytes)
+    }
+
+    /// Reads the proof from its uncompressed form.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid proof version");
+        // Read the proof.
+        let proof = varuna::Proof::deserialize_uncompressed(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes after the proof");
+        // Return the proof.
+        Ok(Self { proof })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +95,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_compressed_and_uncompressed() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Check the compressed form.
+        let compressed = expected.to_bytes_compressed()?;
+        assert_eq!(compressed, expected.to_bytes_le()?);
+        assert_eq!(expected, Proof::from_bytes_compressed(&compressed)?);
+
+        // Check the uncompressed form.
+        let uncompressed = expected.to_bytes_uncompressed()?;
+        assert!(uncompressed.len() > compressed.len());
+        assert_eq!(expected, Proof::from_bytes_uncompressed(&uncompressed)?);
+
+        // Ensure the forms are not interchangeable.
+        assert!(Proof::<console::network::Testnet3>::from_bytes_uncompressed(&compressed).is_err());
+        assert!(Proof::<console::network::Testnet3>::from_bytes_compressed(&uncompressed).is_err());
+
+        Ok(())
+    }
 }
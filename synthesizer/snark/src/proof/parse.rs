@@ -14,7 +14,12 @@
 
 use super::*;
 
-static PROOF_PREFIX: &str = "proof";
+const PROOF_PREFIX: &str = "proof";
+
+impl<N: Network> Bech32m for Proof<N> {
+    /// The proof string prefix.
+    const PREFIX: &'static str = PROOF_PREFIX;
+}
 
 impl<N: Network> Parser for Proof<N> {
     /// Parses a string into an proof.
@@ -121,4 +126,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bech32m() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Ensure the canonical encoding matches the string representation.
+        let candidate = expected.to_bech32m()?;
+        assert_eq!(expected.to_string(), candidate);
+        assert_eq!(expected, Proof::<CurrentNetwork>::from_bech32m(&candidate)?);
+
+        Ok(())
+    }
 }
@@ -39,3 +39,76 @@ impl<N: Network> ToBytes for ProvingKey<N> {
         self.proving_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> ProvingKey<N> {
+    /// Returns the proving key in compressed form, which is its canonical byte representation.
+    /// This is the encoding used by `ToBytes`, and by serde in both human-readable and binary formats.
+    pub fn to_bytes_compressed(&self) -> Result<Vec<u8>> {
+        self.to_bytes_le()
+    }
+
+    /// Returns the proving key in uncompressed form, which stores both coordinates of each group element
+    /// in the circuit verifying key. The committer key is always stored uncompressed, so this is only
+    /// slightly larger than the compressed form, but is faster to read. As the two forms are not
+    /// interchangeable, it must be read with `from_bytes_uncompressed`.
+    pub fn to_bytes_uncompressed(&self) -> Result<Vec<u8>> {
+        // Write the version.
+        let mut bytes = vec![1u8];
+        // Write the bytes.
+        self.proving_key.circuit_verifying_key.serialize_uncompressed(&mut bytes)?;
+        self.proving_key.circuit.serialize_uncompressed(&mut bytes)?;
+        self.proving_key.committer_key.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads the proving key from its compressed form.
+    pub fn from_bytes_compressed(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(bytes)
+    }
+
+    /// Reads the proving key from its uncompressed form.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid proving key version");
+        // Read the proving key.
+        let circuit_verifying_key = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let circuit = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes after the proving key");
+        // Return the proving key.
+        Ok(Self { proving_key: Arc::new(varuna::CircuitProvingKey { circuit_verifying_key, circuit, committer_key }) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes_compressed_and_uncompressed() -> Result<()> {
+        // Sample the proving key.
+        let expected = crate::test_helpers::sample_keys().0;
+
+        // Check the compressed form.
+        let compressed = expected.to_bytes_compressed()?;
+        assert_eq!(compressed, expected.to_bytes_le()?);
+        assert_eq!(compressed, ProvingKey::<CurrentNetwork>::from_bytes_compressed(&compressed)?.to_bytes_le()?);
+
+        // Check the uncompressed form.
+        let uncompressed = expected.to_bytes_uncompressed()?;
+        assert!(uncompressed.len() > compressed.len());
+        assert_eq!(compressed, ProvingKey::<CurrentNetwork>::from_bytes_uncompressed(&uncompressed)?.to_bytes_le()?);
+
+        // Ensure the forms are not interchangeable.
+        assert!(ProvingKey::<CurrentNetwork>::from_bytes_uncompressed(&compressed).is_err());
+        assert!(ProvingKey::<CurrentNetwork>::from_bytes_compressed(&uncompressed).is_err());
+
+        Ok(())
+    }
+}
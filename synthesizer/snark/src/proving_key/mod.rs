@@ -54,6 +54,16 @@ impl<N: Network> ProvingKey<N> {
         Ok(proof)
     }
 
+    /// Returns a proof for the given assignment on the circuit, along with a report on the resources used to create it.
+    pub fn prove_with_report<R: Rng + CryptoRng>(
+        &self,
+        function_name: &str,
+        assignment: &circuit::Assignment<N::Field>,
+        rng: &mut R,
+    ) -> Result<(Proof<N>, ProvingReport)> {
+        Self::prove_batch_with_report(function_name, &[(self.clone(), vec![assignment.clone()])], rng)
+    }
+
     /// Returns a proof for the given batch of proving keys and assignments.
     #[allow(clippy::type_complexity)]
     pub fn prove_batch<R: Rng + CryptoRng>(
@@ -82,6 +92,34 @@ impl<N: Network> ProvingKey<N> {
 
         Ok(batch_proof)
     }
+
+    /// Returns a proof for the given batch of proving keys and assignments,
+    /// along with a report on the resources used to create it.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_with_report<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        rng: &mut R,
+    ) -> Result<(Proof<N>, ProvingReport)> {
+        // Prepare the instances.
+        let instances: BTreeMap<_, _> = assignments
+            .iter()
+            .map(|(proving_key, assignments)| (proving_key.deref(), assignments.as_slice()))
+            .collect();
+
+        // Retrieve the proving parameters.
+        let universal_prover = N::varuna_universal_prover();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Compute the proof and the report.
+        let (batch_proof, report) =
+            Varuna::<N>::prove_batch_with_report(universal_prover, fiat_shamir, &instances, rng)?;
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{}", format!(" • Executed '{locator}' (in {} ms)", report.total_time.as_millis()).dimmed());
+
+        Ok((Proof::new(batch_proof), report))
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
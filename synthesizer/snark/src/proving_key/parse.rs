@@ -14,7 +14,12 @@
 
 use super::*;
 
-static PROVING_KEY: &str = "prover";
+const PROVING_KEY: &str = "prover";
+
+impl<N: Network> Bech32m for ProvingKey<N> {
+    /// The proving key string prefix.
+    const PREFIX: &'static str = PROVING_KEY;
+}
 
 impl<N: Network> Parser for ProvingKey<N> {
     /// Parses a string into the proving key.
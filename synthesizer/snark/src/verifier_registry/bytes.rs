@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for VerifierRegistry<N> {
+    /// Reads the registry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid verifier registry version"));
+        }
+        // Read the number of verifying keys.
+        let num_verifying_keys = u32::read_le(&mut reader)?;
+        // Read the verifying keys.
+        // Note: The number of verifying keys is untrusted, so the map is not preallocated with it.
+        let mut verifying_keys = IndexMap::new();
+        for _ in 0..num_verifying_keys {
+            // Read the locator.
+            let locator = Locator::read_le(&mut reader)?;
+            // Read the verifying key.
+            let verifying_key = VerifyingKey::read_le(&mut reader)?;
+            // Ensure the locator is not a duplicate.
+            if verifying_keys.insert(locator, verifying_key).is_some() {
+                return Err(error(format!("Duplicate verifying key for '{locator}' in the verifier registry")));
+            }
+        }
+        // Return the registry.
+        Ok(Self { verifying_keys })
+    }
+}
+
+impl<N: Network> ToBytes for VerifierRegistry<N> {
+    /// Writes the registry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of verifying keys.
+        u32::try_from(self.verifying_keys.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the verifying keys.
+        for (locator, verifying_key) in &self.verifying_keys {
+            locator.write_le(&mut writer)?;
+            verifying_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the registry.
+        let expected = test_helpers::sample_registry();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, VerifierRegistry::read_le(&expected_bytes[..])?);
+
+        // Check the empty registry.
+        let empty = VerifierRegistry::<console::network::Testnet3>::new();
+        assert_eq!(empty, VerifierRegistry::read_le(&empty.to_bytes_le()?[..])?);
+
+        // Ensure a registry claiming more verifying keys than it contains is rejected.
+        let mut bytes = vec![1u8];
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(VerifierRegistry::<console::network::Testnet3>::read_le(&bytes[..]).is_err());
+
+        Ok(())
+    }
+}
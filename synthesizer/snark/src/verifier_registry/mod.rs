@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+mod bytes;
+mod serialize;
+
+use console::program::{Identifier, Locator, ProgramID};
+
+use indexmap::IndexMap;
+
+/// A registry of verifying keys, indexed by the program ID and function name.
+/// This allows a light verifier to check proofs without loading the programs themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifierRegistry<N: Network> {
+    /// The verifying keys, indexed by the locator of the function.
+    verifying_keys: IndexMap<Locator<N>, VerifyingKey<N>>,
+}
+
+impl<N: Network> VerifierRegistry<N> {
+    /// Initializes a new empty registry.
+    pub fn new() -> Self {
+        Self { verifying_keys: IndexMap::new() }
+    }
+
+    /// Inserts the verifying key for the given function, returning the previous verifying key if it exists.
+    pub fn insert(
+        &mut self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        verifying_key: VerifyingKey<N>,
+    ) -> Option<VerifyingKey<N>> {
+        self.verifying_keys.insert(Locator::new(program_id, function_name), verifying_key)
+    }
+
+    /// Removes the verifying key for the given function, returning the verifying key if it exists.
+    pub fn remove(&mut self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Option<VerifyingKey<N>> {
+        self.verifying_keys.shift_remove(&Locator::new(*program_id, *function_name))
+    }
+
+    /// Returns the verifying key for the given function, if it exists.
+    pub fn get(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Option<&VerifyingKey<N>> {
+        self.verifying_keys.get(&Locator::new(*program_id, *function_name))
+    }
+
+    /// Returns `true` if the registry contains a verifying key for the given function.
+    pub fn contains(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        self.verifying_keys.contains_key(&Locator::new(*program_id, *function_name))
+    }
+
+    /// Returns `true` if the proof is valid for the given function and public inputs.
+    /// Returns an error if the registry does not contain a verifying key for the function.
+    pub fn verify(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[N::Field],
+        proof: &Proof<N>,
+    ) -> Result<bool> {
+        let locator = Locator::new(*program_id, *function_name);
+        // Retrieve the verifying key.
+        let Some(verifying_key) = self.verifying_keys.get(&locator) else {
+            bail!("Missing the verifying key for '{locator}'")
+        };
+        // Verify the proof.
+        Ok(verifying_key.verify(&locator.to_string(), inputs, proof))
+    }
+
+    /// Returns an iterator over the locators and verifying keys in the registry.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (&Locator<N>, &VerifyingKey<N>)> {
+        self.verifying_keys.iter()
+    }
+
+    /// Returns the number of verifying keys in the registry.
+    pub fn len(&self) -> usize {
+        self.verifying_keys.len()
+    }
+
+    /// Returns `true` if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.verifying_keys.is_empty()
+    }
+}
+
+impl<N: Network> Default for VerifierRegistry<N> {
+    /// Initializes a new empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a sample registry, with the example circuit registered for `test.aleo/run`.
+    pub(crate) fn sample_registry() -> VerifierRegistry<CurrentNetwork> {
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let mut registry = VerifierRegistry::new();
+        registry.insert(ProgramID::from_str("test.aleo").unwrap(), Identifier::from_str("run").unwrap(), verifying_key);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::environment::{Circuit, Environment};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let registry = test_helpers::sample_registry();
+        let proof = crate::test_helpers::sample_proof();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("test.aleo")?;
+        let function_name = Identifier::from_str("run")?;
+        assert!(registry.contains(&program_id, &function_name));
+        assert_eq!(registry.len(), 1);
+
+        // Ensure the proof verifies for the registered function.
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(registry.verify(&program_id, &function_name, &[one, one], &proof)?);
+        assert!(!registry.verify(&program_id, &function_name, &[one, one + one], &proof)?);
+
+        // Ensure verification fails for an unregistered function.
+        let unknown = Identifier::from_str("unknown")?;
+        assert!(registry.verify(&program_id, &unknown, &[one, one], &proof).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_and_remove() -> Result<()> {
+        let mut registry = test_helpers::sample_registry();
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("test.aleo")?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Ensure inserting an existing function returns the previous verifying key.
+        assert_eq!(registry.insert(program_id, function_name, verifying_key.clone()), Some(verifying_key.clone()));
+        assert_eq!(registry.len(), 1);
+
+        // Ensure removing the function empties the registry.
+        assert_eq!(registry.remove(&program_id, &function_name), Some(verifying_key));
+        assert!(registry.get(&program_id, &function_name).is_none());
+        assert!(registry.is_empty());
+        Ok(())
+    }
+}
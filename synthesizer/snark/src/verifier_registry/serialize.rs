@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for VerifierRegistry<N> {
+    /// Serializes the registry into a map of locators to verifying keys, or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => self.verifying_keys.serialize(serializer),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for VerifierRegistry<N> {
+    /// Deserializes the registry from a map of locators to verifying keys, or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => Ok(Self { verifying_keys: IndexMap::deserialize(deserializer)? }),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "verifier registry"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        // Sample the registry.
+        let expected = test_helpers::sample_registry();
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        let (locator, verifying_key) = expected.iter().next().unwrap();
+        let candidate_value = serde_json::Value::from_str(&candidate_string)?;
+        assert_eq!(verifying_key.to_string(), candidate_value[locator.to_string()].as_str().unwrap());
+
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        // Sample the registry.
+        let expected = test_helpers::sample_registry();
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, VerifierRegistry::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
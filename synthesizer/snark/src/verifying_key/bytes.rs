@@ -39,3 +39,72 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
         self.verifying_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> VerifyingKey<N> {
+    /// Returns the verifying key in compressed form, which is its canonical byte representation.
+    /// This is the encoding used by `ToBytes`, and by serde in both human-readable and binary formats.
+    pub fn to_bytes_compressed(&self) -> Result<Vec<u8>> {
+        self.to_bytes_le()
+    }
+
+    /// Returns the verifying key in uncompressed form, which stores both coordinates of each group element.
+    /// This is larger than the compressed form, but is faster to read, as no point
+    /// decompression is needed. As the two forms are not interchangeable, it must be read with
+    /// `from_bytes_uncompressed`.
+    pub fn to_bytes_uncompressed(&self) -> Result<Vec<u8>> {
+        // Write the version.
+        let mut bytes = vec![1u8];
+        // Write the bytes.
+        self.verifying_key.serialize_uncompressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads the verifying key from its compressed form.
+    pub fn from_bytes_compressed(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(bytes)
+    }
+
+    /// Reads the verifying key from its uncompressed form.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid verifying key version");
+        // Read the verifying key.
+        let verifying_key = Arc::new(varuna::CircuitVerifyingKey::deserialize_uncompressed(&mut reader)?);
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes after the verifying key");
+        // Return the verifying key.
+        Ok(Self { verifying_key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes_compressed_and_uncompressed() -> Result<()> {
+        // Sample the verifying key.
+        let expected = crate::test_helpers::sample_keys().1;
+
+        // Check the compressed form.
+        let compressed = expected.to_bytes_compressed()?;
+        assert_eq!(compressed, expected.to_bytes_le()?);
+        assert_eq!(compressed, VerifyingKey::<CurrentNetwork>::from_bytes_compressed(&compressed)?.to_bytes_le()?);
+
+        // Check the uncompressed form.
+        let uncompressed = expected.to_bytes_uncompressed()?;
+        assert!(uncompressed.len() > compressed.len());
+        assert_eq!(compressed, VerifyingKey::<CurrentNetwork>::from_bytes_uncompressed(&uncompressed)?.to_bytes_le()?);
+
+        // Ensure the forms are not interchangeable.
+        assert!(VerifyingKey::<CurrentNetwork>::from_bytes_uncompressed(&compressed).is_err());
+        assert!(VerifyingKey::<CurrentNetwork>::from_bytes_compressed(&uncompressed).is_err());
+
+        Ok(())
+    }
+}
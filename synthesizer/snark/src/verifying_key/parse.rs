@@ -14,7 +14,12 @@
 
 use super::*;
 
-static VERIFYING_KEY: &str = "verifier";
+const VERIFYING_KEY: &str = "verifier";
+
+impl<N: Network> Bech32m for VerifyingKey<N> {
+    /// The verifying key string prefix.
+    const PREFIX: &'static str = VERIFYING_KEY;
+}
 
 impl<N: Network> Parser for VerifyingKey<N> {
     /// Parses a string into the verifying key.
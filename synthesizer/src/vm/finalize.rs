@@ -108,7 +108,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Finalizes the given transactions into the VM.
     ///
-    /// Returns the finalize operations from pre-ratify and post-ratify.
+    /// Returns the finalize operations from pre-ratify and post-ratify,
+    /// and the state diffs of each transaction, indexed by transaction ID.
     #[inline]
     pub fn finalize(
         &self,
@@ -116,14 +117,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, IndexMap<N::TransactionID, Vec<StateDiff<N>>>)> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let (ratified_finalize_operations, state_diffs) =
+            self.atomic_finalize(state, ratifications, solutions, transactions)?;
 
         finish!(timer, "Finished real-run of finalize");
-        Ok(ratified_finalize_operations)
+        Ok((ratified_finalize_operations, state_diffs))
     }
 }
 
@@ -494,7 +496,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Performs atomic finalization over a list of transactions.
     ///
-    /// Returns the finalize operations from pre-ratify and post-ratify.
+    /// Returns the finalize operations from pre-ratify and post-ratify,
+    /// and the state diffs of each transaction.
     #[inline]
     fn atomic_finalize(
         &self,
@@ -502,7 +505,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, IndexMap<N::TransactionID, Vec<StateDiff<N>>>)> {
         // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
         // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
         // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
@@ -525,6 +528,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list of finalize operations.
             let mut ratified_finalize_operations = Vec::new();
+            // Initialize a map of transaction IDs to their state diffs.
+            let mut state_diffs = IndexMap::with_capacity(transactions.len());
 
             // Retrieve the finalize store.
             let store = self.finalize_store();
@@ -559,9 +564,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     return Err(format!("Mismatch in {} transaction index", transaction.variant()));
                 }
                 // Process the transaction in an isolated atomic batch.
-                // - If the transaction succeeds, the finalize operations are stored.
+                // - If the transaction succeeds, the finalize operations are stored, and its state diffs are returned.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome: Result<(), String> = match transaction {
+                let outcome: Result<Vec<StateDiff<N>>, String> = match transaction {
                     ConfirmedTransaction::AcceptedDeploy(_, transaction, finalize) => {
                         // Extract the deployment and fee from the transaction.
                         let (deployment, fee) = match transaction {
@@ -570,11 +575,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             _ => return Err("Expected deploy transaction".to_string()),
                         };
                         // The finalize operation here involves appending the 'stack', and adding the program to the finalize tree.
-                        match process.finalize_deployment(state, store, deployment, fee) {
+                        match process.finalize_deployment_with_state_diffs(state, store, deployment, fee) {
                             // Ensure the finalize operations match the expected.
-                            Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
+                            Ok((stack, finalize_operations, state_diffs)) => match finalize == &finalize_operations {
                                 // Store the stack.
-                                true => stacks.push(stack),
+                                true => {
+                                    stacks.push(stack);
+                                    Ok(state_diffs)
+                                }
                                 // Note: This will abort the entire atomic batch.
                                 false => {
                                     return Err(format!(
@@ -586,8 +594,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             Err(error) => {
                                 return Err(format!("Failed to finalize an accepted deploy transaction - {error}"));
                             }
-                        };
-                        Ok(())
+                        }
                     }
                     ConfirmedTransaction::AcceptedExecute(_, transaction, finalize) => {
                         // Extract the execution and fee from the transaction.
@@ -598,22 +605,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         // The finalize operation here involves calling 'update_key_value',
                         // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        match process.finalize_execution_with_state_diffs(state, store, execution, fee.as_ref()) {
                             // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            Ok((finalize_operations, state_diffs)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for an accepted execute - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                Ok(state_diffs)
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
                                 return Err(format!("Failed to finalize an accepted execute transaction - {error}"));
                             }
                         }
-                        Ok(())
                     }
                     ConfirmedTransaction::RejectedDeploy(_, Transaction::Fee(_, fee), rejected, finalize) => {
                         // Extract the rejected deployment.
@@ -637,22 +644,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             return Err("Mismatch in fee for a rejected deploy transaction".to_string());
                         }
                         // Lastly, finalize the fee.
-                        match process.finalize_fee(state, store, fee) {
+                        match process.finalize_fee_with_state_diffs(state, store, fee) {
                             // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            Ok((finalize_operations, state_diffs)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for a rejected deploy - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                Ok(state_diffs)
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(_e) => {
                                 return Err("Failed to finalize the fee in a rejected deploy transaction".to_string());
                             }
                         }
-                        Ok(())
                     }
                     ConfirmedTransaction::RejectedExecute(_, Transaction::Fee(_, fee), rejected, finalize) => {
                         // Extract the rejected execution.
@@ -676,22 +683,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             return Err("Mismatch in fee for a rejected execute transaction".to_string());
                         }
                         // Lastly, finalize the fee.
-                        match process.finalize_fee(state, store, fee) {
+                        match process.finalize_fee_with_state_diffs(state, store, fee) {
                             // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            Ok((finalize_operations, state_diffs)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for a rejected execute - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                Ok(state_diffs)
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(_e) => {
                                 return Err("Failed to finalize the fee in a rejected execute transaction".to_string());
                             }
                         }
-                        Ok(())
                     }
                     // Note: This will abort the entire atomic batch.
                     _ => return Err("Invalid confirmed transaction type".to_string()),
@@ -699,8 +706,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 lap!(timer, "Finalizing transaction {}", transaction.id());
 
                 match outcome {
-                    // If the transaction succeeded to finalize, continue to the next transaction.
-                    Ok(()) => (),
+                    // If the transaction succeeded to finalize, store its state diffs, and continue to the next one.
+                    Ok(transaction_state_diffs) => {
+                        state_diffs.insert(transaction.id(), transaction_state_diffs);
+                    }
                     // If the transaction failed to finalize, abort and continue to the next transaction.
                     Err(error) => {
                         eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
@@ -728,7 +737,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
-            Ok(ratified_finalize_operations)
+            Ok((ratified_finalize_operations, state_diffs))
         })
     }
 
@@ -1256,6 +1265,54 @@ finalize transfer_public:
         assert_eq!(candidate_transactions[0].to_unconfirmed_transaction_id().unwrap(), deployment_transaction_id);
     }
 
+    #[test]
+    fn test_finalize_state_diffs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Retrieve the balance of the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+        let old_value = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+
+        // Fetch a deployment transaction with a private fee, and an execution transaction with a public fee.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transactions = [deployment_transaction.clone(), execution_transaction.clone()];
+
+        // Prepare the confirmed transactions.
+        let (ratifications, confirmed_transactions, aborted_transaction_ids, _) =
+            vm.speculate(sample_finalize_state(1), None, vec![], None, transactions.iter()).unwrap();
+        assert_eq!(confirmed_transactions.len(), 2);
+        assert!(aborted_transaction_ids.is_empty());
+
+        // Finalize the transactions.
+        let (_, state_diffs) =
+            vm.finalize(sample_finalize_state(1), &ratifications, None, &confirmed_transactions).unwrap();
+        assert_eq!(state_diffs.len(), 2);
+
+        // Ensure the deployment does not write any mapping key, as its fee is private.
+        assert!(state_diffs[&deployment_transaction.id()].is_empty());
+
+        // Ensure the transfer to the caller and the public fee are merged into a single state diff.
+        let state_diffs = &state_diffs[&execution_transaction.id()];
+        assert_eq!(state_diffs.len(), 1);
+        assert_eq!(state_diffs[0].program_id(), &program_id);
+        assert_eq!(state_diffs[0].mapping_name(), &mapping_name);
+        assert_eq!(state_diffs[0].key(), &key);
+        assert_eq!(state_diffs[0].old_value(), &old_value);
+
+        // Ensure the state diff matches the finalized balance of the caller.
+        let new_value = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+        assert_eq!(state_diffs[0].new_value(), &new_value);
+        assert_ne!(old_value, new_value);
+    }
+
     #[test]
     fn test_atomic_finalize_many() {
         let rng = &mut TestRng::default();
@@ -112,15 +112,32 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Call(_)) => bail!("`call` is not supported in finalize."),
         Command::Instruction(Instruction::Cast(_)) => Ok(2_000),
         Command::Instruction(Instruction::CastLossy(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Clamp(_)) => Ok(2_000),
         Command::Instruction(Instruction::CommitBHP256(_)) => Ok(200_000),
         Command::Instruction(Instruction::CommitBHP512(_)) => Ok(200_000),
         Command::Instruction(Instruction::CommitBHP768(_)) => Ok(200_000),
         Command::Instruction(Instruction::CommitBHP1024(_)) => Ok(200_000),
         Command::Instruction(Instruction::CommitPED64(_)) => Ok(100_000),
         Command::Instruction(Instruction::CommitPED128(_)) => Ok(100_000),
+        Command::Instruction(Instruction::CommitOpen(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitSeal(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitVerifyBHP256(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitVerifyBHP512(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitVerifyBHP768(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitVerifyBHP1024(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitVerifyPED64(_)) => Ok(100_000),
+        Command::Instruction(Instruction::CommitVerifyPED128(_)) => Ok(100_000),
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Dot(_)) => bail!("`dot` is not supported in finalize."),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Fixed64Add(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Fixed64Div(_)) => Ok(10_000),
+        Command::Instruction(Instruction::Fixed64Exp(_)) => Ok(50_000),
+        Command::Instruction(Instruction::Fixed64Ln(_)) => Ok(50_000),
+        Command::Instruction(Instruction::Fixed64Mul(_)) => Ok(10_000),
+        Command::Instruction(Instruction::Fixed64Sin(_)) => Ok(50_000),
+        Command::Instruction(Instruction::Fixed64Sub(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
@@ -164,6 +181,9 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
         Command::Instruction(Instruction::LessThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::MatMul(_)) => bail!("`matmul` is not supported in finalize."),
+        Command::Instruction(Instruction::Max(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Min(_)) => Ok(2_000),
         Command::Instruction(Instruction::Modulo(_)) => Ok(2_000),
         Command::Instruction(Instruction::Mul(_)) => Ok(150_000),
         Command::Instruction(Instruction::MulWrapped(_)) => Ok(2_000),
@@ -186,6 +206,7 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VRFVerify(_)) => Ok(300_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
         //  Expect these numbers to change as their usage is stabilized.
@@ -198,6 +219,10 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Set(_) => Ok(100_000),
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
         Command::Position(_) => Ok(1_000),
+        Command::AssertHeightGe(_)
+        | Command::AssertHeightLe(_)
+        | Command::AssertTimestampGe(_)
+        | Command::AssertTimestampLe(_) => Ok(2_000),
     };
     finalize
         .commands()
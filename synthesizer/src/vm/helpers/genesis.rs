@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VM;
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use ledger_block::Block;
+use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
+use ledger_store::ConsensusStorage;
+use synthesizer_program::{FinalizeStoreTrait, Program, StackMatches, StackProgram};
+
+use indexmap::IndexMap;
+
+/// A builder for the genesis block of a quorum chain, with a custom committee and public balances.
+///
+/// The starting supply that is not staked or assigned as a public balance is assigned to the genesis account,
+/// which executes the genesis transactions.
+///
+/// The genesis block has a fixed set of genesis transactions, so the initial programs are deployed by the
+/// genesis account after the genesis block, and the initial mapping entries are written once they are deployed.
+#[derive(Clone)]
+pub struct GenesisBuilder<N: Network> {
+    /// The private key of the genesis account.
+    private_key: PrivateKey<N>,
+    /// The validators, with their stake in microcredits.
+    validators: IndexMap<Address<N>, u64>,
+    /// The public balances in microcredits.
+    public_balances: IndexMap<Address<N>, u64>,
+    /// The initial programs, in deployment order.
+    programs: IndexMap<ProgramID<N>, Program<N>>,
+    /// The initial mapping entries, for each program and mapping.
+    mapping_entries: IndexMap<(ProgramID<N>, Identifier<N>), Vec<(Plaintext<N>, Value<N>)>>,
+}
+
+impl<N: Network> GenesisBuilder<N> {
+    /// Initializes a new genesis builder, for the given genesis account.
+    pub fn new(private_key: PrivateKey<N>) -> Self {
+        Self {
+            private_key,
+            validators: Default::default(),
+            public_balances: Default::default(),
+            programs: Default::default(),
+            mapping_entries: Default::default(),
+        }
+    }
+
+    /// Adds a validator to the genesis committee, with the given stake in microcredits.
+    pub fn add_validator(mut self, address: Address<N>, stake: u64) -> Result<Self> {
+        // Ensure the validator does not already exist.
+        ensure!(!self.validators.contains_key(&address), "Validator '{address}' is already in the genesis committee");
+        // Ensure the stake meets the minimum validator stake.
+        ensure!(stake >= MIN_VALIDATOR_STAKE, "Validator '{address}' must stake at least {MIN_VALIDATOR_STAKE}");
+        // Add the validator.
+        self.validators.insert(address, stake);
+        Ok(self)
+    }
+
+    /// Adds a public balance for the given address, in microcredits.
+    pub fn add_public_balance(mut self, address: Address<N>, amount: u64) -> Result<Self> {
+        // Ensure the public balance does not already exist.
+        ensure!(!self.public_balances.contains_key(&address), "Address '{address}' already has a public balance");
+        // Add the public balance.
+        self.public_balances.insert(address, amount);
+        Ok(self)
+    }
+
+    /// Adds an initial program, which is deployed after any previously added programs.
+    pub fn add_program(mut self, program: Program<N>) -> Result<Self> {
+        // Ensure the program does not already exist.
+        ensure!(!self.programs.contains_key(program.id()), "Program '{}' is already an initial program", program.id());
+        // Add the program.
+        self.programs.insert(*program.id(), program);
+        Ok(self)
+    }
+
+    /// Adds an initial entry to the given mapping of an initial program.
+    pub fn add_mapping_entry(
+        mut self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<Self> {
+        // Ensure the mapping exists in an initial program.
+        match self.programs.get(&program_id) {
+            Some(program) => program.get_mapping(&mapping_name)?,
+            None => bail!("Program '{program_id}' is not an initial program"),
+        };
+        // Ensure the key does not already exist.
+        let entries = self.mapping_entries.entry((program_id, mapping_name)).or_default();
+        ensure!(
+            !entries.iter().any(|(existing_key, _)| *existing_key == key),
+            "Key '{key}' already exists in '{program_id}/{mapping_name}'"
+        );
+        // Add the mapping entry.
+        entries.push((key, value));
+        Ok(self)
+    }
+
+    /// Returns the private key of the genesis account.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns the initial programs, in deployment order.
+    pub fn programs(&self) -> impl '_ + ExactSizeIterator<Item = &Program<N>> {
+        self.programs.values()
+    }
+
+    /// Returns the genesis committee.
+    pub fn committee(&self) -> Result<Committee<N>> {
+        Committee::new_genesis(self.validators.iter().map(|(address, stake)| (*address, (*stake, true))).collect())
+    }
+
+    /// Returns the public balances, including the remaining supply assigned to the genesis account.
+    pub fn public_balances(&self) -> Result<IndexMap<Address<N>, u64>> {
+        // Compute the allocated supply.
+        let allocated_supply = self
+            .validators
+            .values()
+            .chain(self.public_balances.values())
+            .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+            .ok_or_else(|| anyhow!("The allocated genesis supply overflowed"))?;
+        // Compute the remaining supply.
+        let remaining_supply = N::STARTING_SUPPLY.checked_sub(allocated_supply).ok_or_else(|| {
+            anyhow!("The allocated genesis supply exceeds the starting supply of {}", N::STARTING_SUPPLY)
+        })?;
+
+        // Assign the remaining supply to the genesis account.
+        let mut public_balances = self.public_balances.clone();
+        let balance = public_balances.entry(Address::try_from(&self.private_key)?).or_default();
+        *balance = balance.checked_add(remaining_supply).ok_or_else(|| anyhow!("The genesis balance overflowed"))?;
+
+        // Ensure the genesis account can fund the genesis transactions.
+        let required_balance = MIN_VALIDATOR_STAKE.saturating_mul(Block::<N>::NUM_GENESIS_TRANSACTIONS as u64);
+        ensure!(
+            *balance >= required_balance,
+            "The genesis account requires a balance of at least {required_balance} to execute the genesis transactions"
+        );
+        Ok(public_balances)
+    }
+
+    /// Returns the genesis block, constructed with the given VM.
+    pub fn build<C: ConsensusStorage<N>, R: Rng + CryptoRng>(&self, vm: &VM<N, C>, rng: &mut R) -> Result<Block<N>> {
+        vm.genesis_quorum(&self.private_key, self.committee()?, self.public_balances()?, rng)
+    }
+
+    /// Writes the initial mapping entries into the given VM, once the initial programs are deployed.
+    pub fn insert_mapping_entries<C: ConsensusStorage<N>>(&self, vm: &VM<N, C>) -> Result<()> {
+        // Retrieve the process.
+        let process = vm.process();
+        let process = process.read();
+
+        for ((program_id, mapping_name), entries) in &self.mapping_entries {
+            // Retrieve the stack and the mapping.
+            let stack = process.get_stack(program_id)?;
+            let mapping = stack.program().get_mapping(mapping_name)?;
+            for (key, value) in entries {
+                // Ensure the key and value match the mapping types.
+                stack.matches_plaintext(key, mapping.key().plaintext_type())?;
+                match value {
+                    Value::Plaintext(plaintext) => {
+                        stack.matches_plaintext(plaintext, mapping.value().plaintext_type())?
+                    }
+                    _ => bail!("The value for '{key}' in '{program_id}/{mapping_name}' must be a plaintext"),
+                }
+                // Insert the mapping entry.
+                vm.finalize_store().insert_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_genesis_builder() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis account and the validators.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let genesis_address = Address::try_from(&private_key).unwrap();
+        let validators = (0..4)
+            .map(|_| Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let account = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Construct the builder.
+        let mut builder = GenesisBuilder::new(private_key);
+        for validator in &validators {
+            builder = builder.add_validator(*validator, MIN_VALIDATOR_STAKE).unwrap();
+        }
+        let builder = builder.add_public_balance(account, 1_000_000).unwrap();
+
+        // Ensure duplicate and insufficient entries are rejected.
+        assert!(builder.clone().add_validator(validators[0], MIN_VALIDATOR_STAKE).is_err());
+        assert!(builder.clone().add_validator(account, MIN_VALIDATOR_STAKE - 1).is_err());
+        assert!(builder.clone().add_public_balance(account, 1).is_err());
+
+        // Check the committee.
+        let committee = builder.committee().unwrap();
+        assert_eq!(committee.members().len(), 4);
+        assert_eq!(committee.total_stake(), 4 * MIN_VALIDATOR_STAKE);
+
+        // Check the public balances, which assign the remaining supply to the genesis account.
+        let public_balances = builder.public_balances().unwrap();
+        assert_eq!(public_balances.len(), 2);
+        assert_eq!(public_balances[&account], 1_000_000);
+        assert_eq!(
+            public_balances[&genesis_address],
+            CurrentNetwork::STARTING_SUPPLY - 4 * MIN_VALIDATOR_STAKE - 1_000_000
+        );
+
+        // Ensure the allocations cannot exceed the starting supply.
+        let builder = builder.add_public_balance(genesis_address, CurrentNetwork::STARTING_SUPPLY).unwrap();
+        assert!(builder.public_balances().is_err());
+    }
+
+    #[test]
+    fn test_genesis_builder_programs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with a mapping.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program registry.aleo;
+mapping entries:
+    key as u8.public;
+    value as u64.public;
+function twice:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        )
+        .unwrap();
+        let program_id = *program.id();
+        let mapping_name = Identifier::from_str("entries").unwrap();
+        let (key, value) = (Plaintext::from_str("1u8").unwrap(), Value::from_str("100u64").unwrap());
+
+        // Construct the builder.
+        let builder = GenesisBuilder::new(PrivateKey::<CurrentNetwork>::new(rng).unwrap());
+        // Ensure a mapping entry requires an initial program.
+        assert!(builder.clone().add_mapping_entry(program_id, mapping_name, key.clone(), value.clone()).is_err());
+
+        let builder = builder.add_program(program.clone()).unwrap();
+        let builder = builder.add_mapping_entry(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        assert_eq!(builder.programs().collect::<Vec<_>>(), vec![&program]);
+
+        // Ensure duplicate programs and keys, and unknown mappings, are rejected.
+        assert!(builder.clone().add_program(program).is_err());
+        assert!(builder.clone().add_mapping_entry(program_id, mapping_name, key.clone(), value.clone()).is_err());
+        let unknown_mapping = Identifier::from_str("unknown").unwrap();
+        assert!(builder.add_mapping_entry(program_id, unknown_mapping, key, value).is_err());
+    }
+}
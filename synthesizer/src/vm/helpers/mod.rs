@@ -18,7 +18,13 @@ pub use committee::*;
 mod cost;
 pub use cost::*;
 
+mod genesis;
+pub use genesis::*;
+
 mod macros;
 
+mod prioritizer;
+pub use prioritizer::*;
+
 mod rewards;
 pub use rewards::*;
@@ -0,0 +1,411 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VM;
+use console::{
+    prelude::*,
+    program::{Argument, Identifier, Plaintext, ProgramID, Register},
+    types::Field,
+};
+use ledger_block::Transaction;
+use ledger_store::ConsensusStorage;
+use synthesizer_program::{Command, Finalize, MappingLocator, Operand};
+
+use indexmap::IndexSet;
+
+/// A mapping access, as `(program ID, mapping name, key ID)`.
+/// The key ID is the hash of the key if the key is statically known, and `None` if the access covers the whole mapping.
+pub type MappingAccess<N> = (ProgramID<N>, Identifier<N>, Option<Field<N>>);
+
+/// The mapping entries that are read and written by the finalize logic of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateAccess<N: Network> {
+    /// The mapping entries that are read.
+    reads: IndexSet<MappingAccess<N>>,
+    /// The mapping entries that are written.
+    writes: IndexSet<MappingAccess<N>>,
+}
+
+impl<N: Network> StateAccess<N> {
+    /// Initializes a new state access, with the given reads and writes.
+    pub fn new(reads: IndexSet<MappingAccess<N>>, writes: IndexSet<MappingAccess<N>>) -> Self {
+        Self { reads, writes }
+    }
+
+    /// Returns the state access of the given transaction, from the finalize logic of its transitions.
+    ///
+    /// The key of an access is statically known if it is a literal, or a finalize input (or a member of one),
+    /// as the finalize inputs are the public arguments of the future of the transition.
+    /// A deployment is considered to write every mapping of its program.
+    pub fn from_transaction<C: ConsensusStorage<N>>(vm: &VM<N, C>, transaction: &Transaction<N>) -> Result<Self> {
+        let mut access = Self::new(Default::default(), Default::default());
+
+        // If the transaction is a deployment, add the mappings of the program as writes.
+        if let Transaction::Deploy(_, _, deployment, _) = transaction {
+            let program = deployment.program();
+            access.writes.extend(program.mappings().keys().map(|mapping_name| (*program.id(), *mapping_name, None)));
+        }
+
+        // Retrieve the process.
+        let process = vm.process();
+        let process = process.read();
+
+        // Add the mapping entries accessed by the finalize logic of each transition.
+        for transition in transaction.transitions() {
+            // Retrieve the program ID and the function.
+            let program_id = transition.program_id();
+            let function = process.get_program(program_id)?.get_function_ref(transition.function_name())?;
+            // If the function does not have a finalize logic, continue.
+            let Some(finalize) = function.finalize_logic() else {
+                continue;
+            };
+            // Retrieve the arguments of the future, which are the finalize inputs.
+            let arguments = match transition.outputs().iter().find_map(|output| output.future()) {
+                Some(future) => future.arguments(),
+                None => &[],
+            };
+            // Resolve the given mapping locator, with respect to the program ID.
+            let resolve = |mapping: &MappingLocator<N>| match mapping {
+                MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+                MappingLocator::Resource(mapping_name) => (*program_id, *mapping_name),
+            };
+            // Returns the mapping access for the given mapping of the program, and key.
+            let entry = |mapping_name: &Identifier<N>, key: &Operand<N>| {
+                mapping_access(finalize, arguments, (*program_id, *mapping_name), key)
+            };
+            for command in finalize.commands() {
+                match command {
+                    Command::Contains(contains) => access.reads.insert(entry(contains.mapping_name(), contains.key())?),
+                    Command::Get(get) => {
+                        access.reads.insert(mapping_access(finalize, arguments, resolve(get.mapping()), get.key())?)
+                    }
+                    Command::GetOrUse(get_or_use) => {
+                        let mapping = resolve(get_or_use.mapping());
+                        access.reads.insert(mapping_access(finalize, arguments, mapping, get_or_use.key())?)
+                    }
+                    Command::Set(set) => access.writes.insert(entry(set.mapping_name(), set.key())?),
+                    Command::Remove(remove) => access.writes.insert(entry(remove.mapping_name(), remove.key())?),
+                    _ => continue,
+                };
+            }
+        }
+        Ok(access)
+    }
+
+    /// Returns the mapping entries that are read.
+    pub const fn reads(&self) -> &IndexSet<MappingAccess<N>> {
+        &self.reads
+    }
+
+    /// Returns the mapping entries that are written.
+    pub const fn writes(&self) -> &IndexSet<MappingAccess<N>> {
+        &self.writes
+    }
+
+    /// Returns `true` if the state access conflicts with the given state access,
+    /// which is the case if either one writes a mapping entry that the other reads or writes.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.writes.iter().any(|write| other.reads.iter().chain(&other.writes).any(|access| overlaps(write, access)))
+            || other.writes.iter().any(|write| self.reads.iter().any(|access| overlaps(write, access)))
+    }
+}
+
+/// Returns `true` if the given mapping accesses may refer to the same mapping entry.
+fn overlaps<N: Network>(a: &MappingAccess<N>, b: &MappingAccess<N>) -> bool {
+    a.0 == b.0 && a.1 == b.1 && (a.2.is_none() || b.2.is_none() || a.2 == b.2)
+}
+
+/// Returns the mapping access for the given mapping and key operand, in the given finalize logic.
+fn mapping_access<N: Network>(
+    finalize: &Finalize<N>,
+    arguments: &[Argument<N>],
+    (program_id, mapping_name): (ProgramID<N>, Identifier<N>),
+    key: &Operand<N>,
+) -> Result<MappingAccess<N>> {
+    // Compute the key ID, if the key is statically known.
+    let key_id = match static_key(finalize, arguments, key) {
+        Some(key) => Some(N::hash_bhp1024(&key.to_bits_le())?),
+        None => None,
+    };
+    Ok((program_id, mapping_name, key_id))
+}
+
+/// Returns the value of the given key operand, if it is statically known from the finalize inputs.
+fn static_key<N: Network>(finalize: &Finalize<N>, arguments: &[Argument<N>], key: &Operand<N>) -> Option<Plaintext<N>> {
+    match key {
+        Operand::Literal(literal) => Some(Plaintext::from(literal)),
+        Operand::Register(register) => {
+            // Retrieve the finalize input argument for the register.
+            let argument = finalize
+                .inputs()
+                .iter()
+                .zip(arguments)
+                .find(|(input, _)| input.register().locator() == register.locator())
+                .map(|(_, argument)| argument);
+            match (argument, register) {
+                (Some(Argument::Plaintext(plaintext)), Register::Locator(..)) => Some(plaintext.clone()),
+                (Some(Argument::Plaintext(plaintext)), Register::Access(_, path)) => plaintext.find(path).ok(),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Orders candidate transactions for a block producer, by fee density and state conflicts.
+pub struct Prioritizer<N: Network> {
+    /// The candidate transactions, with their fee in microcredits, size in bytes, and state access.
+    candidates: Vec<(Transaction<N>, u64, u64, StateAccess<N>)>,
+    /// The candidate transactions that could not be prioritized, with the reason.
+    skipped: Vec<(Transaction<N>, String)>,
+}
+
+impl<N: Network> Prioritizer<N> {
+    /// Initializes a new prioritizer for the given candidate transactions.
+    /// A candidate whose fee, size, or state access cannot be computed is skipped, and reported in `skipped`.
+    pub fn new<C: ConsensusStorage<N>>(vm: &VM<N, C>, transactions: Vec<Transaction<N>>) -> Self {
+        let mut candidates = Vec::with_capacity(transactions.len());
+        let mut skipped = Vec::new();
+        for transaction in transactions {
+            match Self::prepare_candidate(vm, &transaction) {
+                Ok((fee, size_in_bytes, access)) => candidates.push((transaction, fee, size_in_bytes, access)),
+                Err(error) => skipped.push((transaction, error.to_string())),
+            }
+        }
+        Self { candidates, skipped }
+    }
+
+    /// Returns the candidate transactions that could not be prioritized, with the reason.
+    pub fn skipped(&self) -> &[(Transaction<N>, String)] {
+        &self.skipped
+    }
+
+    /// Returns the fee, size in bytes, and state access of the given candidate transaction.
+    fn prepare_candidate<C: ConsensusStorage<N>>(
+        vm: &VM<N, C>,
+        transaction: &Transaction<N>,
+    ) -> Result<(u64, u64, StateAccess<N>)> {
+        let fee = *transaction.fee_amount()?;
+        let size_in_bytes = u64::try_from(transaction.to_bytes_le()?.len())?;
+        let access = StateAccess::from_transaction(vm, transaction)?;
+        Ok((fee, size_in_bytes, access))
+    }
+
+    /// Returns the candidate transactions as an executable schedule, which is a sequence of batches.
+    ///
+    /// The transactions are taken in order of decreasing fee density (fee per byte), with ties broken by transaction ID.
+    /// Transactions in the same batch do not conflict, and each transaction is scheduled in a later batch
+    /// than every higher-priority transaction it conflicts with.
+    pub fn schedule(self) -> Vec<Vec<Transaction<N>>> {
+        // Compute the batch index of each candidate.
+        let entries = self
+            .candidates
+            .iter()
+            .map(|(transaction, fee, size_in_bytes, access)| (transaction.id(), *fee, *size_in_bytes, access))
+            .collect::<Vec<_>>();
+        let batches = schedule(&entries);
+
+        // Move the transactions into their batches.
+        let mut candidates = self.candidates.into_iter().map(|(transaction, ..)| Some(transaction)).collect::<Vec<_>>();
+        batches
+            .into_iter()
+            .map(|batch| batch.into_iter().filter_map(|index| candidates[index].take()).collect())
+            .collect()
+    }
+}
+
+/// Returns the indices of the given entries `(transaction ID, fee, size in bytes, state access)`, grouped into batches.
+fn schedule<N: Network>(entries: &[(N::TransactionID, u64, u64, &StateAccess<N>)]) -> Vec<Vec<usize>> {
+    // Order the entries by decreasing fee density, with ties broken by transaction ID.
+    // Note: The fee densities are compared as `fee_a * size_b` against `fee_b * size_a`, to avoid floating point.
+    let mut order = (0..entries.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| {
+        let (id_a, fee_a, size_a, _) = entries[*a];
+        let (id_b, fee_b, size_b, _) = entries[*b];
+        let density_a = (fee_a as u128) * (size_b.max(1) as u128);
+        let density_b = (fee_b as u128) * (size_a.max(1) as u128);
+        density_b.cmp(&density_a).then_with(|| id_a.cmp(&id_b))
+    });
+
+    // Assign each entry to the batch after the last batch containing a conflicting entry.
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    for index in order {
+        let access = entries[index].3;
+        let batch_index = batches
+            .iter()
+            .rposition(|batch| batch.iter().any(|other| entries[*other].3.conflicts_with(access)))
+            .map_or(0, |position| position + 1);
+        match batches.get_mut(batch_index) {
+            Some(batch) => batch.push(index),
+            None => batches.push(vec![index]),
+        }
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_deployment_transaction,
+        sample_execution_transaction_with_public_fee,
+        sample_genesis_private_key,
+        sample_vm_with_genesis_block,
+    };
+    use console::{
+        account::Address,
+        network::Testnet3,
+        program::{Literal, Value},
+    };
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a state access with the given reads and writes, on mappings of 'test.aleo'.
+    /// Each access is either a mapping name, such as `a`, or a mapping name with a key ID, such as `a[1]`.
+    fn sample_access(reads: &[&str], writes: &[&str]) -> StateAccess<CurrentNetwork> {
+        let program_id = ProgramID::from_str("test.aleo").unwrap();
+        let to_accesses = |accesses: &[&str]| {
+            accesses
+                .iter()
+                .map(|access| match access.split_once('[') {
+                    Some((name, key)) => {
+                        let key_id = Field::from_u8(key.trim_end_matches(']').parse().unwrap());
+                        (program_id, Identifier::from_str(name).unwrap(), Some(key_id))
+                    }
+                    None => (program_id, Identifier::from_str(access).unwrap(), None),
+                })
+                .collect::<IndexSet<_>>()
+        };
+        StateAccess::new(to_accesses(reads), to_accesses(writes))
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let read_a = sample_access(&["a"], &[]);
+        let write_a = sample_access(&[], &["a"]);
+        let write_b = sample_access(&["a"], &["b"]);
+
+        assert!(!read_a.conflicts_with(&read_a));
+        assert!(read_a.conflicts_with(&write_a));
+        assert!(write_a.conflicts_with(&read_a));
+        assert!(write_a.conflicts_with(&write_a));
+        assert!(write_a.conflicts_with(&write_b));
+        assert!(!read_a.conflicts_with(&write_b));
+    }
+
+    #[test]
+    fn test_conflicts_with_keys() {
+        let write_a1 = sample_access(&["a[1]"], &["a[1]"]);
+        let write_a2 = sample_access(&["a[2]"], &["a[2]"]);
+        let read_a = sample_access(&["a"], &[]);
+
+        // Ensure writes to distinct keys of the same mapping do not conflict.
+        assert!(!write_a1.conflicts_with(&write_a2));
+        assert!(write_a1.conflicts_with(&write_a1));
+        // Ensure an access with an unknown key conflicts with every key of the mapping.
+        assert!(write_a1.conflicts_with(&read_a));
+        assert!(read_a.conflicts_with(&write_a2));
+    }
+
+    #[test]
+    fn test_from_transaction() {
+        let rng = &mut TestRng::default();
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Ensure a public transfer with a public fee accesses only the balance of the caller.
+        // Note: The sample transaction transfers from the caller to the caller.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        let address = Address::try_from(&sample_genesis_private_key(rng)).unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        let key_id = <CurrentNetwork as Network>::hash_bhp1024(&key.to_bits_le()).unwrap();
+        let account = (ProgramID::from_str("credits.aleo").unwrap(), Identifier::from_str("account").unwrap());
+        let expected = IndexSet::from([(account.0, account.1, Some(key_id))]);
+
+        let access = StateAccess::from_transaction(&vm, &transaction).unwrap();
+        assert_eq!(access.reads(), &expected);
+        assert_eq!(access.writes(), &expected);
+
+        // Ensure a deployment writes every mapping of its program.
+        let transaction = sample_deployment_transaction(rng);
+        let access = StateAccess::from_transaction(&vm, &transaction).unwrap();
+        let mapping = (ProgramID::from_str("testing.aleo").unwrap(), Identifier::from_str("account").unwrap(), None);
+        assert!(access.writes().contains(&mapping));
+    }
+
+    #[test]
+    fn test_prioritizer_new() {
+        let rng = &mut TestRng::default();
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Construct an execution of a program that is not in the VM.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+function twice:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        )
+        .unwrap();
+        let other_vm = sample_vm_with_genesis_block(rng);
+        other_vm.process().write().add_program(&program).unwrap();
+        let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()].into_iter();
+        let private_key = sample_genesis_private_key(rng);
+        let unknown = other_vm.execute(&private_key, ("unknown.aleo", "twice"), inputs, None, 0, None, rng).unwrap();
+
+        // Initialize the prioritizer.
+        let transactions = vec![
+            sample_execution_transaction_with_public_fee(rng),
+            unknown.clone(),
+            sample_deployment_transaction(rng),
+        ];
+        let prioritizer = Prioritizer::new(&vm, transactions);
+
+        // Ensure the invalid candidate is skipped, and the remaining candidates are scheduled.
+        assert_eq!(prioritizer.skipped().len(), 1);
+        assert_eq!(prioritizer.skipped()[0].0.id(), unknown.id());
+        let schedule = prioritizer.schedule();
+        assert_eq!(schedule.len(), 1);
+        assert_eq!(schedule[0].len(), 2);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut rng = TestRng::default();
+        let ids = (0..5).map(|_| Field::<CurrentNetwork>::rand(&mut rng).into()).collect::<Vec<_>>();
+
+        let write_a = sample_access(&[], &["a"]);
+        let read_a = sample_access(&["a"], &[]);
+        let write_b = sample_access(&[], &["b"]);
+        let none = sample_access(&[], &[]);
+
+        let entries = vec![
+            // Index 0: The lowest fee density.
+            (ids[0], 10, 100, &none),
+            // Index 1: The highest fee density.
+            (ids[1], 100, 10, &write_a),
+            // Index 2: Conflicts with index 1.
+            (ids[2], 50, 10, &write_a),
+            // Index 3: Conflicts with index 2, by a read.
+            (ids[3], 40, 10, &read_a),
+            // Index 4: Does not conflict with any entry.
+            (ids[4], 60, 10, &write_b),
+        ];
+        assert_eq!(schedule::<CurrentNetwork>(&entries), vec![vec![1, 4, 0], vec![2], vec![3]]);
+
+        // Ensure an empty list of entries produces an empty schedule.
+        assert!(schedule::<CurrentNetwork>(&[]).is_empty());
+    }
+}
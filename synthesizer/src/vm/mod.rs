@@ -19,13 +19,14 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod simulate;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
     types::{Field, Group, U64},
 };
 use ledger_block::{
@@ -40,6 +41,7 @@ use ledger_block::{
     Rejected,
     Transaction,
     Transactions,
+    Transition,
 };
 use ledger_coinbase::CoinbaseSolution;
 use ledger_committee::Committee;
@@ -56,7 +58,14 @@ use ledger_store::{
     TransitionStore,
 };
 use synthesizer_process::{Authorization, Process, Trace};
-use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_program::{
+    FinalizeGlobalState,
+    FinalizeOperation,
+    FinalizeStoreTrait,
+    Program,
+    ProgramPolicy,
+    StateDiff,
+};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::{IndexMap, IndexSet};
@@ -199,6 +208,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.clone()
     }
 
+    /// Sets the program policy, which applies to the deployments created with `VM::deploy`,
+    /// and to the transactions checked with `VM::check_policy`.
+    #[inline]
+    pub fn set_policy(&self, policy: ProgramPolicy) {
+        self.process.write().set_policy(policy)
+    }
+
+    /// Ensures the program of the given deployment transaction satisfies the program policy.
+    /// This check is meant for mempool admission, and is deliberately not part of `VM::check_transaction`,
+    /// as a block that is valid for the network must not be rejected because of a local policy.
+    #[inline]
+    pub fn check_policy(&self, transaction: &Transaction<N>) -> Result<()> {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => self.process.read().policy().check(deployment.program()),
+            _ => Ok(()),
+        }
+    }
+
     /// Returns the partially-verified transactions.
     #[inline]
     pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, ()>>> {
@@ -339,6 +366,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -352,7 +380,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
         match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
-            Ok(_ratified_finalize_operations) => {
+            Ok((_ratified_finalize_operations, _state_diffs)) => {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
                 #[cfg(feature = "rocks")]
                 self.block_store().unpause_atomic_writes::<false>()?;
@@ -411,7 +439,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates a call to the program function against the current state of the VM.
+    /// Returns the response and the state diffs the call **would** produce.
+    ///
+    /// The call is executed without computing a proof, and its finalize logic is run in dry-run mode,
+    /// so the VM state is never mutated. This is intended for read-only queries and for previewing
+    /// the effect of a transaction before paying to broadcast it.
+    ///
+    /// Note: The simulation does not check that the input records exist or are unspent,
+    /// and does not include a fee.
+    pub fn simulate<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        state: FinalizeGlobalState,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Vec<StateDiff<N>>)> {
+        let timer = timer!("VM::simulate");
+
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        lap!(timer, "Compute the authorization");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Execute the call, without preparing the assignments or computing the proof.
+                let (response, trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                // Retrieve the (unproven) transitions.
+                let transitions = trace.transitions().to_vec();
+                // Return the response and the transitions.
+                Ok::<_, Error>((
+                    cast_ref!(response as Response<N>).clone(),
+                    cast_ref!(transitions as Vec<Transition<N>>).clone(),
+                ))
+            }};
+        }
+
+        // Execute the authorization.
+        let (response, transitions) = process!(self, logic)?;
+        lap!(timer, "Execute the call");
+
+        // Construct the execution, without a proof.
+        let execution = Execution::from(transitions.into_iter(), self.block_store().current_state_root(), None)?;
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Perform the finalize operations in dry-run mode, so that they are never committed.
+        let (_, state_diffs) = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Finalize the execution.
+            self.process
+                .read()
+                .finalize_execution_with_state_diffs(state, self.finalize_store(), &execution, None)
+                .map_err(|e| format!("Failed to finalize the simulated execution - {e}"))
+        })?;
+
+        finish!(timer, "Simulate the call");
+
+        // Return the response and the state diffs.
+        Ok((response, state_diffs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::sample_finalize_state;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_simulate_transfer_public() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+        // Initialize a new recipient.
+        let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Retrieve the public balance of the given address.
+        let balance = |address: &Address<CurrentNetwork>| {
+            vm.finalize_store()
+                .get_value_confirmed(
+                    ProgramID::from_str("credits.aleo").unwrap(),
+                    Identifier::from_str("account").unwrap(),
+                    &Plaintext::from(Literal::Address(*address)),
+                )
+                .unwrap()
+        };
+        let caller_balance = balance(&caller_address);
+        assert!(caller_balance.is_some());
+        assert!(balance(&recipient_address).is_none());
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&recipient_address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Simulate the call.
+        let (response, state_diffs) = vm
+            .simulate(&caller_private_key, ("credits.aleo", "transfer_public"), inputs, sample_finalize_state(1), rng)
+            .unwrap();
+
+        // Ensure the response contains the future.
+        assert_eq!(response.outputs().len(), 1);
+        // Ensure the caller balance is updated, and the recipient balance is inserted.
+        assert_eq!(state_diffs.len(), 2);
+        assert_eq!(state_diffs[0].key(), &Plaintext::from(Literal::Address(caller_address)));
+        assert_eq!(state_diffs[0].old_value(), &caller_balance);
+        assert!(state_diffs[1].is_insert());
+        assert_eq!(state_diffs[1].key(), &Plaintext::from(Literal::Address(recipient_address)));
+        assert_eq!(state_diffs[1].new_value(), &Some(Value::from_str("1u64").unwrap()));
+
+        // Ensure the state is unchanged.
+        assert_eq!(balance(&caller_address), caller_balance);
+        assert!(balance(&recipient_address).is_none());
+    }
+
+    #[test]
+    fn test_simulate_fails_on_invalid_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller, without a public balance.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&caller_address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Ensure the simulation fails, as the caller does not have a public balance.
+        let locator = ("credits.aleo", "transfer_public");
+        let result = vm.simulate(&caller_private_key, locator, inputs, sample_finalize_state(1), rng);
+        assert!(result.is_err());
+    }
+}
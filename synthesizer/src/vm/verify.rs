@@ -374,6 +374,24 @@ mod tests {
         vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_policy() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction, whose program uses the `cast` opcode.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        // Disallow the `cast` opcode.
+        vm.set_policy(ProgramPolicy::new().disallow_opcode("cast"));
+
+        // Ensure the transaction is not admitted under the policy.
+        assert!(vm.check_policy(&deployment_transaction).is_err());
+        // Ensure the transaction still verifies, as the policy does not apply to consensus.
+        vm.check_transaction(&deployment_transaction, None, rng).unwrap();
+        // Ensure the program cannot be deployed under the policy.
+        assert!(vm.deploy_raw(&crate::vm::test_helpers::sample_program(), rng).is_err());
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
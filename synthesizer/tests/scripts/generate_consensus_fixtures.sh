@@ -0,0 +1,34 @@
+#!/bin/bash
+# Emits the consensus fixtures from a previous release of snarkVM.
+#
+# The harness and test cases of the current tree are copied into a worktree of the given release,
+# where the harness is run with `REWRITE_EXPECTATIONS=1`. The emitted fixtures are copied back into
+# `expectations/consensus`, so the current build can be checked against the previous release.
+#
+# Usage: ./scripts/generate_consensus_fixtures.sh <release tag or commit>
+
+set -euo pipefail
+
+RELEASE=${1:?"Usage: $0 <release tag or commit>"}
+
+TESTS_DIR=$(cd "$(dirname "$0")/.." && pwd)
+REPO_DIR=$(git -C "$TESTS_DIR" rev-parse --show-toplevel)
+WORKTREE=$(mktemp -d)
+
+# Create a worktree for the release, and remove it on exit.
+git -C "$REPO_DIR" worktree add --detach "$WORKTREE" "$RELEASE"
+trap 'git -C "$REPO_DIR" worktree remove --force "$WORKTREE"' EXIT
+
+# Copy the harness, its utilities, and the test cases into the release.
+RELEASE_TESTS_DIR="$WORKTREE/synthesizer/tests"
+mkdir -p "$RELEASE_TESTS_DIR/tests/consensus" "$RELEASE_TESTS_DIR/expectations/consensus"
+cp "$TESTS_DIR/test_consensus_semantics.rs" "$RELEASE_TESTS_DIR/"
+cp -r "$TESTS_DIR/utilities/." "$RELEASE_TESTS_DIR/utilities/"
+cp -r "$TESTS_DIR/tests/consensus/." "$RELEASE_TESTS_DIR/tests/consensus/"
+
+# Run the harness against the release, emitting the fixtures.
+(cd "$WORKTREE/synthesizer" && REWRITE_EXPECTATIONS=1 cargo test --release --test test_consensus_semantics)
+
+# Copy the fixtures back into the current tree.
+cp -r "$RELEASE_TESTS_DIR/expectations/consensus/." "$TESTS_DIR/expectations/consensus/"
+echo "Emitted the consensus fixtures from '$RELEASE' into '$TESTS_DIR/expectations/consensus'."
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod utilities;
+
+use console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, ProgramID, RegisterType, Value},
+    types::Boolean,
+};
+use synthesizer_process::{Process, Stack, StackProgramTypes};
+use synthesizer_program::{InstructionTrait, Program};
+use utilities::*;
+
+use rayon::prelude::*;
+
+/// The ID of the program that wraps each instruction.
+const PROGRAM_ID: &str = "semantics.aleo";
+/// The name of the function that wraps each instruction.
+const FUNCTION_NAME: &str = "run";
+/// The private key that authorizes each case.
+const PRIVATE_KEY: &str = "APrivateKey1zkpFbGDx4znwxo1zrxfUscfGn1Vy3My3ia5gRHx3XwaLtCR";
+
+#[test]
+fn test_consensus_semantics() {
+    // Load the tests.
+    let tests = load_tests::<_, ConsensusTest>("./tests/consensus", "./expectations/consensus");
+    // Initialize a process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Run each test and compare it against its corresponding expectation.
+    tests.par_iter().for_each(|test| {
+        // Run each case.
+        let output = test.cases().iter().map(|case| run_case(&process, case)).collect::<serde_yaml::Sequence>();
+        // Check against the expected fixtures.
+        test.check(&output).unwrap();
+        // Save the fixtures.
+        test.save(&output).unwrap();
+    });
+}
+
+// A helper function to run a case and emit its fixture as YAML, to be compared against the expectation.
+fn run_case(process: &Process<CurrentNetwork>, case: &serde_yaml::Value) -> serde_yaml::Value {
+    // Extract the instruction and inputs.
+    let case = case.as_mapping().expect("expected mapping for test case");
+    let instruction = case
+        .get("instruction")
+        .expect("expected instruction for test case")
+        .as_str()
+        .expect("expected string for instruction");
+    let inputs = case
+        .get("inputs")
+        .expect("expected inputs for test case")
+        .as_sequence()
+        .expect("expected sequence for inputs")
+        .iter()
+        .map(|input| match &input {
+            serde_yaml::Value::Bool(bool) => Value::<CurrentNetwork>::from(Literal::Boolean(Boolean::new(*bool))),
+            _ => Value::<CurrentNetwork>::from_str(input.as_str().expect("expected string for input"))
+                .expect("unable to parse input"),
+        })
+        .collect_vec();
+
+    // Initialize the fixture with the case.
+    let mut fixture = serde_yaml::Mapping::new();
+    fixture.insert("instruction".into(), instruction.into());
+    let fixture_inputs = inputs.iter().map(|input| input.to_string().into()).collect();
+    fixture.insert("inputs".into(), serde_yaml::Value::Sequence(fixture_inputs));
+
+    // Run the case, recording the outputs and the constraint digest.
+    match run_instruction(process.clone(), instruction, &inputs) {
+        Ok((outputs, num_constraints, constraint_digest)) => {
+            let outputs = outputs.iter().map(|output| output.to_string().into()).collect();
+            fixture.insert("outputs".into(), serde_yaml::Value::Sequence(outputs));
+            fixture.insert("num_constraints".into(), num_constraints.into());
+            fixture.insert("constraint_digest".into(), constraint_digest.into());
+        }
+        Err(error) => {
+            fixture.insert("error".into(), error.to_string().into());
+        }
+    }
+    serde_yaml::Value::Mapping(fixture)
+}
+
+// A helper function to wrap the instruction in a function, and return its outputs, number of constraints,
+// and constraint digest. The digest is the ID of the circuit, which commits to the constraint matrices.
+fn run_instruction(
+    mut process: Process<CurrentNetwork>,
+    instruction: &str,
+    inputs: &[Value<CurrentNetwork>],
+) -> Result<(Vec<Value<CurrentNetwork>>, usize, String)> {
+    // Declare each input as a private input of its literal type.
+    let declarations = inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| match input {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) => {
+                Ok(format!("    input r{index} as {}.private;\n", literal.to_type()))
+            }
+            _ => bail!("Input '{input}' is not a literal"),
+        })
+        .collect::<Result<String>>()?;
+    let source = |outputs: &str| {
+        format!("program {PROGRAM_ID};\n\nfunction {FUNCTION_NAME}:\n{declarations}    {instruction}\n{outputs}")
+    };
+
+    // Construct the program without outputs, to determine the types of the destinations.
+    let program = Program::<CurrentNetwork>::from_str(&source(""))?;
+    let function_name = Identifier::from_str(FUNCTION_NAME)?;
+    let stack = Stack::new(&process, &program)?;
+    let register_types = stack.get_register_types(&function_name)?;
+    let function = program.get_function_ref(&function_name)?;
+    let Some(wrapped) = function.instructions().last() else { bail!("Expected one instruction, found none") };
+    let outputs = wrapped
+        .destinations()
+        .iter()
+        .map(|destination| match register_types.get_type(&stack, destination)? {
+            RegisterType::Plaintext(plaintext_type) => {
+                Ok(format!("    output {destination} as {plaintext_type}.private;\n"))
+            }
+            register_type => bail!("Destination '{destination}' has unsupported type '{register_type}'"),
+        })
+        .collect::<Result<String>>()?;
+
+    // Construct the program with outputs, and add it to the process.
+    let program = Program::<CurrentNetwork>::from_str(&source(&outputs))?;
+    process.add_program(&program)?;
+    let program_id = ProgramID::<CurrentNetwork>::from_str(PROGRAM_ID)?;
+
+    // Evaluate the function.
+    let rng = &mut TestRng::fixed(0);
+    let private_key = PrivateKey::<CurrentNetwork>::from_str(PRIVATE_KEY)?;
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&private_key, program_id, function_name, inputs.iter(), rng)?;
+    let response = process.evaluate::<CurrentAleo>(authorization)?;
+
+    // Synthesize the circuit, to determine the number of constraints and the constraint digest.
+    process.synthesize_key::<CurrentAleo, _>(&program_id, &function_name, rng)?;
+    let verifying_key = process.get_verifying_key(program_id, function_name)?;
+
+    Ok((response.outputs().to_vec(), verifying_key.circuit_info.num_constraints, verifying_key.id.to_string()))
+}
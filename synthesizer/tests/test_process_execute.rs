@@ -44,6 +44,31 @@ fn test_process_execute() {
     });
 }
 
+#[test]
+fn test_process_execute_stdlib() {
+    // Load the tests.
+    let tests = load_tests::<_, ProgramTest>("./tests/process/stdlib", "./expectations/process/stdlib");
+    // Initialize a process with the standard library.
+    let process = sample_process_with_stdlib();
+
+    // Run each test and compare it against its corresponding expectation.
+    tests.par_iter().for_each(|test| {
+        // Run the test.
+        let output = run_test(process.clone(), test);
+        // Check against the expected output.
+        test.check(&output).unwrap();
+        // Save the output.
+        test.save(&output).unwrap();
+    });
+}
+
+// A helper function to initialize a process with the standard library, so that tests can import it.
+fn sample_process_with_stdlib() -> Process<CurrentNetwork> {
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    process.add_stdlib().unwrap();
+    process
+}
+
 // A helper function to run the test and extract the outputs as YAML, to be compared against the expectation.
 fn run_test(process: Process<CurrentNetwork>, test: &ProgramTest) -> serde_yaml::Mapping {
     // Initialize the output.
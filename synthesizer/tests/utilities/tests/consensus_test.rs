@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{get_expectation_path, print_difference, ExpectedTest};
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use serde_yaml::{Sequence, Value};
+use std::path::{Path, PathBuf};
+
+/// A set of semantic fixtures for instructions.
+/// The test file lists the cases, each with an `instruction` and its `inputs`.
+/// The expectation file is the emitted fixture, which records the `outputs`, the `num_constraints`,
+/// and the `constraint_digest` of each case, as produced by the release that generated it.
+pub struct ConsensusTest {
+    /// The set of test cases.
+    cases: Sequence,
+    /// The expected fixtures.
+    expected: Sequence,
+    /// The path to the expectation file.
+    path: PathBuf,
+    /// Whether the expectation file should be rewritten.
+    rewrite: bool,
+}
+
+impl ConsensusTest {
+    /// Returns the test cases.
+    pub fn cases(&self) -> &[Value] {
+        &self.cases
+    }
+}
+
+impl ExpectedTest for ConsensusTest {
+    type Output = Sequence;
+
+    /// Loads the test from a given path.
+    fn load<P: AsRef<Path>>(test_path: P, expectation_dir: P) -> Self {
+        // Check if the expectation file should be rewritten.
+        let rewrite = std::env::var("REWRITE_EXPECTATIONS").is_ok();
+
+        // Read the test cases.
+        let source = std::fs::read_to_string(&test_path).expect("Failed to read test file.");
+        let cases = serde_yaml::from_str::<Sequence>(&source).expect("test file must be a sequence of cases");
+
+        // Construct the path to the expectation file.
+        let path = get_expectation_path(&test_path, &expectation_dir);
+        // If the expectation file should be rewritten, then there is no need to read the expectation file.
+        let expected = match rewrite {
+            true => Sequence::new(),
+            false => {
+                let source = std::fs::read_to_string(&path).expect("Failed to read expectation file.");
+                serde_yaml::from_str::<Sequence>(&source).expect("invalid expectation")
+            }
+        };
+
+        Self { cases, expected, path, rewrite }
+    }
+
+    /// Checks the fixtures against the given output.
+    /// Each field in an expected fixture must match the output, while fields absent from the fixture are not checked.
+    fn check(&self, output: &Self::Output) -> Result<()> {
+        // Initialize space to accumulate failed tests.
+        let mut failed = Vec::new();
+        // If the expectation file should be rewritten, then there is no need to check the output.
+        if !self.rewrite {
+            self.cases.iter().zip_eq(self.expected.iter().zip_eq(output.iter())).for_each(
+                |(test, (expected, actual))| {
+                    let is_match = match (expected, actual) {
+                        (Value::Mapping(expected), Value::Mapping(actual)) => {
+                            expected.iter().all(|(key, value)| actual.get(key) == Some(value))
+                        }
+                        (expected, actual) => expected == actual,
+                    };
+                    if !is_match {
+                        let test = serde_yaml::to_string(test).expect("failed to serialize test to string");
+                        let expected =
+                            serde_yaml::to_string(expected).expect("failed to serialize expected output to string");
+                        let actual =
+                            serde_yaml::to_string(actual).expect("failed to serialize actual output to string");
+                        failed.push(print_difference(test, expected, actual));
+                    }
+                },
+            );
+        }
+        // Write the errors, if any.
+        match failed.is_empty() {
+            true => Ok(()),
+            false => bail!("{}", failed.iter().join("\n\n")),
+        }
+    }
+
+    fn save(&self, output: &Self::Output) -> Result<()> {
+        if self.rewrite {
+            std::fs::write(&self.path, serde_yaml::to_string(&output).expect("failed to serialize output to string"))?;
+        }
+        Ok(())
+    }
+}
@@ -26,7 +26,7 @@ pub struct Build {
 }
 
 impl Build {
-    /// Compiles an Aleo program with the specified name.
+    /// Builds an Aleo program, and packages it with its circuit keys and ABI descriptor into a bundle.
     pub fn parse(self) -> Result<String> {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
@@ -34,20 +34,32 @@ impl Build {
         // Load the package.
         let package = Package::open(&path)?;
 
-        println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
-
-        // Build the package, if the package requires building.
-        package.build::<Aleo>(self.endpoint)?;
-
-        // package.build::<Aleo>(match self.offline {
-        //     true => None,
-        //     false => Some(endpoint.unwrap_or("https://vm.aleo.org/testnet3/build".to_string())),
-        // })?;
+        // Build and bundle the package.
+        let bundle_file = package.bundle::<Aleo>(self.endpoint)?;
 
         // Prepare the path string.
-        let path_string = format!("(in \"{}\")", path.display());
+        let path_string = format!("(in \"{}\")", package.build_directory().display());
 
         // Log the build as successful.
-        Ok(format!("✅ Built '{}' {}", package.program_id().to_string().bold(), path_string.dimmed()))
+        Ok(format!("✅ Bundled '{}' {}", format!("{}.bundle", bundle_file.file_name()).bold(), path_string.dimmed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_build() {
+        let arg_vec = vec!["snarkvm", "build", "--offline"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Build(build) = cli.command {
+            assert!(build.offline);
+            assert!(build.endpoint.is_none());
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
     }
 }
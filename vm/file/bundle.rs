@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    prelude::{FromBytes, Network, ProgramID, ToBytes},
+    synthesizer::{process::CompiledProgram, Program},
+};
+
+use anyhow::{anyhow, ensure, Result};
+use std::{
+    fs::{self, File},
+    io::{BufReader, Read, Write},
+    path::Path,
+};
+
+static BUNDLE_FILE_EXTENSION: &str = "bundle";
+
+/// A distributable bundle of a program, containing its compiled artifact (the program, its analysis, and the
+/// circuit keys of its functions), the imported programs it was compiled with, and an ABI descriptor of its functions.
+///
+/// The bundle is written to the build directory as `{program_id}.bundle`, in the following format:
+///     program || number of imports (u16) || imported programs || ABI length (u32) || ABI (JSON, UTF-8)
+///         || compiled program (see `CompiledProgram::write_le`)
+///
+/// The program and imported programs form the header of the bundle, which is read on its own
+/// to check if the bundle is current, before the circuit keys are read.
+pub struct BundleFile<N: Network> {
+    /// The file name (without the extension).
+    file_name: String,
+    /// The imported programs.
+    imports: Vec<Program<N>>,
+    /// The ABI descriptor.
+    abi: serde_json::Value,
+    /// The compiled program.
+    compiled: CompiledProgram<N>,
+}
+
+impl<N: Network> BundleFile<N> {
+    /// Creates a new bundle file, given the directory path, compiled program, and the imported programs.
+    pub fn create(directory: &Path, compiled: CompiledProgram<N>, imports: Vec<Program<N>>) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The build directory does not exist: '{}'", directory.display());
+        // Ensure the program name is valid.
+        ensure!(
+            !Program::is_reserved_keyword(compiled.program_id().name()),
+            "Program name is invalid (reserved): {}",
+            compiled.program_id()
+        );
+        // Ensure every function has a proving key, so the bundle can be executed.
+        for function_name in compiled.program().functions().keys() {
+            ensure!(
+                compiled.proving_keys().contains_key(function_name),
+                "The compiled program is missing the proving key for '{function_name}'"
+            );
+        }
+
+        // Ensure the imported programs match the program imports, in order.
+        ensure!(
+            compiled.program().imports().keys().eq(imports.iter().map(Program::id)),
+            "The imported programs do not match the imports of '{}'",
+            compiled.program_id()
+        );
+
+        // Create the candidate bundle file.
+        let file_name = compiled.program_id().to_string();
+        let abi = Self::derive_abi(compiled.program());
+        let bundle_file = Self { file_name, imports, abi, compiled };
+
+        // Construct the file path.
+        let path = directory.join(format!("{}.{BUNDLE_FILE_EXTENSION}", bundle_file.file_name));
+        // Write the file (overwriting if it already exists).
+        File::create(&path)?.write_all(&bundle_file.to_bytes()?)?;
+
+        // Attempt to load the bundle file.
+        Self::from_filepath(&path)
+    }
+
+    /// Opens the bundle file, given the directory path and program ID.
+    pub fn open(directory: &Path, program_id: &ProgramID<N>) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The build directory does not exist: '{}'", directory.display());
+
+        // Construct the file path.
+        let path = directory.join(format!("{program_id}.{BUNDLE_FILE_EXTENSION}"));
+        // Ensure the file path exists.
+        ensure!(path.exists(), "The bundle file is missing: '{}'", path.display());
+
+        // Load the bundle file.
+        let bundle_file = Self::from_filepath(&path)?;
+
+        // Ensure the program ID matches.
+        ensure!(
+            bundle_file.compiled.program_id() == program_id,
+            "The bundle file for '{program_id}' contains an incorrect program ID of '{}'",
+            bundle_file.compiled.program_id()
+        );
+
+        Ok(bundle_file)
+    }
+
+    /// Returns `true` if the bundle file for the given program ID exists at the given directory.
+    pub fn exists_at(directory: &Path, program_id: &ProgramID<N>) -> bool {
+        // Construct the file path.
+        let path = directory.join(format!("{program_id}.{BUNDLE_FILE_EXTENSION}"));
+        // Ensure the path is well-formed.
+        Self::check_path(&path).is_ok() && path.exists()
+    }
+
+    /// Returns `true` if the bundle file for the given program exists at the given directory,
+    /// and was bundled from the given program and imported programs.
+    /// Note: Only the header of the bundle file is read, so this check does not read the circuit keys.
+    pub fn is_current(directory: &Path, program: &Program<N>, imports: &[Program<N>]) -> bool {
+        // Ensure the bundle file exists.
+        if !Self::exists_at(directory, program.id()) {
+            return false;
+        }
+        // Construct the file path.
+        let path = directory.join(format!("{}.{BUNDLE_FILE_EXTENSION}", program.id()));
+        // Read the header of the bundle file.
+        let header = File::open(path).map_err(Into::into).and_then(|file| Self::read_header(BufReader::new(file)));
+        // Ensure the program and imported programs match.
+        matches!(header, Ok((candidate, candidate_imports)) if &candidate == program && candidate_imports == imports)
+    }
+
+    /// Returns the file name.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Returns the compiled program.
+    pub const fn compiled(&self) -> &CompiledProgram<N> {
+        &self.compiled
+    }
+
+    /// Returns the program.
+    pub const fn program(&self) -> &Program<N> {
+        self.compiled.program()
+    }
+
+    /// Returns the imported programs.
+    pub fn imports(&self) -> &[Program<N>] {
+        &self.imports
+    }
+
+    /// Returns the ABI descriptor.
+    pub const fn abi(&self) -> &serde_json::Value {
+        &self.abi
+    }
+
+    /// Removes the file at the given path, if it exists.
+    pub fn remove(&self, path: &Path) -> Result<()> {
+        // If the path does not exist, do nothing.
+        if !path.exists() {
+            Ok(())
+        } else {
+            // Ensure the path is well-formed.
+            Self::check_path(path)?;
+            // Remove the file.
+            Ok(fs::remove_file(path)?)
+        }
+    }
+}
+
+impl<N: Network> BundleFile<N> {
+    /// Returns the ABI descriptor of the given program, which lists the input and output types of each function.
+    fn derive_abi(program: &Program<N>) -> serde_json::Value {
+        // Describe each function.
+        let functions = program
+            .functions()
+            .values()
+            .map(|function| {
+                let inputs = function.inputs().iter().map(|input| input.value_type().to_string()).collect::<Vec<_>>();
+                let outputs =
+                    function.outputs().iter().map(|output| output.value_type().to_string()).collect::<Vec<_>>();
+                serde_json::json!({
+                    "name": function.name().to_string(),
+                    "inputs": inputs,
+                    "outputs": outputs,
+                    "finalize": function.finalize_logic().is_some(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "program": program.id().to_string(),
+            "imports": program.imports().keys().map(|program_id| program_id.to_string()).collect::<Vec<_>>(),
+            "functions": functions,
+        })
+    }
+
+    /// Returns the bundle file bytes.
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        // Write the header.
+        let mut bytes = self.compiled.program().to_bytes_le()?;
+        bytes.extend_from_slice(&u16::try_from(self.imports.len())?.to_le_bytes());
+        for import in &self.imports {
+            bytes.extend_from_slice(&import.to_bytes_le()?);
+        }
+        // Write the ABI descriptor.
+        let abi = serde_json::to_vec(&self.abi)?;
+        bytes.extend_from_slice(&u32::try_from(abi.len())?.to_le_bytes());
+        bytes.extend_from_slice(&abi);
+        // Write the compiled program.
+        bytes.extend_from_slice(&self.compiled.to_bytes_le()?);
+        Ok(bytes)
+    }
+
+    /// Reads the header of the bundle, which contains the program and the imported programs.
+    fn read_header(mut reader: impl Read) -> Result<(Program<N>, Vec<Program<N>>)> {
+        // Read the program.
+        let program = Program::read_le(&mut reader)?;
+        // Read the imported programs.
+        let num_imports = u16::read_le(&mut reader)?;
+        let imports = (0..num_imports).map(|_| Program::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok((program, imports))
+    }
+
+    /// Checks that the given path has the correct file extension.
+    fn check_path(path: &Path) -> Result<()> {
+        // Ensure the given path is a file.
+        ensure!(path.is_file(), "The path is not a file.");
+
+        // Ensure the given path has the correct file extension.
+        let extension = path.extension().ok_or_else(|| anyhow!("File extension not found."))?;
+        ensure!(extension == BUNDLE_FILE_EXTENSION, "File extension is incorrect.");
+
+        // Ensure the given path exists.
+        ensure!(path.exists(), "File does not exist: {}", path.display());
+
+        Ok(())
+    }
+
+    /// Reads the bundle from the given file path, if it exists.
+    fn from_filepath(file: &Path) -> Result<Self> {
+        // Ensure the path is well-formed.
+        Self::check_path(file)?;
+
+        // Retrieve the file name.
+        let file_name = file
+            .file_stem()
+            .ok_or_else(|| anyhow!("File name not found."))?
+            .to_str()
+            .ok_or_else(|| anyhow!("File name not found."))?
+            .to_string();
+
+        // Read the bundle bytes.
+        let bytes = fs::read(file)?;
+        let mut reader = &bytes[..];
+        // Parse the header.
+        let (program, imports) = Self::read_header(&mut reader)?;
+        // Parse the ABI descriptor.
+        let abi_length = u32::read_le(&mut reader)? as usize;
+        ensure!(reader.len() >= abi_length, "The ABI descriptor in the bundle file has an incorrect length");
+        let abi: serde_json::Value = serde_json::from_slice(&reader[..abi_length])?;
+        reader = &reader[abi_length..];
+        // Parse the compiled program.
+        let compiled = CompiledProgram::<N>::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "The bundle file contains trailing bytes");
+
+        // Ensure the file name matches the program ID.
+        ensure!(compiled.program_id().to_string() == file_name, "Program ID does not match file stem.");
+        // Ensure the header matches the compiled program.
+        ensure!(&program == compiled.program(), "The bundle header does not match the compiled program.");
+        ensure!(
+            program.imports().keys().eq(imports.iter().map(Program::id)),
+            "The imported programs do not match the program imports."
+        );
+        // Ensure the ABI descriptor matches the program.
+        ensure!(abi == Self::derive_abi(compiled.program()), "The ABI descriptor does not match the program.");
+
+        // Return the bundle file.
+        Ok(Self { file_name, imports, abi, compiled })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::process::Process;
+    use snarkvm_console::network::Testnet3;
+    use snarkvm_utilities::TestRng;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    fn temp_dir() -> std::path::PathBuf {
+        tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+    }
+
+    #[test]
+    fn test_create_and_open() {
+        // Initialize a temporary directory.
+        let directory = temp_dir();
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program bundle.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+
+        // Compile the program.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let compiled = process.compile::<CurrentAleo, _>(program.id(), true, &mut TestRng::default()).unwrap();
+
+        // Create the bundle file.
+        let bundle_file = BundleFile::create(&directory, compiled, vec![]).unwrap();
+        assert!(BundleFile::exists_at(&directory, program.id()));
+        assert_eq!(bundle_file.program(), &program);
+        assert_eq!(bundle_file.abi()["program"], "bundle.aleo");
+        assert_eq!(bundle_file.abi()["functions"][0]["inputs"], serde_json::json!(["u64.private", "u64.public"]));
+        assert_eq!(bundle_file.abi()["functions"][0]["outputs"], serde_json::json!(["u64.private"]));
+
+        // Open the bundle file.
+        let candidate = BundleFile::<CurrentNetwork>::open(&directory, program.id()).unwrap();
+        assert_eq!(candidate.program(), &program);
+        assert_eq!(candidate.abi(), bundle_file.abi());
+        assert_eq!(candidate.compiled().verifying_keys(), bundle_file.compiled().verifying_keys());
+        assert!(candidate.imports().is_empty());
+
+        // Ensure the bundle is current only for the program and imports it was bundled from.
+        assert!(BundleFile::is_current(&directory, &program, &[]));
+        assert!(!BundleFile::is_current(&directory, &program, &[program.clone()]));
+        let noop = "function noop:\n    input r0 as u8.private;\n    output r0 as u8.private;";
+        let modified = Program::<CurrentNetwork>::from_str(&format!("{program}\n\n{noop}")).unwrap();
+        assert!(!BundleFile::is_current(&directory, &modified, &[]));
+
+        // Ensure a bundle for a different program is not found.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap();
+        assert!(!BundleFile::exists_at(&directory, &program_id));
+        assert!(BundleFile::<CurrentNetwork>::open(&directory, &program_id).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
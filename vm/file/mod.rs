@@ -18,6 +18,9 @@ pub use aleo::AleoFile;
 mod avm;
 pub use avm::AVMFile;
 
+mod bundle;
+pub use bundle::BundleFile;
+
 mod manifest;
 pub use manifest::Manifest;
 
@@ -45,6 +45,8 @@ pub use snarkvm_parameters as parameters;
 pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
+#[cfg(feature = "verifier")]
+pub use snarkvm_synthesizer_snark as verifier;
 #[cfg(feature = "wasm")]
 pub use snarkvm_wasm as wasm;
 
@@ -55,4 +57,6 @@ pub mod prelude {
     pub use crate::ledger::*;
     #[cfg(feature = "synthesizer")]
     pub use crate::synthesizer::prelude::*;
+    #[cfg(feature = "verifier")]
+    pub use crate::verifier::{Certificate, Proof, VerifierRegistry, VerifyingKey};
 }
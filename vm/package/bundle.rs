@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Builds the package, and packages the compiled program, the import programs, and the ABI descriptor
+    /// into a bundle in the build directory. Once bundled, `execute` restores the program and its circuit keys
+    /// from the bundle, for as long as the program and its import programs are unchanged.
+    pub fn bundle<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        endpoint: Option<String>,
+    ) -> Result<BundleFile<N>> {
+        // Build the package, which type-checks the program and synthesizes its circuit keys.
+        self.build::<A>(endpoint)?;
+
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Prepare the build directory.
+        let build_directory = self.build_directory();
+
+        #[cfg(feature = "aleo-cli")]
+        println!("📦 Bundling '{}'...\n", program_id.to_string().bold());
+
+        // Construct the process.
+        let process = self.get_process()?;
+        // Insert the circuit keys from the build directory.
+        for function_name in self.program().functions().keys() {
+            // Load the proving key.
+            let prover = ProverFile::open(&build_directory, function_name)?;
+            process.insert_proving_key(program_id, function_name, prover.proving_key().clone())?;
+            // Load the verifying key.
+            let verifier = VerifierFile::open(&build_directory, function_name)?;
+            process.insert_verifying_key(program_id, function_name, verifier.verifying_key().clone())?;
+        }
+
+        // Compile the program, including the proving keys.
+        let compiled = process.compile::<A, _>(program_id, true, &mut rand::thread_rng())?;
+        // Retrieve the imported programs.
+        let imported_programs = self
+            .program()
+            .imports()
+            .keys()
+            .map(|program_id| process.get_program(program_id).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        // Write the bundle file.
+        BundleFile::create(&build_directory, compiled, imported_programs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_bundle() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Bundle the package.
+        let bundle_file = package.bundle::<CurrentAleo>(None).unwrap();
+        assert_eq!(bundle_file.program(), package.program());
+        assert!(BundleFile::exists_at(&package.build_directory(), package.program_id()));
+
+        // Ensure the process restores the circuit keys from the bundle.
+        let rng = &mut TestRng::default();
+        let process = package.get_bundled_process::<CurrentAleo, _>(rng).unwrap();
+        for (function_name, verifying_key) in bundle_file.compiled().verifying_keys() {
+            assert_eq!(&process.get_verifying_key(package.program_id(), function_name).unwrap(), verifying_key);
+        }
+
+        // Run the program function.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        let (_response, _metrics) = package.run::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).unwrap();
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_bundle_with_import() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+
+        // Bundle the package.
+        let bundle_file = package.bundle::<CurrentAleo>(None).unwrap();
+        assert_eq!(bundle_file.abi()["imports"], serde_json::json!(["token.aleo"]));
+        assert_eq!(bundle_file.imports().len(), 1);
+
+        // Ensure the process restores the circuit keys from the bundle.
+        let rng = &mut TestRng::default();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let process = package.get_bundled_process::<CurrentAleo, _>(rng).unwrap();
+        assert!(process.get_verifying_key(package.program_id(), function_name).is_ok());
+
+        // Update the imported program.
+        let imported_program = &bundle_file.imports()[0];
+        let noop = "function noop:\n    input r0 as u8.private;\n    output r0 as u8.private;";
+        let updated_program = Program::from_str(&format!("{imported_program}\n\n{noop}")).unwrap();
+        let path = package.imports_directory().join("token.aleo");
+        std::fs::write(path, updated_program.to_string()).unwrap();
+
+        // Ensure the bundle is stale, so the process does not restore the circuit keys from the bundle.
+        assert!(!BundleFile::is_current(&package.build_directory(), package.program(), &[updated_program]));
+        let process = package.get_bundled_process::<CurrentAleo, _>(rng).unwrap();
+        assert!(process.get_verifying_key(package.program_id(), function_name).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -45,8 +45,8 @@ impl<N: Network> Package<N> {
         #[cfg(feature = "aleo-cli")]
         println!("🚀 Executing '{}'...\n", locator.to_string().bold());
 
-        // Construct the process.
-        let process = self.get_process()?;
+        // Construct the process, restoring the program and its circuit keys from the bundle if it is current.
+        let process = self.get_bundled_process::<A, R>(rng)?;
         // Authorize the function call.
         let authorization = process.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
 
@@ -83,17 +83,20 @@ impl<N: Network> Package<N> {
             }
         }
 
-        // Prepare the build directory.
-        let build_directory = self.build_directory();
-        // Load the prover.
-        let prover = ProverFile::open(&build_directory, &function_name)?;
-        // Load the verifier.
-        let verifier = VerifierFile::open(&build_directory, &function_name)?;
-
-        // Adds the proving key to the process.
-        process.insert_proving_key(program_id, &function_name, prover.proving_key().clone())?;
-        // Adds the verifying key to the process.
-        process.insert_verifying_key(program_id, &function_name, verifier.verifying_key().clone())?;
+        // If the circuit keys were not restored from the bundle, load them from the build directory.
+        if process.get_proving_key(program_id, function_name).is_err() {
+            // Prepare the build directory.
+            let build_directory = self.build_directory();
+            // Load the prover.
+            let prover = ProverFile::open(&build_directory, &function_name)?;
+            // Load the verifier.
+            let verifier = VerifierFile::open(&build_directory, &function_name)?;
+
+            // Adds the proving key to the process.
+            process.insert_proving_key(program_id, &function_name, prover.proving_key().clone())?;
+            // Adds the verifying key to the process.
+            process.insert_verifying_key(program_id, &function_name, verifier.verifying_key().clone())?;
+        }
 
         // Execute the circuit.
         let (response, mut trace) = process.execute::<A, R>(authorization, rng)?;
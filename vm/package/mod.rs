@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod build;
+mod bundle;
 mod clean;
 mod deploy;
 mod execute;
@@ -28,7 +29,7 @@ use crate::{
         network::Network,
         program::{Identifier, Locator, ProgramID, Response, Value},
     },
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{AVMFile, AleoFile, BundleFile, Manifest, ProverFile, VerifierFile, README},
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
@@ -149,6 +150,48 @@ impl<N: Network> Package<N> {
 
     /// Returns a new process for the package.
     pub fn get_process(&self) -> Result<Process<N>> {
+        // Create the process, with the import programs.
+        let mut process = self.get_import_process()?;
+        // Add the program to the process.
+        process.add_program(self.program())?;
+
+        Ok(process)
+    }
+
+    /// Returns a new process for the package, restoring the program and its circuit keys from the bundle,
+    /// if the bundle was built from the current program and import programs.
+    /// The circuit keys are checked against their certificates when they are restored.
+    pub fn get_bundled_process<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Process<N>> {
+        // Create the process, with the import programs.
+        let mut process = self.get_import_process()?;
+
+        // Retrieve the imported programs.
+        let imported_programs = self
+            .program()
+            .imports()
+            .keys()
+            .map(|program_id| process.get_program(program_id).cloned())
+            .collect::<Result<Vec<_>>>()?;
+
+        // Prepare the build directory.
+        let build_directory = self.build_directory();
+        // Add the program to the process, restoring it from the bundle if the bundle is current.
+        match BundleFile::is_current(&build_directory, self.program(), &imported_programs) {
+            true => {
+                let bundle_file = BundleFile::<N>::open(&build_directory, &self.program_id)?;
+                process.load_compiled::<A, R>(bundle_file.compiled(), rng)?
+            }
+            false => process.add_program(self.program())?,
+        }
+
+        Ok(process)
+    }
+
+    /// Returns a new process, with the import programs of the package.
+    fn get_import_process(&self) -> Result<Process<N>> {
         // Create the process.
         let mut process = Process::load()?;
 
@@ -170,9 +213,6 @@ impl<N: Network> Package<N> {
             Ok::<_, Error>(())
         })?;
 
-        // Add the program to the process.
-        process.add_program(self.program())?;
-
         Ok(process)
     }
 }